@@ -222,6 +222,7 @@ impl AlertManager {
         active_node: &str,
         standby_node: &str,
         total_time: Option<std::time::Duration>,
+        first_vote_time: Option<std::time::Duration>,
         error: Option<&str>,
     ) -> Result<()> {
         if !self.config.enabled {
@@ -236,12 +237,22 @@ impl AlertManager {
                     String::new()
                 };
 
+                let vote_str = if let Some(time) = first_vote_time {
+                    format!(
+                        "*First Landed Vote:* {}ms after activation",
+                        time.as_millis()
+                    )
+                } else {
+                    "⚠️ *First Landed Vote:* not confirmed on-chain".to_string()
+                };
+
                 format!(
                     "✅ *VALIDATOR SWITCH SUCCESSFUL*{}\n\n\
                     *Previous Active:* {}\n\
-                    *New Active:* {}\n\n\
+                    *New Active:* {}\n\
+                    {}\n\n\
                     Switch completed successfully!",
-                    time_str, active_node, standby_node
+                    time_str, active_node, standby_node, vote_str
                 )
             } else {
                 let error_msg = error.unwrap_or("Unknown error");
@@ -261,6 +272,169 @@ impl AlertManager {
         Ok(())
     }
 
+    pub async fn send_switch_rollback_alert(
+        &self,
+        validator_identity: &str,
+        active_node: &str,
+        standby_node: &str,
+        failed_step: &str,
+        error: &str,
+        rollback_error: Option<&str>,
+    ) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        if let Some(telegram) = &self.config.telegram {
+            // No rollback error means the original active was restored
+            let message = if let Some(rollback_error) = rollback_error {
+                format!(
+                    "🚨 *VALIDATOR SWITCH FAILED - ROLLBACK FAILED* 🚨\n\n\
+                    *Validator:* `{}`\n\
+                    *Active Node:* {} (left on unfunded identity)\n\
+                    *Standby Node:* {}\n\
+                    *Failed Step:* {}\n\
+                    *Error:* {}\n\
+                    *Rollback Error:* {}\n\n\
+                    ⚠️ *MANUAL INTERVENTION REQUIRED - VALIDATOR MAY NOT BE VOTING*",
+                    validator_identity,
+                    active_node,
+                    standby_node,
+                    failed_step,
+                    error,
+                    rollback_error
+                )
+            } else {
+                format!(
+                    "↩️ *VALIDATOR SWITCH ROLLED BACK*\n\n\
+                    *Validator:* `{}`\n\
+                    *Active Node:* {} (restored to funded identity)\n\
+                    *Standby Node:* {}\n\
+                    *Failed Step:* {}\n\
+                    *Error:* {}\n\n\
+                    ⚠️ *Verify the active node is voting*",
+                    validator_identity, active_node, standby_node, failed_step, error
+                )
+            };
+
+            self.send_telegram_message(telegram, &message).await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn send_double_identity_alert(
+        &self,
+        validator_identity: &str,
+        holders: &[String],
+        kept_node: &str,
+        fence_results: &[(String, std::result::Result<(), String>)],
+    ) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        if let Some(telegram) = &self.config.telegram {
+            let fencing = if fence_results.is_empty() {
+                "not configured (switch_policy.fence_double_identity)\n\n\
+                ⚠️ *SET THE EXTRA NODES TO UNFUNDED NOW*"
+                    .to_string()
+            } else {
+                fence_results
+                    .iter()
+                    .map(|(label, result)| match result {
+                        Ok(()) => format!("{} → unfunded ✅", label),
+                        Err(e) => format!("{} → failed ❌ ({})", label, e),
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            };
+            let message = format!(
+                "🚨🚨 *DOUBLE IDENTITY DETECTED* 🚨🚨\n\n\
+                *Validator:* `{}`\n\
+                *Nodes with funded identity:* {}\n\
+                *Kept:* {}\n\
+                *Fencing:* {}",
+                validator_identity,
+                holders.join(", "),
+                kept_node,
+                fencing
+            );
+
+            self.send_telegram_message(telegram, &message).await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn send_switch_lock_broken_alert(
+        &self,
+        validator_identity: &str,
+        broken: &[(String, String)],
+        broken_by: &str,
+    ) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        if let Some(telegram) = &self.config.telegram {
+            let locks = broken
+                .iter()
+                .map(|(label, holder)| format!("{}: {}", label, holder))
+                .collect::<Vec<_>>()
+                .join("\n");
+            let message = format!(
+                "🔓 *SWITCH LOCK BROKEN* 🔓\n\n\
+                *Validator:* `{}`\n\
+                *Broken by:* {}\n\
+                *Previous holder:*\n{}\n\n\
+                ⚠️ Make sure the other svs is no longer switching this validator",
+                validator_identity, broken_by, locks
+            );
+
+            self.send_telegram_message(telegram, &message).await?;
+        }
+
+        Ok(())
+    }
+
+    /// One alert for a whole batch switch; `results` holds (validator, outcome) per pair
+    pub async fn send_batch_switch_alert(
+        &self,
+        results: &[(String, String)],
+        switched: usize,
+    ) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        if let Some(telegram) = &self.config.telegram {
+            let header = if switched == results.len() {
+                format!(
+                    "✅ *BATCH SWITCH SUCCESSFUL* ({}/{})",
+                    switched,
+                    results.len()
+                )
+            } else {
+                format!(
+                    "⚠️ *BATCH SWITCH INCOMPLETE* ({}/{} switched)",
+                    switched,
+                    results.len()
+                )
+            };
+            let lines = results
+                .iter()
+                .map(|(validator, outcome)| format!("`{}`: {}", validator, outcome))
+                .collect::<Vec<_>>()
+                .join("\n");
+            let message = format!("{}\n\n{}", header, lines);
+
+            self.send_telegram_message(telegram, &message).await?;
+        }
+
+        Ok(())
+    }
+
     pub async fn send_ssh_failure_alert(
         &self,
         validator_identity: &str,
@@ -329,6 +503,40 @@ impl AlertManager {
         Ok(())
     }
 
+    pub async fn send_rpc_disagreement_alert(
+        &self,
+        validator_identity: &str,
+        headline: &str,
+        rpc_endpoints: &[String],
+    ) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        if let Some(telegram) = &self.config.telegram {
+            let message = format!(
+                "🌐 *RPC ENDPOINTS DISAGREE* 🌐\n\n\
+                *Validator:* `{}`\n\
+                *Status:* {}\n\n\
+                *RPC Endpoints:*\n{}\n\n\
+                ℹ️ Not treated as delinquent, no failover. Check the lagging endpoints",
+                validator_identity,
+                headline,
+                rpc_endpoints
+                    .iter()
+                    .map(|line| format!("• {}", line))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            );
+
+            self.send_telegram_message(telegram, &message).await?;
+        }
+
+        Ok(())
+    }
+
+    /// `rpc_endpoints` lists what each RPC endpoint sees when there are several
+    #[allow(clippy::too_many_arguments)]
     pub async fn send_delinquency_alert_with_health(
         &self,
         validator_identity: &str,
@@ -337,6 +545,7 @@ impl AlertManager {
         last_vote_slot: u64,
         seconds_since_vote: u64,
         node_health: &NodeHealthStatus,
+        rpc_endpoints: &[String],
     ) -> Result<()> {
         if !self.config.enabled {
             return Ok(());
@@ -373,6 +582,19 @@ impl AlertManager {
                 "✅ Working".to_string()
             };
 
+            let endpoints = if rpc_endpoints.is_empty() {
+                String::new()
+            } else {
+                format!(
+                    "*RPC Endpoints:*\n{}\n\n",
+                    rpc_endpoints
+                        .iter()
+                        .map(|line| format!("• {}", line))
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                )
+            };
+
             let message = format!(
                 "🚨 *VALIDATOR DELINQUENCY ALERT* 🚨\n\n\
                 *Validator:* `{}`\n\
@@ -383,6 +605,7 @@ impl AlertManager {
                 *Health Status:*\n\
                 • SSH: {}\n\
                 • RPC: {}\n\n\
+                {}\
                 ⚠️ *Action Required:* Check validator health",
                 validator_identity,
                 node_label,
@@ -391,7 +614,8 @@ impl AlertManager {
                 seconds_since_vote,
                 self.config.delinquency_threshold_seconds,
                 ssh_status,
-                rpc_status
+                rpc_status,
+                endpoints
             );
 
             self.send_telegram_message(telegram, &message).await?;
@@ -400,6 +624,8 @@ impl AlertManager {
         Ok(())
     }
 
+    /// `decision` lists the auto-failover policy checks that led to the takeover
+    #[allow(clippy::too_many_arguments)]
     pub async fn send_emergency_takeover_alert(
         &self,
         validator_identity: &str,
@@ -410,6 +636,7 @@ impl AlertManager {
         standby_switch_success: bool,
         total_time: Duration,
         error: Option<&str>,
+        decision: &[String],
     ) -> Result<()> {
         if !self.config.enabled {
             return Ok(());
@@ -418,12 +645,18 @@ impl AlertManager {
         if let Some(telegram) = &self.config.telegram {
             let primary_status = if primary_switch_success { "✅" } else { "❌" };
             let tower_status = if tower_copy_success { "✅" } else { "❌" };
+            let decision = if decision.is_empty() {
+                String::new()
+            } else {
+                format!("*Decision:*\n{}\n\n", decision.join("\n"))
+            };
 
             let message = if let Some(error_msg) = error {
                 format!(
                     "❌ *EMERGENCY TAKEOVER FAILED*\n\n\
                     *Validator:* `{}`\n\
                     *Reason:* Not voting with confirmed connectivity\n\n\
+                    {}\
                     *Previous Active:* {} ❌\n\
                     *Attempted New Active:* {} ❌\n\n\
                     *Optional Steps:*\n\
@@ -434,6 +667,7 @@ impl AlertManager {
                     *Duration:* {}ms\n\n\
                     ⚠️ *MANUAL INTERVENTION REQUIRED*",
                     validator_identity,
+                    decision,
                     active_node,
                     standby_node,
                     primary_status,
@@ -446,6 +680,7 @@ impl AlertManager {
                     "{} *EMERGENCY TAKEOVER {}*\n\n\
                     *Validator:* `{}`\n\
                     *Reason:* Not voting for 30+ seconds with confirmed connectivity\n\n\
+                    {}\
                     *Previous Active:* {} ❌\n\
                     *New Active:* {} ✅\n\n\
                     *Optional Steps:*\n\
@@ -466,6 +701,7 @@ impl AlertManager {
                         "FAILED"
                     },
                     validator_identity,
+                    decision,
                     active_node,
                     standby_node,
                     primary_status,
@@ -490,11 +726,41 @@ impl AlertManager {
         Ok(())
     }
 
+    /// Auto-failover stood down (policy) or only pretended to take over (dry run)
+    pub async fn send_failover_decision_alert(
+        &self,
+        validator_identity: &str,
+        headline: &str,
+        reasons: &[String],
+    ) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        if let Some(telegram) = &self.config.telegram {
+            let message = format!(
+                "🛑 *AUTO-FAILOVER NOT EXECUTED* 🛑\n\n\
+                *Validator:* `{}`\n\
+                *Decision:* {}\n\n\
+                *Checks:*\n{}\n\n\
+                ⚠️ The validator is still delinquent, take over manually if needed",
+                validator_identity,
+                headline,
+                reasons.join("\n")
+            );
+
+            self.send_telegram_message(telegram, &message).await?;
+        }
+
+        Ok(())
+    }
+
+    /// A standby failed the readiness gate, so automatic failover did not activate it
     pub async fn send_catchup_failure_alert(
         &self,
         validator_identity: &str,
         node_label: &str,
-        consecutive_failures: u32,
+        issues: &[String],
     ) -> Result<()> {
         if !self.config.enabled {
             return Ok(());
@@ -502,13 +768,18 @@ impl AlertManager {
 
         if let Some(telegram) = &self.config.telegram {
             let message = format!(
-                "⚠️ *STANDBY NODE CATCHUP FAILURE* ⚠️\n\n\
+                "⚠️ *STANDBY NODE NOT READY* ⚠️\n\n\
                 *Validator:* `{}`\n\
-                *Standby Node:* {}\n\
-                *Consecutive Failures:* {}\n\n\
-                The standby node has failed catchup check {} times in a row.\n\
-                This may indicate issues with the standby node's sync status.",
-                validator_identity, node_label, consecutive_failures, consecutive_failures
+                *Standby Node:* {}\n\n\
+                *Issues:*\n{}\n\n\
+                The emergency takeover skipped this node instead of activating a standby that can't vote.",
+                validator_identity,
+                node_label,
+                issues
+                    .iter()
+                    .map(|issue| format!("• {}", issue))
+                    .collect::<Vec<_>>()
+                    .join("\n")
             );
 
             self.send_telegram_message(telegram, &message).await?;
@@ -13,6 +13,7 @@ mod alert_integration_tests {
             rpc_failure_threshold_seconds: 1800, // 30 minutes
             telegram: None,
             auto_failover_enabled: false,
+            auto_failover_policy: Default::default(),
         };
 
         // Simulate the actual check from status_ui_v2.rs
@@ -100,6 +101,7 @@ mod alert_integration_tests {
             rpc_failure_threshold_seconds: 1800, // 30 minutes
             telegram: None,
             auto_failover_enabled: false,
+            auto_failover_policy: Default::default(),
         };
 
         let mut ssh_tracker = FailureTracker::new();
@@ -140,6 +142,7 @@ mod alert_integration_tests {
             rpc_failure_threshold_seconds: 1800, // 30 minutes
             telegram: None,
             auto_failover_enabled: false,
+            auto_failover_policy: Default::default(),
         };
 
         // Validator state
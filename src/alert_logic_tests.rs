@@ -15,6 +15,7 @@ mod alert_logic_tests {
                 chat_id: "test_chat".to_string(),
             }),
             auto_failover_enabled: false,
+            auto_failover_policy: Default::default(),
         }
     }
 
@@ -16,6 +16,7 @@ mod tests {
                 chat_id: "test_chat".to_string(),
             }),
             auto_failover_enabled: false,
+            auto_failover_policy: Default::default(),
         }
     }
 
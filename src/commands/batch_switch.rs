@@ -0,0 +1,450 @@
+use anyhow::{anyhow, Result};
+use colored::*;
+use comfy_table::{
+    modifiers::UTF8_ROUND_CORNERS, Attribute, Cell, Color, ContentArrangement, Table,
+};
+use futures::stream::{self, StreamExt};
+use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
+use std::time::{Duration, Instant};
+
+use crate::commands::switch::{
+    check_switch_preflight, select_switch_targets, SwitchManager, SwitchOptions,
+};
+use crate::switch_journal::SwitchTrigger;
+use crate::types::NodeStatus;
+use crate::AppState;
+
+/// What happened to one validator of a batch switch
+#[derive(Debug, Clone, PartialEq)]
+pub enum BatchOutcome {
+    Switched,
+    /// Failed pre-flight, nothing was changed
+    Skipped(String),
+    Failed(String),
+    /// Failed part-way and the original active node was restored
+    RolledBack(String),
+}
+
+impl BatchOutcome {
+    fn label(&self, dry_run: bool) -> (&'static str, Color) {
+        match self {
+            BatchOutcome::Switched if dry_run => ("DRY RUN OK", Color::Green),
+            BatchOutcome::Switched => ("SWITCHED", Color::Green),
+            BatchOutcome::Skipped(_) => ("SKIPPED", Color::Yellow),
+            BatchOutcome::Failed(_) => ("FAILED", Color::Red),
+            BatchOutcome::RolledBack(_) => ("ROLLED BACK", Color::Red),
+        }
+    }
+
+    fn reason(&self) -> &str {
+        match self {
+            BatchOutcome::Switched => "",
+            BatchOutcome::Skipped(reason)
+            | BatchOutcome::Failed(reason)
+            | BatchOutcome::RolledBack(reason) => reason,
+        }
+    }
+}
+
+/// One validator of a batch switch
+struct BatchEntry {
+    validator_idx: usize,
+    validator: String,
+    active_index: usize,
+    standby_index: usize,
+    from: String,
+    to: String,
+    warnings: Vec<String>,
+    outcome: Option<BatchOutcome>,
+    duration: Option<Duration>,
+}
+
+/// Restores the previous SVS_SILENT_MODE when the batch ends, however it ends
+struct SilentModeGuard {
+    previous: Option<String>,
+}
+
+impl SilentModeGuard {
+    fn enable() -> Self {
+        let previous = std::env::var("SVS_SILENT_MODE").ok();
+        std::env::set_var("SVS_SILENT_MODE", "1");
+        Self { previous }
+    }
+}
+
+impl Drop for SilentModeGuard {
+    fn drop(&mut self) {
+        match &self.previous {
+            Some(value) => std::env::set_var("SVS_SILENT_MODE", value),
+            None => std::env::remove_var("SVS_SILENT_MODE"),
+        }
+    }
+}
+
+fn short_identity(identity: &str) -> String {
+    if identity.len() > 12 {
+        format!("{}…", &identity[..8])
+    } else {
+        identity.to_string()
+    }
+}
+
+/// Count of (switched, failed or rolled back, skipped) validators
+fn summarize(outcomes: &[BatchOutcome]) -> (usize, usize, usize) {
+    outcomes
+        .iter()
+        .fold((0, 0, 0), |(ok, failed, skipped), outcome| match outcome {
+            BatchOutcome::Switched => (ok + 1, failed, skipped),
+            BatchOutcome::Skipped(_) => (ok, failed, skipped + 1),
+            BatchOutcome::Failed(_) | BatchOutcome::RolledBack(_) => (ok, failed + 1, skipped),
+        })
+}
+
+/// Switch several validators, `concurrency` at a time. Every validator is checked
+/// first; those that fail pre-flight are skipped and the rest are switched after a
+/// single confirmation. Per-switch prompts are answered with their safe default.
+pub async fn batch_switch_command(
+    options: SwitchOptions,
+    app_state: &mut AppState,
+    validator_indices: Vec<usize>,
+    concurrency: usize,
+) -> Result<()> {
+    let concurrency = concurrency.max(1);
+    let dry_run = options.dry_run;
+    if validator_indices.is_empty() {
+        return Err(anyhow!("No validators selected for the batch switch"));
+    }
+
+    println!(
+        "\n{}",
+        format!(
+            "🔁 Batch switch of {} validator(s), {} at a time{}",
+            validator_indices.len(),
+            concurrency,
+            if dry_run { " (dry run)" } else { "" }
+        )
+        .bright_cyan()
+        .bold()
+    );
+
+    // The switches print nothing of their own; progress is shown per validator below
+    let silent_mode = SilentModeGuard::enable();
+
+    let spinner = ProgressBar::new_spinner();
+    spinner.set_style(
+        ProgressStyle::default_spinner()
+            .template("  {spinner:.green} {msg}")
+            .unwrap(),
+    );
+    spinner.enable_steady_tick(Duration::from_millis(100));
+    spinner.set_message("Running pre-flight checks...");
+    let state: &AppState = app_state;
+    let mut entries: Vec<BatchEntry> = stream::iter(validator_indices)
+        .map(|validator_idx| preflight(state, validator_idx, options.standby.as_deref()))
+        .buffered(concurrency)
+        .collect()
+        .await;
+    spinner.finish_and_clear();
+
+    print_plan(&entries);
+
+    let ready = entries.iter().filter(|e| e.outcome.is_none()).count();
+    if ready == 0 {
+        return Err(anyhow!("No validator passed the pre-flight checks"));
+    }
+
+    if !dry_run {
+        let confirmed = inquire::Confirm::new(&format!(
+            "Switch {} validator(s), {} at a time?",
+            ready, concurrency
+        ))
+        .with_default(false)
+        .prompt()?;
+        if !confirmed {
+            println!("\n{}", "❌ Batch switch cancelled by user".red());
+            return Ok(());
+        }
+    }
+
+    // Combined progress view: one line per validator
+    let multi = MultiProgress::new();
+    let style = ProgressStyle::default_spinner()
+        .template("  {spinner:.green} {prefix:.bold} {msg}")
+        .unwrap();
+    let bars: Vec<Option<ProgressBar>> = entries
+        .iter()
+        .map(|entry| {
+            entry.outcome.is_none().then(|| {
+                let bar = multi.add(ProgressBar::new_spinner());
+                bar.set_style(style.clone());
+                bar.set_prefix(format!("{} {} → {}", entry.validator, entry.from, entry.to));
+                bar.set_message("queued");
+                bar.enable_steady_tick(Duration::from_millis(100));
+                bar
+            })
+        })
+        .collect();
+
+    let state: &AppState = app_state;
+    let options = &options;
+    let results: Vec<(usize, BatchOutcome, Duration)> = stream::iter(
+        entries
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| entry.outcome.is_none())
+            .map(|(i, entry)| (i, entry, bars[i].clone())),
+    )
+    .map(|(i, entry, bar)| async move {
+        let started = Instant::now();
+        let outcome = run_switch(state, entry, options, bar.clone()).await;
+        if let Some(bar) = bar {
+            match &outcome {
+                BatchOutcome::Switched => bar.finish_with_message("✅ done".to_string()),
+                other => bar.finish_with_message(format!("❌ {}", other.reason())),
+            }
+        }
+        (i, outcome, started.elapsed())
+    })
+    .buffer_unordered(concurrency)
+    .collect()
+    .await;
+    drop(silent_mode);
+
+    for (i, outcome, duration) in results {
+        let entry = &mut entries[i];
+        if !dry_run && outcome == BatchOutcome::Switched {
+            // Reflect the switch in app_state
+            let nodes = &mut app_state.validator_statuses[entry.validator_idx].nodes_with_status;
+            if nodes[entry.active_index].status == NodeStatus::Active {
+                nodes[entry.active_index].status = NodeStatus::Standby;
+            }
+            nodes[entry.standby_index].status = NodeStatus::Active;
+        }
+        entry.outcome = Some(outcome);
+        entry.duration = Some(duration);
+    }
+
+    print_results(&entries, dry_run);
+
+    let outcomes: Vec<BatchOutcome> = entries.iter().filter_map(|e| e.outcome.clone()).collect();
+    let (switched, failed, skipped) = summarize(&outcomes);
+
+    if !dry_run {
+        if let Some(alert_config) = &app_state.config.alert_config {
+            let alert_manager = crate::alert::AlertManager::new(alert_config.clone());
+            let lines: Vec<(String, String)> = entries
+                .iter()
+                .map(|entry| {
+                    let outcome = entry.outcome.as_ref().expect("every entry has an outcome");
+                    let (label, _) = outcome.label(dry_run);
+                    let text = if outcome.reason().is_empty() {
+                        format!("{} ({} → {})", label, entry.from, entry.to)
+                    } else {
+                        format!(
+                            "{} ({} → {}): {}",
+                            label,
+                            entry.from,
+                            entry.to,
+                            outcome.reason()
+                        )
+                    };
+                    (entry.validator.clone(), text)
+                })
+                .collect();
+            let _ = alert_manager
+                .send_batch_switch_alert(&lines, switched)
+                .await;
+        }
+    }
+
+    if failed + skipped > 0 {
+        return Err(anyhow!(
+            "Batch switch incomplete: {} switched, {} failed, {} skipped",
+            switched,
+            failed,
+            skipped
+        ));
+    }
+    Ok(())
+}
+
+/// Pick the nodes of one validator and check the switch can go ahead
+async fn preflight(
+    app_state: &AppState,
+    validator_idx: usize,
+    standby_label: Option<&str>,
+) -> BatchEntry {
+    let validator_status = &app_state.validator_statuses[validator_idx];
+    let mut entry = BatchEntry {
+        validator_idx,
+        validator: short_identity(&validator_status.validator_pair.identity_pubkey),
+        active_index: 0,
+        standby_index: 0,
+        from: "-".to_string(),
+        to: "-".to_string(),
+        warnings: Vec::new(),
+        outcome: None,
+        duration: None,
+    };
+
+    let targets = match select_switch_targets(app_state, validator_idx, standby_label).await {
+        Ok(targets) => targets,
+        Err(e) => {
+            entry.outcome = Some(BatchOutcome::Skipped(e.to_string()));
+            return entry;
+        }
+    };
+    let nodes = &validator_status.nodes_with_status;
+    let (active, standby) = (&nodes[targets.active_index], &nodes[targets.standby_index]);
+    entry.active_index = targets.active_index;
+    entry.standby_index = targets.standby_index;
+    entry.from = active.node.label.clone();
+    entry.to = standby.node.label.clone();
+    if targets.recovery_mode {
+        entry
+            .warnings
+            .push("No active node, the standby is activated in recovery mode".to_string());
+    }
+
+    let (errors, warnings) = check_switch_preflight(app_state, active, standby).await;
+    entry.warnings.extend(warnings);
+    if !errors.is_empty() {
+        entry.outcome = Some(BatchOutcome::Skipped(errors.join("; ")));
+    }
+    entry
+}
+
+/// Switch one validator that passed pre-flight and record it in the journal
+async fn run_switch(
+    app_state: &AppState,
+    entry: &BatchEntry,
+    options: &SwitchOptions,
+    progress: Option<ProgressBar>,
+) -> BatchOutcome {
+    let validator_status = &app_state.validator_statuses[entry.validator_idx];
+    let nodes = &validator_status.nodes_with_status;
+
+    let mut switch_manager = SwitchManager::new(
+        nodes[entry.active_index].clone(),
+        nodes[entry.standby_index].clone(),
+        validator_status.validator_pair.clone(),
+        app_state.ssh_pool.clone(),
+        app_state.detected_ssh_keys.clone(),
+    );
+    switch_manager.switch_policy = app_state.config.switch_policy.clone().unwrap_or_default();
+    switch_manager.wait_for_leader_window = !options.no_wait;
+    switch_manager.break_lock = options.break_lock;
+    switch_manager.alert_config = app_state.config.alert_config.clone();
+    if let Some(bar) = &progress {
+        bar.set_message(if options.no_wait {
+            "starting"
+        } else {
+            "waiting for a window without leader slots"
+        });
+    }
+    switch_manager.progress = progress;
+
+    // Confirmed once for the whole batch
+    let result = switch_manager.execute_switch(options.dry_run, false).await;
+    crate::switch_journal::record_switch(&switch_manager.journal_record(
+        SwitchTrigger::Manual,
+        options.dry_run,
+        result.as_ref().err(),
+    ));
+
+    match result {
+        Ok(_) => BatchOutcome::Switched,
+        Err(e) if switch_manager.rollback_report.is_some() => {
+            BatchOutcome::RolledBack(e.to_string())
+        }
+        Err(e) => BatchOutcome::Failed(e.to_string()),
+    }
+}
+
+fn print_plan(entries: &[BatchEntry]) {
+    println!("\n{}", "📋 Batch switch plan:".bright_cyan());
+    for entry in entries {
+        match &entry.outcome {
+            Some(outcome) => println!(
+                "   {} {}",
+                format!("⏭  {} skipped:", entry.validator).yellow(),
+                outcome.reason().yellow()
+            ),
+            None => println!(
+                "   ✅ {} {} → {}",
+                entry.validator.bright_white(),
+                entry.from,
+                entry.to.bright_green()
+            ),
+        }
+        for warning in &entry.warnings {
+            println!("      {}", format!("⚠️  {}", warning).dimmed());
+        }
+    }
+    println!();
+}
+
+fn print_results(entries: &[BatchEntry], dry_run: bool) {
+    println!("\n{}", "🔁 Batch switch results".bright_cyan().bold());
+
+    let mut table = Table::new();
+    table
+        .load_preset(comfy_table::presets::UTF8_BORDERS_ONLY)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_content_arrangement(ContentArrangement::Dynamic);
+
+    table.set_header(vec![
+        Cell::new("Validator").add_attribute(Attribute::Bold),
+        Cell::new("From → To").add_attribute(Attribute::Bold),
+        Cell::new("Result").add_attribute(Attribute::Bold),
+        Cell::new("Time").add_attribute(Attribute::Bold),
+        Cell::new("Details").add_attribute(Attribute::Bold),
+    ]);
+
+    for entry in entries {
+        let Some(outcome) = &entry.outcome else {
+            continue;
+        };
+        let (result, color) = outcome.label(dry_run);
+        table.add_row(vec![
+            Cell::new(&entry.validator),
+            Cell::new(format!("{} → {}", entry.from, entry.to)),
+            Cell::new(result).fg(color),
+            Cell::new(
+                entry
+                    .duration
+                    .map(|d| format!("{:.1}s", d.as_secs_f64()))
+                    .unwrap_or_else(|| "-".to_string()),
+            ),
+            Cell::new(outcome.reason()).fg(color),
+        ]);
+    }
+
+    println!("{}", table);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_batch_outcomes_are_summarized() {
+        let outcomes = vec![
+            BatchOutcome::Switched,
+            BatchOutcome::Skipped("Target node spare is unreachable".to_string()),
+            BatchOutcome::RolledBack("Tower transfer failed".to_string()),
+            BatchOutcome::Switched,
+            BatchOutcome::Failed("Switch lock held".to_string()),
+        ];
+        assert_eq!(summarize(&outcomes), (2, 2, 1));
+        assert_eq!(outcomes[1].reason(), "Target node spare is unreachable");
+        assert_eq!(outcomes[0].label(true).0, "DRY RUN OK");
+        assert_eq!(outcomes[2].label(false).0, "ROLLED BACK");
+
+        assert_eq!(
+            short_identity("7Np41oeYqPefeNQEHSv1UDhYrehxin3NStELsSKCT4K2"),
+            "7Np41oeY…"
+        );
+        assert_eq!(short_identity("Ident111"), "Ident111");
+    }
+}
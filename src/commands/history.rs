@@ -0,0 +1,160 @@
+use anyhow::{anyhow, Result};
+use colored::*;
+use comfy_table::{
+    modifiers::UTF8_ROUND_CORNERS, Attribute, Cell, Color, ContentArrangement, Table,
+};
+
+use crate::switch_journal::{records_to_csv, HistoryFilter, SwitchJournal, SwitchRecord};
+
+/// Options for `svs history`
+pub struct HistoryOptions {
+    pub validator: Option<String>,
+    pub trigger: Option<String>,
+    pub since: Option<String>,
+    pub failed_only: bool,
+    pub include_dry_runs: bool,
+    pub limit: Option<usize>,
+    pub format: Option<String>,
+    pub output: Option<String>,
+}
+
+pub async fn history_command(options: HistoryOptions) -> Result<()> {
+    let journal = SwitchJournal::new()?;
+
+    let filter = HistoryFilter {
+        validator: options.validator.clone(),
+        trigger: options.trigger.as_deref().map(str::parse).transpose()?,
+        since: options.since.as_deref().map(parse_since).transpose()?,
+        failed_only: options.failed_only,
+        include_dry_runs: options.include_dry_runs,
+    };
+
+    let mut records: Vec<SwitchRecord> = journal
+        .load()?
+        .into_iter()
+        .filter(|record| filter.matches(record))
+        .collect();
+
+    if let Some(limit) = options.limit {
+        let skip = records.len().saturating_sub(limit);
+        records.drain(..skip);
+    }
+
+    let export = match options.format.as_deref() {
+        None => None,
+        Some("json") => Some(serde_json::to_string_pretty(&records)?),
+        Some("csv") => Some(records_to_csv(&records)),
+        Some(other) => {
+            return Err(anyhow!(
+                "Unsupported export format '{}' (expected json or csv)",
+                other
+            ))
+        }
+    };
+
+    match (export, options.output) {
+        (Some(content), Some(path)) => {
+            std::fs::write(&path, content)?;
+            println!(
+                "{}",
+                format!("✅ Exported {} switch record(s) to {}", records.len(), path).green()
+            );
+        }
+        (Some(content), None) => print!("{}", content),
+        (None, _) => display_history_table(&records, &journal),
+    }
+
+    Ok(())
+}
+
+/// Accept either a date (YYYY-MM-DD, midnight UTC) or a full RFC 3339 timestamp
+fn parse_since(value: &str) -> Result<chrono::DateTime<chrono::Utc>> {
+    if let Ok(date) = chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return Ok(date.and_hms_opt(0, 0, 0).unwrap().and_utc());
+    }
+
+    chrono::DateTime::parse_from_rfc3339(value)
+        .map(|ts| ts.with_timezone(&chrono::Utc))
+        .map_err(|_| {
+            anyhow!(
+                "Invalid --since value '{}' (use YYYY-MM-DD or RFC 3339)",
+                value
+            )
+        })
+}
+
+fn display_history_table(records: &[SwitchRecord], journal: &SwitchJournal) {
+    println!("\n{}", "📜 Switch History".bright_cyan().bold());
+    println!(
+        "{}",
+        format!("Journal: {}", journal.path().display()).dimmed()
+    );
+    println!();
+
+    if records.is_empty() {
+        println!("{}", "No switch records found".yellow());
+        return;
+    }
+
+    let mut table = Table::new();
+    table
+        .load_preset(comfy_table::presets::UTF8_BORDERS_ONLY)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_content_arrangement(ContentArrangement::Dynamic);
+
+    table.set_header(vec![
+        Cell::new("Time (UTC)").add_attribute(Attribute::Bold),
+        Cell::new("Trigger").add_attribute(Attribute::Bold),
+        Cell::new("Result").add_attribute(Attribute::Bold),
+        Cell::new("From → To").add_attribute(Attribute::Bold),
+        Cell::new("Switch").add_attribute(Attribute::Bold),
+        Cell::new("Tower").add_attribute(Attribute::Bold),
+        Cell::new("First Vote").add_attribute(Attribute::Bold),
+        Cell::new("Operator").add_attribute(Attribute::Bold),
+        Cell::new("Errors").add_attribute(Attribute::Bold),
+    ]);
+
+    let ms = |v: Option<u64>| {
+        v.map(|v| format!("{}ms", v))
+            .unwrap_or_else(|| "-".to_string())
+    };
+
+    for record in records {
+        let timestamp = chrono::DateTime::parse_from_rfc3339(&record.timestamp)
+            .map(|ts| {
+                ts.with_timezone(&chrono::Utc)
+                    .format("%Y-%m-%d %H:%M:%S")
+                    .to_string()
+            })
+            .unwrap_or_else(|_| record.timestamp.clone());
+
+        let (result, color) = if record.dry_run {
+            ("DRY RUN", Color::DarkGrey)
+        } else if record.success {
+            ("OK", Color::Green)
+        } else {
+            ("FAILED", Color::Red)
+        };
+
+        table.add_row(vec![
+            Cell::new(timestamp),
+            Cell::new(record.trigger.as_str()),
+            Cell::new(result).fg(color),
+            Cell::new(format!(
+                "{} ({}) → {} ({})",
+                record.from_node.label,
+                record.from_node.validator_type,
+                record.to_node.label,
+                record.to_node.validator_type
+            )),
+            Cell::new(ms(record.steps.identity_switch_ms)),
+            Cell::new(ms(record.steps.tower_transfer_ms)),
+            Cell::new(ms(record.steps.first_vote_ms)),
+            Cell::new(&record.operator_host),
+            Cell::new(record.errors.join("; ")).fg(Color::Red),
+        ]);
+    }
+
+    println!("{}", table);
+    println!("\n{}", format!("{} record(s)", records.len()).dimmed());
+}
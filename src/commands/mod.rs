@@ -1,9 +1,15 @@
+pub mod batch_switch;
 pub mod error_handler;
+pub mod history;
+pub mod monitor;
 pub mod status;
 pub mod status_ui_v2;
 pub mod switch;
 pub mod test_alert;
 
+pub use batch_switch::batch_switch_command;
+pub use history::{history_command, HistoryOptions};
+pub use monitor::{monitor_command, LogFormat, MonitorOptions};
 pub use status::status_command;
-pub use switch::switch_command;
+pub use switch::{switch_command, SwitchOptions};
 pub use test_alert::test_alert_command;
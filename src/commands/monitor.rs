@@ -0,0 +1,248 @@
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+use std::io::Write;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::signal::unix::{signal, SignalKind};
+use tokio::sync::RwLock;
+
+use crate::monitor::{EventLevel, MonitorEngine, MonitorEvent};
+use crate::switch_state::{SwitchState, SwitchStateStore};
+use crate::AppState;
+
+/// Format of the lines `svs monitor` writes to stdout
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LogFormat {
+    /// One JSON object per line
+    Json,
+    /// `<timestamp> <LEVEL> <event> [<validator>] <message>`
+    Text,
+}
+
+impl std::str::FromStr for LogFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "json" => Ok(Self::Json),
+            "text" => Ok(Self::Text),
+            other => Err(anyhow!(
+                "Unsupported log format '{}' (expected json or text)",
+                other
+            )),
+        }
+    }
+}
+
+/// Options for `svs monitor`
+pub struct MonitorOptions {
+    pub interval_seconds: u64,
+    pub log_format: LogFormat,
+    /// Force the auto-failover policy into dry-run mode
+    pub dry_run: bool,
+}
+
+#[derive(Serialize)]
+struct LogLine<'a> {
+    timestamp: String,
+    #[serde(flatten)]
+    event: &'a MonitorEvent,
+}
+
+fn format_line(format: LogFormat, timestamp: String, event: &MonitorEvent) -> String {
+    match format {
+        LogFormat::Json => serde_json::to_string(&LogLine { timestamp, event })
+            .unwrap_or_else(|e| format!("{{\"error\":\"{}\"}}", e)),
+        LogFormat::Text => {
+            let level = match event.level {
+                EventLevel::Info => "INFO",
+                EventLevel::Warning => "WARN",
+                EventLevel::Error => "ERROR",
+            };
+            let validator = if event.validator.is_empty() {
+                String::new()
+            } else {
+                format!(" [{}]", event.validator)
+            };
+            format!(
+                "{} {:<5} {}{} {}",
+                timestamp, level, event.event, validator, event.message
+            )
+        }
+    }
+}
+
+fn log(format: LogFormat, event: &MonitorEvent) {
+    let timestamp = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
+    let mut stdout = std::io::stdout().lock();
+    let _ = writeln!(stdout, "{}", format_line(format, timestamp, event));
+    let _ = stdout.flush();
+}
+
+fn lifecycle(level: EventLevel, event: &'static str, message: String) -> MonitorEvent {
+    MonitorEvent {
+        level,
+        event,
+        validator: String::new(),
+        message,
+    }
+}
+
+/// Warnings for switches of monitored validators that a crash or Ctrl-C left unfinished;
+/// they are resolved by starting svs interactively
+fn unfinished_switch_events(app_state: &AppState, states: &[SwitchState]) -> Vec<MonitorEvent> {
+    states
+        .iter()
+        .filter(|state| {
+            app_state
+                .validator_statuses
+                .iter()
+                .any(|v| v.validator_pair.identity_pubkey == state.validator_identity)
+        })
+        .map(|state| MonitorEvent {
+            level: EventLevel::Warning,
+            event: "unfinished_switch",
+            validator: state.validator_identity.clone(),
+            message: format!(
+                "switch {} → {} started {} on {} stopped at: {}; run svs interactively to resume or roll back",
+                state.from_node,
+                state.to_node,
+                state.started_at,
+                state.operator_host,
+                state.phase.describe()
+            ),
+        })
+        .collect()
+}
+
+/// Run delinquency alerts, auto-failover and the double-identity guard without the
+/// dashboard until SIGTERM or SIGINT, e.g. as a systemd service
+pub async fn monitor_command(options: MonitorOptions, mut app_state: AppState) -> Result<()> {
+    // Takeover progress is printed between the log lines, keep it free of escape codes
+    colored::control::set_override(false);
+
+    let format = options.log_format;
+    if options.dry_run {
+        if let Some(config) = app_state.config.alert_config.as_mut() {
+            config.auto_failover_policy.dry_run = true;
+        }
+    }
+    let auto_failover = match &app_state.config.alert_config {
+        Some(config) if config.enabled && config.auto_failover_enabled => {
+            if config.auto_failover_policy.dry_run {
+                "enabled (dry run)"
+            } else {
+                "enabled"
+            }
+        }
+        _ => "disabled",
+    };
+    log(
+        format,
+        &lifecycle(
+            EventLevel::Info,
+            "monitor_started",
+            format!(
+                "monitoring {} validator(s) every {}s, auto-failover {}",
+                app_state.validator_statuses.len(),
+                options.interval_seconds,
+                auto_failover
+            ),
+        ),
+    );
+
+    match SwitchStateStore::new().and_then(|store| store.load_unfinished()) {
+        Ok(states) => {
+            for event in unfinished_switch_events(&app_state, &states) {
+                log(format, &event);
+            }
+        }
+        Err(e) => log(
+            format,
+            &lifecycle(
+                EventLevel::Warning,
+                "unfinished_switch",
+                format!("could not check for interrupted switches: {}", e),
+            ),
+        ),
+    }
+
+    let mut sigterm = signal(SignalKind::terminate())?;
+    let mut sigint = signal(SignalKind::interrupt())?;
+    let mut engine = MonitorEngine::new(Arc::new(app_state), Arc::new(RwLock::new(false)));
+    let mut interval = tokio::time::interval(Duration::from_secs(options.interval_seconds));
+
+    // A signal arriving during a tick is picked up once the tick is done
+    let signal_name = loop {
+        tokio::select! {
+            _ = interval.tick() => {}
+            _ = sigterm.recv() => break "SIGTERM",
+            _ = sigint.recv() => break "SIGINT",
+        }
+        for event in engine.tick().await {
+            log(format, &event);
+        }
+    };
+
+    log(
+        format,
+        &lifecycle(
+            EventLevel::Info,
+            "shutdown",
+            format!("received {}, shutting down", signal_name),
+        ),
+    );
+    // Leaving a takeover halfway is worse than stopping late
+    if engine.is_failing_over() {
+        log(
+            format,
+            &lifecycle(
+                EventLevel::Warning,
+                "shutdown_waiting",
+                "waiting for the running emergency takeover to finish".to_string(),
+            ),
+        );
+    }
+    for event in engine.shutdown().await {
+        log(format, &event);
+    }
+    log(
+        format,
+        &lifecycle(EventLevel::Info, "monitor_stopped", "stopped".to_string()),
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_lines() {
+        let event = MonitorEvent {
+            level: EventLevel::Warning,
+            event: "delinquent",
+            validator: "Ident111".to_string(),
+            message: "no new vote for 45s".to_string(),
+        };
+        let timestamp = "2026-10-17T08:00:00.000Z".to_string();
+
+        let json: serde_json::Value =
+            serde_json::from_str(&format_line(LogFormat::Json, timestamp.clone(), &event)).unwrap();
+        assert_eq!(json["timestamp"], "2026-10-17T08:00:00.000Z");
+        assert_eq!(json["level"], "warning");
+        assert_eq!(json["event"], "delinquent");
+        assert_eq!(json["validator"], "Ident111");
+
+        assert_eq!(
+            format_line(LogFormat::Text, timestamp.clone(), &event),
+            "2026-10-17T08:00:00.000Z WARN  delinquent [Ident111] no new vote for 45s"
+        );
+        let stopped = lifecycle(EventLevel::Info, "monitor_stopped", "stopped".to_string());
+        assert_eq!(
+            format_line(LogFormat::Text, timestamp, &stopped),
+            "2026-10-17T08:00:00.000Z INFO  monitor_stopped stopped"
+        );
+        assert!("yaml".parse::<LogFormat>().is_err());
+    }
+}
@@ -48,124 +48,11 @@ use tokio::sync::RwLock;
 use tokio::time::interval;
 
 // Required imports for alerts and vote data
-use crate::alert::AlertManager;
-use crate::solana_rpc::{fetch_vote_account_data, ValidatorVoteData};
+use crate::monitor::{EventLevel, MonitorEngine, QuorumSummary};
+use crate::solana_rpc::ValidatorVoteData;
 use crate::types::{FailureTracker, NodeHealthStatus};
 use crate::{ssh::AsyncSshPool, AppState};
 
-/// Refresh vote data for all validators and send alerts
-async fn refresh_vote_data_for_alerts(
-    app_state: Arc<AppState>,
-    ui_state: Arc<RwLock<UiState>>,
-    log_sender: tokio::sync::mpsc::UnboundedSender<LogMessage>,
-    _alert_manager: Option<AlertManager>,
-) {
-    let mut new_vote_data = Vec::new();
-
-    // Fetch vote data for all validators
-    for (idx, validator_status) in app_state.validator_statuses.iter().enumerate() {
-        let validator_pair = &validator_status.validator_pair;
-
-        match fetch_vote_account_data(&validator_pair.rpc, &validator_pair.vote_pubkey).await {
-            Ok(data) => {
-                // Update RPC success
-                if let Ok(mut state) = ui_state.try_write() {
-                    state.rpc_failure_tracker[idx].record_success();
-                }
-
-                let _ = log_sender.send(LogMessage {
-                    host: format!("validator-{}", idx),
-                    message: format!(
-                        "Vote data fetched: last slot {}",
-                        data.recent_votes.last().map(|v| v.slot).unwrap_or(0)
-                    ),
-                    timestamp: Instant::now(),
-                    level: LogLevel::Info,
-                });
-
-                new_vote_data.push(Some(data));
-            }
-            Err(e) => {
-                // Update RPC failure
-                if let Ok(mut state) = ui_state.try_write() {
-                    state.rpc_failure_tracker[idx].record_failure(e.to_string());
-                }
-
-                let _ = log_sender.send(LogMessage {
-                    host: format!("validator-{}", idx),
-                    message: format!("Failed to fetch vote data: {}", e),
-                    timestamp: Instant::now(),
-                    level: LogLevel::Error,
-                });
-
-                new_vote_data.push(None);
-            }
-        }
-    }
-
-    // Update UI state and check for delinquency alerts
-    if let Ok(mut state) = ui_state.try_write() {
-        // Update vote data
-        let mut new_slot_times = Vec::new();
-        let mut new_increments = Vec::new();
-
-        for (idx, new_data) in new_vote_data.iter().enumerate() {
-            if let Some(new) = new_data {
-                let new_last_slot = new.recent_votes.last().map(|v| v.slot);
-
-                // Check if this is a new slot
-                if let Some(new_slot) = new_last_slot {
-                    // Check against our tracked slot time
-                    let should_update_slot_time = if let Some(tracked) =
-                        state.last_vote_slot_times.get(idx).and_then(|&v| v)
-                    {
-                        tracked.0 != new_slot // Slot has changed
-                    } else {
-                        true // No previous tracking
-                    };
-
-                    if should_update_slot_time {
-                        new_slot_times.push(Some((new_slot, Instant::now())));
-                    } else {
-                        // Slot hasn't changed, keep existing time
-                        new_slot_times.push(state.last_vote_slot_times.get(idx).and_then(|&v| v));
-
-                        // NOTE: Delinquency checking is handled in the main monitoring loop
-                        // with proper alert throttling via alert_tracker.delinquency_tracker
-                    }
-
-                    // Handle increment display
-                    if let Some(old) = state.vote_data.get(idx).and_then(|v| v.as_ref()) {
-                        if let Some(old_last_slot) = old.recent_votes.last().map(|v| v.slot) {
-                            if new_slot > old_last_slot {
-                                new_increments.push(Some(Instant::now()));
-                            } else {
-                                new_increments.push(None);
-                            }
-                        } else {
-                            new_increments.push(None);
-                        }
-                    } else {
-                        new_increments.push(None);
-                    }
-                } else {
-                    new_increments.push(None);
-                    new_slot_times.push(None);
-                }
-            } else {
-                new_increments.push(None);
-                new_slot_times.push(state.last_vote_slot_times.get(idx).and_then(|&v| v));
-            }
-        }
-
-        // Update state
-        state.vote_data = new_vote_data;
-        state.increment_times = new_increments;
-        state.last_vote_slot_times = new_slot_times;
-        state.last_vote_refresh = Instant::now();
-    }
-}
-
 /// View states for the UI
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ViewState {
@@ -304,18 +191,7 @@ async fn handle_refresh_with_timeout(
 
         // Set all field refresh states to true
         for refresh_state in ui_state_write.field_refresh_states.iter_mut() {
-            refresh_state.node_0.status_refreshing = true;
-            refresh_state.node_0.identity_refreshing = true;
-            refresh_state.node_0.version_refreshing = true;
-            refresh_state.node_0.ssh_connectivity_refreshing = true;
-            refresh_state.node_0.rpc_health_refreshing = true;
-            refresh_state.node_0.swap_readiness_refreshing = true;
-            refresh_state.node_1.status_refreshing = true;
-            refresh_state.node_1.identity_refreshing = true;
-            refresh_state.node_1.version_refreshing = true;
-            refresh_state.node_1.ssh_connectivity_refreshing = true;
-            refresh_state.node_1.rpc_health_refreshing = true;
-            refresh_state.node_1.swap_readiness_refreshing = true;
+            refresh_state.mark_all_refreshing();
         }
     }
 
@@ -355,18 +231,7 @@ async fn handle_validator_switch_with_timeout(
 
         // Set all field refresh states to true for the new validator
         for refresh_state in ui_state_write.field_refresh_states.iter_mut() {
-            refresh_state.node_0.status_refreshing = true;
-            refresh_state.node_0.identity_refreshing = true;
-            refresh_state.node_0.version_refreshing = true;
-            refresh_state.node_0.ssh_connectivity_refreshing = true;
-            refresh_state.node_0.rpc_health_refreshing = true;
-            refresh_state.node_0.swap_readiness_refreshing = true;
-            refresh_state.node_1.status_refreshing = true;
-            refresh_state.node_1.identity_refreshing = true;
-            refresh_state.node_1.version_refreshing = true;
-            refresh_state.node_1.ssh_connectivity_refreshing = true;
-            refresh_state.node_1.rpc_health_refreshing = true;
-            refresh_state.node_1.swap_readiness_refreshing = true;
+            refresh_state.mark_all_refreshing();
         }
     }
 
@@ -404,32 +269,29 @@ pub struct UiState {
     pub increment_times: Vec<Option<Instant>>,
     pub selected_validator_index: usize,
 
-    // Track when each validator's last vote slot changed
-    pub last_vote_slot_times: Vec<Option<(u64, Instant)>>, // (slot, time when slot last changed)
+    // How the RPC endpoints of each validator see its votes (with more than one endpoint)
+    pub rpc_quorum: Vec<Option<QuorumSummary>>,
 
     // Catchup status for each node
-    pub catchup_data: Vec<NodePairStatus>,
+    pub catchup_data: Vec<NodeCatchupStatuses>,
 
     // Track consecutive catchup failures for standby nodes
     #[allow(dead_code)]
-    pub catchup_failure_counts: Vec<(u32, u32)>, // (node_0_failures, node_1_failures)
+    pub catchup_failure_counts: Vec<Vec<u32>>, // failures per node
 
     // Track last alert time for catchup failures
     #[allow(dead_code)]
-    pub last_catchup_alert_times: Vec<(Option<Instant>, Option<Instant>)>, // (node_0_last_alert, node_1_last_alert)
+    pub last_catchup_alert_times: Vec<Vec<Option<Instant>>>, // last alert per node
 
     // SSH health status for each node
-    pub ssh_health_data: Vec<NodePairSshStatus>,
+    pub ssh_health_data: Vec<NodeSshStatuses>,
 
     // RPC health status for each node
-    pub rpc_health_data: Vec<NodePairRpcStatus>,
+    pub rpc_health_data: Vec<NodeRpcStatuses>,
 
     // Comprehensive health tracking for each validator
     pub validator_health: Vec<NodeHealthStatus>,
 
-    // RPC failure tracking for each validator
-    pub rpc_failure_tracker: Vec<FailureTracker>,
-
     // Refresh state
     pub last_vote_refresh: Instant,
     pub last_catchup_refresh: Instant,
@@ -450,8 +312,21 @@ pub struct UiState {
 
 #[derive(Debug, Clone)]
 pub struct NodeFieldRefreshState {
-    pub node_0: FieldRefreshStates,
-    pub node_1: FieldRefreshStates,
+    pub nodes: Vec<FieldRefreshStates>,
+}
+
+impl NodeFieldRefreshState {
+    /// Flag the fields updated by a full refresh on every node
+    fn mark_all_refreshing(&mut self) {
+        for node in self.nodes.iter_mut() {
+            node.status_refreshing = true;
+            node.identity_refreshing = true;
+            node.version_refreshing = true;
+            node.ssh_connectivity_refreshing = true;
+            node.rpc_health_refreshing = true;
+            node.swap_readiness_refreshing = true;
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -484,9 +359,9 @@ impl Default for FieldRefreshStates {
 // Removed FocusedPane enum as logs are no longer displayed
 
 #[derive(Clone)]
-pub struct NodePairStatus {
-    pub node_0: Option<CatchupStatus>,
-    pub node_1: Option<CatchupStatus>,
+#[allow(dead_code)] // Only read by the catchup streaming tasks, which are currently disabled
+pub struct NodeCatchupStatuses {
+    pub nodes: Vec<Option<CatchupStatus>>,
 }
 
 #[derive(Clone)]
@@ -498,15 +373,13 @@ pub struct CatchupStatus {
 }
 
 #[derive(Clone)]
-pub struct NodePairSshStatus {
-    pub node_0: SshHealthStatus,
-    pub node_1: SshHealthStatus,
+pub struct NodeSshStatuses {
+    pub nodes: Vec<SshHealthStatus>,
 }
 
 #[derive(Clone)]
-pub struct NodePairRpcStatus {
-    pub node_0: RpcHealthStatus,
-    pub node_1: RpcHealthStatus,
+pub struct NodeRpcStatuses {
+    pub nodes: Vec<RpcHealthStatus>,
 }
 
 #[derive(Clone)]
@@ -554,74 +427,57 @@ impl EnhancedStatusApp {
         for validator_status in &app_state.validator_statuses {
             initial_vote_data.push(None);
 
-            // Initialize catchup status for standby nodes
-            let mut node_pair = NodePairStatus {
-                node_0: None,
-                node_1: None,
+            // Initialize catchup status for standby nodes or Firedancer nodes
+            let catchup_statuses = NodeCatchupStatuses {
+                nodes: validator_status
+                    .nodes_with_status
+                    .iter()
+                    .map(|node| {
+                        (node.status == crate::types::NodeStatus::Standby
+                            || node.validator_type == crate::types::ValidatorType::Firedancer)
+                            .then(|| CatchupStatus {
+                                status: "⏳ Initializing...".to_string(),
+                                last_updated: Instant::now(),
+                                is_streaming: false,
+                            })
+                    })
+                    .collect(),
             };
+            initial_catchup_data.push(catchup_statuses);
 
-            if validator_status.nodes_with_status.len() >= 2 {
-                // Initialize for standby nodes or Firedancer nodes
-                if validator_status.nodes_with_status[0].status == crate::types::NodeStatus::Standby
-                    || validator_status.nodes_with_status[0].validator_type
-                        == crate::types::ValidatorType::Firedancer
-                {
-                    node_pair.node_0 = Some(CatchupStatus {
-                        status: "⏳ Initializing...".to_string(),
-                        last_updated: Instant::now(),
-                        is_streaming: false,
-                    });
-                }
-                if validator_status.nodes_with_status[1].status == crate::types::NodeStatus::Standby
-                    || validator_status.nodes_with_status[1].validator_type
-                        == crate::types::ValidatorType::Firedancer
-                {
-                    node_pair.node_1 = Some(CatchupStatus {
-                        status: "⏳ Initializing...".to_string(),
-                        last_updated: Instant::now(),
-                        is_streaming: false,
-                    });
-                }
-            }
-
-            initial_catchup_data.push(node_pair);
-
-            let ssh_pair = NodePairSshStatus {
-                node_0: SshHealthStatus {
-                    is_healthy: true,
-                    last_success: Some(Instant::now()),
-                    failure_start: None,
-                },
-                node_1: SshHealthStatus {
-                    is_healthy: true,
-                    last_success: Some(Instant::now()),
-                    failure_start: None,
-                },
+            let ssh_statuses = NodeSshStatuses {
+                nodes: validator_status
+                    .nodes_with_status
+                    .iter()
+                    .map(|_| SshHealthStatus {
+                        is_healthy: true,
+                        last_success: Some(Instant::now()),
+                        failure_start: None,
+                    })
+                    .collect(),
             };
-            initial_ssh_health_data.push(ssh_pair);
+            initial_ssh_health_data.push(ssh_statuses);
         }
 
         // Initialize RPC health data
-        let mut initial_rpc_health_data = Vec::new();
-        for _ in 0..app_state.validator_statuses.len() {
-            let rpc_pair = NodePairRpcStatus {
-                node_0: RpcHealthStatus {
-                    is_healthy: false,
-                    last_check: None,
-                    error_message: None,
-                },
-                node_1: RpcHealthStatus {
-                    is_healthy: false,
-                    last_check: None,
-                    error_message: None,
-                },
-            };
-            initial_rpc_health_data.push(rpc_pair);
-        }
+        let initial_rpc_health_data = app_state
+            .validator_statuses
+            .iter()
+            .map(|validator_status| NodeRpcStatuses {
+                nodes: validator_status
+                    .nodes_with_status
+                    .iter()
+                    .map(|_| RpcHealthStatus {
+                        is_healthy: false,
+                        last_check: None,
+                        error_message: None,
+                    })
+                    .collect(),
+            })
+            .collect();
 
         // Initialize health tracking
         let mut initial_validator_health = Vec::new();
-        let mut initial_rpc_trackers = Vec::new();
         for _ in 0..app_state.validator_statuses.len() {
             initial_validator_health.push(NodeHealthStatus {
                 ssh_status: FailureTracker::new(),
@@ -630,14 +486,17 @@ impl EnhancedStatusApp {
                 last_vote_slot: None,
                 last_vote_time: None,
             });
-            initial_rpc_trackers.push(FailureTracker::new());
         }
 
         // Initialize field refresh states
-        let initial_field_refresh_states = (0..app_state.validator_statuses.len())
-            .map(|_| NodeFieldRefreshState {
-                node_0: FieldRefreshStates::default(),
-                node_1: FieldRefreshStates::default(),
+        let initial_field_refresh_states = app_state
+            .validator_statuses
+            .iter()
+            .map(|validator_status| NodeFieldRefreshState {
+                nodes: vec![
+                    FieldRefreshStates::default();
+                    validator_status.nodes_with_status.len()
+                ],
             })
             .collect();
 
@@ -646,14 +505,21 @@ impl EnhancedStatusApp {
             previous_last_slots: Vec::new(),
             increment_times: Vec::new(),
             selected_validator_index: app_state.selected_validator_index,
-            last_vote_slot_times: vec![None; app_state.validator_statuses.len()],
+            rpc_quorum: vec![None; app_state.validator_statuses.len()],
             catchup_data: initial_catchup_data,
-            catchup_failure_counts: vec![(0, 0); app_state.validator_statuses.len()],
-            last_catchup_alert_times: vec![(None, None); app_state.validator_statuses.len()],
+            catchup_failure_counts: app_state
+                .validator_statuses
+                .iter()
+                .map(|v| vec![0; v.nodes_with_status.len()])
+                .collect(),
+            last_catchup_alert_times: app_state
+                .validator_statuses
+                .iter()
+                .map(|v| vec![None; v.nodes_with_status.len()])
+                .collect(),
             ssh_health_data: initial_ssh_health_data,
             rpc_health_data: initial_rpc_health_data,
             validator_health: initial_validator_health,
-            rpc_failure_tracker: initial_rpc_trackers,
             last_vote_refresh: Instant::now(),
             last_catchup_refresh: Instant::now(),
             last_ssh_health_refresh: Instant::now(),
@@ -678,6 +544,7 @@ impl EnhancedStatusApp {
     }
 
     /// Spawn continuous catchup streaming tasks for each node
+    #[allow(dead_code)] // Catchup streaming is currently disabled
     fn spawn_catchup_streaming_tasks(&self) {
         let ui_state = Arc::clone(&self.ui_state);
         let app_state = Arc::clone(&self.app_state);
@@ -713,22 +580,13 @@ impl EnhancedStatusApp {
 
     /// Spawn background tasks for data fetching
     pub fn spawn_background_tasks(&self) {
-        // Unified refresh task that runs every 10 seconds and includes vote data for alerts
+        // Unified refresh task that runs every 10 seconds
         let ui_state_for_refresh = Arc::clone(&self.ui_state);
         let app_state_for_refresh = Arc::clone(&self.app_state);
-        let log_sender = self.log_sender.clone();
 
         tokio::spawn(async move {
             let mut interval = interval(Duration::from_secs(10));
 
-            // Initialize alert manager if alerts are configured
-            let alert_manager = app_state_for_refresh
-                .config
-                .alert_config
-                .as_ref()
-                .filter(|config| config.enabled)
-                .map(|config| AlertManager::new(config.clone()));
-
             loop {
                 interval.tick().await;
 
@@ -748,37 +606,10 @@ impl EnhancedStatusApp {
                     let selected_idx = state.selected_validator_index;
                     if selected_idx < state.field_refresh_states.len() {
                         let refresh_state = &mut state.field_refresh_states[selected_idx];
-                        refresh_state.node_0.status_refreshing = true;
-                        refresh_state.node_0.identity_refreshing = true;
-                        refresh_state.node_0.version_refreshing = true;
-                        refresh_state.node_0.ssh_connectivity_refreshing = true;
-                        refresh_state.node_0.rpc_health_refreshing = true;
-                        refresh_state.node_0.swap_readiness_refreshing = true;
-                        refresh_state.node_1.status_refreshing = true;
-                        refresh_state.node_1.identity_refreshing = true;
-                        refresh_state.node_1.version_refreshing = true;
-                        refresh_state.node_1.ssh_connectivity_refreshing = true;
-                        refresh_state.node_1.rpc_health_refreshing = true;
-                        refresh_state.node_1.swap_readiness_refreshing = true;
+                        refresh_state.mark_all_refreshing();
                     }
                 }
 
-                // Fetch vote data for all validators (needed for alerts)
-                let ui_state_vote = ui_state_for_refresh.clone();
-                let app_state_vote = app_state_for_refresh.clone();
-                let log_sender_vote = log_sender.clone();
-                let alert_manager_vote = alert_manager.clone();
-
-                tokio::spawn(async move {
-                    refresh_vote_data_for_alerts(
-                        app_state_vote,
-                        ui_state_vote,
-                        log_sender_vote,
-                        alert_manager_vote,
-                    )
-                    .await;
-                });
-
                 // Do the UI field refresh
                 let ui_state_clone = ui_state_for_refresh.clone();
                 let app_state_clone = app_state_for_refresh.clone();
@@ -789,8 +620,60 @@ impl EnhancedStatusApp {
             }
         });
 
-        // That's it! Just one background refresh task
-        // All other tasks (vote data alerts, catchup status, ssh health) are removed
+        // Vote data, delinquency alerts, auto-failover and the double-identity guard,
+        // shared with `svs monitor`
+        let ui_state_for_monitor = Arc::clone(&self.ui_state);
+        let log_sender = self.log_sender.clone();
+        let mut engine = MonitorEngine::new(
+            Arc::clone(&self.app_state),
+            Arc::clone(&self.emergency_takeover_in_progress),
+        );
+
+        tokio::spawn(async move {
+            let mut interval = interval(Duration::from_secs(10));
+
+            loop {
+                interval.tick().await;
+
+                for event in engine.tick().await {
+                    let _ = log_sender.send(LogMessage {
+                        host: event.validator,
+                        message: format!("{}: {}", event.event, event.message),
+                        timestamp: Instant::now(),
+                        level: match event.level {
+                            EventLevel::Info => LogLevel::Info,
+                            EventLevel::Warning => LogLevel::Warning,
+                            EventLevel::Error => LogLevel::Error,
+                        },
+                    });
+                }
+
+                let mut state = ui_state_for_monitor.write().await;
+                let validator_count = state.vote_data.len();
+                let mut increment_times = Vec::with_capacity(validator_count);
+                for idx in 0..validator_count {
+                    let new_data = engine.watch(idx).and_then(|w| w.vote_data.clone());
+                    let new_slot = new_data
+                        .as_ref()
+                        .and_then(|d| d.recent_votes.last().map(|v| v.slot));
+                    let old_slot = state.vote_data[idx]
+                        .as_ref()
+                        .and_then(|d| d.recent_votes.last().map(|v| v.slot));
+
+                    // Flash the vote increment when the last vote slot moved
+                    increment_times.push(match (new_slot, old_slot) {
+                        (Some(new), Some(old)) if new > old => Some(Instant::now()),
+                        _ => None,
+                    });
+                    if new_data.is_some() {
+                        state.vote_data[idx] = new_data;
+                    }
+                    state.rpc_quorum[idx] = engine.quorum_summary(idx);
+                }
+                state.increment_times = increment_times;
+                state.last_vote_refresh = Instant::now();
+            }
+        });
     }
 }
 
@@ -1019,6 +902,7 @@ impl EnhancedStatusApp {
                                                     let ssh_pool = app_state.ssh_pool.clone();
                                                     let ssh_keys = app_state.detected_ssh_keys.clone();
                                                     let emergency_flag = emergency_takeover_flag.clone();
+                                                    let switch_policy = app_state.config.switch_policy.clone().unwrap_or_default();
 
                                                     tokio::spawn(async move {
                                                         execute_emergency_failover(
@@ -1027,6 +911,7 @@ impl EnhancedStatusApp {
                                                             ssh_pool,
                                                             ssh_keys,
                                                             emergency_flag,
+                                                            switch_policy,
                                                         ).await;
                                                     });
                                                 } else {
@@ -1737,30 +1622,14 @@ async fn stream_catchup_for_node(
 ) {
     loop {
         // Determine the catchup command based on node type
-        let catchup_command = if node.validator_type == crate::types::ValidatorType::Firedancer {
-            // For Firedancer, use fdctl status
-            if let Some(fdctl) = &node.fdctl_executable {
-                // Also wrap fdctl in bash -c for consistency
-                format!("bash -c '{} status'", fdctl)
-            } else {
+        let backend = crate::validator_backend::backend_for(&node.validator_type);
+        let catchup_command = match backend.and_then(|backend| backend.catchup_command(&node)) {
+            Some(command) => command,
+            None => {
                 // Sleep and retry
                 tokio::time::sleep(Duration::from_secs(30)).await;
                 continue;
             }
-        } else {
-            // For Agave/Jito, use solana catchup
-            let solana_cli = if let Some(cli) = &node.solana_cli_executable {
-                cli.clone()
-            } else if let Some(validator) = &node.agave_validator_executable {
-                validator.replace("agave-validator", "solana")
-            } else {
-                // Sleep and retry
-                tokio::time::sleep(Duration::from_secs(30)).await;
-                continue;
-            };
-
-            // Need to use bash -c to properly handle the command with its full path
-            format!("bash -c '{} catchup --our-localhost 2>&1'", solana_cli)
         };
 
         // Log the command being executed
@@ -1780,7 +1649,6 @@ async fn stream_catchup_for_node(
 
         // Process streaming output
         let ui_state_clone = Arc::clone(&ui_state);
-        let is_firedancer = node.validator_type == crate::types::ValidatorType::Firedancer;
         let process_task = tokio::spawn(async move {
             while let Some(line) = rx.recv().await {
                 let last_output = line.trim().to_string();
@@ -1788,7 +1656,9 @@ async fn stream_catchup_for_node(
                 // Update UI state with the latest output
                 let mut state = ui_state_clone.write().await;
                 if let Some(catchup_data) = state.catchup_data.get_mut(validator_idx) {
-                    let status = parse_catchup_output(&last_output, is_firedancer);
+                    let status = backend
+                        .map(|backend| backend.catchup_status(&last_output))
+                        .unwrap_or_else(|| last_output.clone());
 
                     let catchup_status = CatchupStatus {
                         status,
@@ -1796,10 +1666,8 @@ async fn stream_catchup_for_node(
                         is_streaming: true,
                     };
 
-                    if node_idx == 0 {
-                        catchup_data.node_0 = Some(catchup_status);
-                    } else {
-                        catchup_data.node_1 = Some(catchup_status);
+                    if let Some(slot) = catchup_data.nodes.get_mut(node_idx) {
+                        *slot = Some(catchup_status);
                     }
                 }
             }
@@ -1826,14 +1694,8 @@ async fn stream_catchup_for_node(
         {
             let mut state = ui_state.write().await;
             if let Some(catchup_data) = state.catchup_data.get_mut(validator_idx) {
-                if node_idx == 0 {
-                    if let Some(ref mut status) = catchup_data.node_0 {
-                        status.is_streaming = false;
-                    }
-                } else {
-                    if let Some(ref mut status) = catchup_data.node_1 {
-                        status.is_streaming = false;
-                    }
+                if let Some(Some(status)) = catchup_data.nodes.get_mut(node_idx) {
+                    status.is_streaming = false;
                 }
             }
         }
@@ -1843,56 +1705,6 @@ async fn stream_catchup_for_node(
     }
 }
 
-/// Parse catchup output to extract status
-fn parse_catchup_output(output: &str, is_firedancer: bool) -> String {
-    if is_firedancer {
-        // For Firedancer, check if it's running
-        if output.contains("running") {
-            "Caught up".to_string()
-        } else {
-            "Not running".to_string()
-        }
-    } else {
-        // For Agave/Jito, parse the catchup output
-        if output.contains("0 slot(s)") || output.contains("has caught up") {
-            "Caught up".to_string()
-        } else if let Some(pos) = output.find(" slot(s) behind") {
-            let start = output[..pos].rfind(' ').map(|i| i + 1).unwrap_or(0);
-            let slots_str = &output[start..pos];
-            if let Ok(slots) = slots_str.parse::<u64>() {
-                format!("{} slots behind", slots)
-            } else {
-                output.to_string()
-            }
-        } else if output.contains("bash:") && output.contains("line") {
-            // Parse bash errors more nicely
-            if output.contains("command not found") || output.contains("No such file") {
-                "CLI not found".to_string()
-            } else {
-                "Command error".to_string()
-            }
-        } else if output.contains("Error") || output.contains("error") {
-            if output.contains("RPC") {
-                "RPC Error".to_string()
-            } else if output.contains("connection") {
-                "Connection Error".to_string()
-            } else {
-                "Error".to_string()
-            }
-        } else if output.trim().is_empty() {
-            "Waiting...".to_string()
-        } else {
-            // Show the raw output if we can't parse it, but limit length
-            let trimmed = output.trim();
-            if trimmed.len() > 40 {
-                format!("{}...", trimmed.chars().take(37).collect::<String>())
-            } else {
-                trimmed.to_string()
-            }
-        }
-    }
-}
-
 /// Run the enhanced UI
 /// Returns true if a switch was confirmed, false otherwise
 pub async fn run_enhanced_ui(app: &mut EnhancedStatusApp) -> Result<bool> {
@@ -2072,10 +1884,15 @@ pub async fn run_enhanced_ui(app: &mut EnhancedStatusApp) -> Result<bool> {
 
 /// Draw the main UI
 fn draw_ui(f: &mut ratatui::Frame, ui_state: &UiState, app_state: &AppState) {
+    let rpc_quorum = ui_state
+        .rpc_quorum
+        .get(ui_state.selected_validator_index)
+        .and_then(|q| q.as_ref());
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Min(0),    // Validator tables take all remaining space
+            Constraint::Min(0), // Validator tables take all remaining space
+            Constraint::Length(rpc_quorum.map_or(0, |_| 1)), // RPC quorum line
             Constraint::Length(1), // Footer
         ])
         .split(f.size());
@@ -2083,8 +1900,33 @@ fn draw_ui(f: &mut ratatui::Frame, ui_state: &UiState, app_state: &AppState) {
     // Draw validator summaries
     draw_validator_summaries(f, chunks[0], ui_state, app_state);
 
+    if let Some(rpc_quorum) = rpc_quorum {
+        draw_rpc_quorum(f, chunks[1], rpc_quorum);
+    }
+
     // Draw footer
-    draw_footer(f, chunks[1], ui_state, app_state);
+    draw_footer(f, chunks[2], ui_state, app_state);
+}
+
+/// One line with what each RPC endpoint sees, highlighted when they disagree
+fn draw_rpc_quorum(f: &mut ratatui::Frame, area: Rect, summary: &QuorumSummary) {
+    let color = if summary.is_delinquent() {
+        Color::Red
+    } else if summary.has_disagreement() || summary.failing > 0 {
+        Color::Yellow
+    } else {
+        Color::DarkGray
+    };
+    let text = format!(
+        "RPC quorum: {} | {}",
+        summary.headline(),
+        summary.endpoints.join(" | ")
+    );
+
+    let line = Paragraph::new(text)
+        .style(Style::default().fg(color))
+        .alignment(Alignment::Center);
+    f.render_widget(line, area);
 }
 
 #[allow(dead_code)]
@@ -2140,78 +1982,26 @@ fn draw_side_by_side_tables(
     increment_time: Option<Instant>,
     app_state: &AppState,
     _last_catchup_refresh: Instant,
-    ssh_health_data: Option<&NodePairSshStatus>,
-    rpc_health_data: Option<&NodePairRpcStatus>,
+    ssh_health_data: Option<&NodeSshStatuses>,
+    rpc_health_data: Option<&NodeRpcStatuses>,
     _last_ssh_health_refresh: Instant,
     field_refresh_state: Option<&NodeFieldRefreshState>,
 ) {
-    // Handle single node configuration
-    if validator_status.nodes_with_status.len() == 1 {
-        // Use the full area for single node
-        if let Some(node) = validator_status.nodes_with_status.get(0) {
-            let ssh_health = ssh_health_data.and_then(|s| Some(&s.node_0));
-            let rpc_health = rpc_health_data.and_then(|r| Some(&r.node_0));
-            let node_refresh_state = field_refresh_state.map(|s| &s.node_0);
-            
-            draw_single_node_table(
-                f,
-                area,
-                validator_status,
-                node,
-                vote_data,
-                previous_last_slot,
-                increment_time,
-                app_state,
-                _last_catchup_refresh,
-                ssh_health,
-                rpc_health,
-                _last_ssh_health_refresh,
-                node_refresh_state,
-                false, // not a left table in split view
-            );
-        }
-        return;
-    }
-
-    // Split area horizontally for two nodes
+    // One table per node side by side, always in config order so hosts keep their positions
+    let node_count = validator_status.nodes_with_status.len().max(1) as u32;
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .constraints(vec![Constraint::Ratio(1, node_count); node_count as usize])
         .split(area);
 
-    // Always show nodes in the same order (node 0 on left, node 1 on right)
-    // This keeps the hosts in consistent positions
-    let (left_node_idx, right_node_idx) = (0, 1);
-
-    // Draw left table (node 0)
-    if let Some(node) = validator_status.nodes_with_status.get(left_node_idx) {
-        let ssh_health = ssh_health_data.and_then(|s| {
-            if left_node_idx == 0 {
-                Some(&s.node_0)
-            } else {
-                Some(&s.node_1)
-            }
-        });
-
-        let rpc_health = rpc_health_data.and_then(|r| {
-            if left_node_idx == 0 {
-                Some(&r.node_0)
-            } else {
-                Some(&r.node_1)
-            }
-        });
-
-        let node_refresh_state = field_refresh_state.map(|s| {
-            if left_node_idx == 0 {
-                &s.node_0
-            } else {
-                &s.node_1
-            }
-        });
+    for (node_idx, node) in validator_status.nodes_with_status.iter().enumerate() {
+        let ssh_health = ssh_health_data.and_then(|s| s.nodes.get(node_idx));
+        let rpc_health = rpc_health_data.and_then(|r| r.nodes.get(node_idx));
+        let node_refresh_state = field_refresh_state.and_then(|s| s.nodes.get(node_idx));
 
         draw_single_node_table(
             f,
-            chunks[0],
+            chunks[node_idx],
             validator_status,
             node,
             vote_data,
@@ -2223,51 +2013,7 @@ fn draw_side_by_side_tables(
             rpc_health,
             _last_ssh_health_refresh,
             node_refresh_state,
-            true, // is_left_table
-        );
-    }
-
-    // Draw right table (node 1)
-    if let Some(node) = validator_status.nodes_with_status.get(right_node_idx) {
-        let ssh_health = ssh_health_data.and_then(|s| {
-            if right_node_idx == 0 {
-                Some(&s.node_0)
-            } else {
-                Some(&s.node_1)
-            }
-        });
-
-        let rpc_health = rpc_health_data.and_then(|r| {
-            if right_node_idx == 0 {
-                Some(&r.node_0)
-            } else {
-                Some(&r.node_1)
-            }
-        });
-
-        let node_refresh_state = field_refresh_state.map(|s| {
-            if right_node_idx == 0 {
-                &s.node_0
-            } else {
-                &s.node_1
-            }
-        });
-
-        draw_single_node_table(
-            f,
-            chunks[1],
-            validator_status,
-            node,
-            vote_data,
-            previous_last_slot,
-            increment_time,
-            app_state,
-            _last_catchup_refresh,
-            ssh_health,
-            rpc_health,
-            _last_ssh_health_refresh,
-            node_refresh_state,
-            false, // is_left_table
+            node_idx + 1 < node_count as usize, // is_left_table
         );
     }
 }
@@ -2360,18 +2106,10 @@ fn draw_single_node_table(
         "🔄 Detecting...".to_string()
     } else {
         let version = node.version.as_deref().unwrap_or("");
-        let cleaned_version = version
-            .replace("Firedancer ", "")
-            .replace("Agave ", "")
-            .replace("Jito ", "");
+        let cleaned_version = crate::validator_backend::strip_client_prefix(version);
         format!(
             "{} {}",
-            match node.validator_type {
-                crate::types::ValidatorType::Firedancer => "Firedancer",
-                crate::types::ValidatorType::Agave => "Agave",
-                crate::types::ValidatorType::Jito => "Jito",
-                crate::types::ValidatorType::Unknown => "Unknown",
-            },
+            crate::validator_backend::validator_type_name(&node.validator_type),
             cleaned_version
         )
     };
@@ -2655,16 +2393,20 @@ fn draw_single_node_table(
             // Auto-failover status
             rows.push(Row::new(vec![
                 Cell::from("Auto-Failover"),
-                Cell::from(if alert_config.auto_failover_enabled {
-                    "✅ Enabled"
-                } else {
+                Cell::from(if !alert_config.auto_failover_enabled {
                     "❌ Disabled"
+                } else if alert_config.auto_failover_policy.dry_run {
+                    "🧪 Dry run"
+                } else {
+                    "✅ Enabled"
                 })
                 .style(Style::default().fg(
-                    if alert_config.auto_failover_enabled {
-                        Color::Green
-                    } else {
+                    if !alert_config.auto_failover_enabled {
                         Color::Red
+                    } else if alert_config.auto_failover_policy.dry_run {
+                        Color::Yellow
+                    } else {
+                        Color::Green
                     },
                 )),
             ]));
@@ -2823,35 +2565,19 @@ fn draw_validator_table(
             Cell::from("Type/Version"),
             Cell::from({
                 let version = node_0.version.as_deref().unwrap_or("");
-                let cleaned_version = version
-                    .replace("Firedancer ", "")
-                    .replace("Agave ", "")
-                    .replace("Jito ", "");
+                let cleaned_version = crate::validator_backend::strip_client_prefix(version);
                 format!(
                     "{} {}",
-                    match node_0.validator_type {
-                        crate::types::ValidatorType::Firedancer => "Firedancer",
-                        crate::types::ValidatorType::Agave => "Agave",
-                        crate::types::ValidatorType::Jito => "Jito",
-                        crate::types::ValidatorType::Unknown => "Unknown",
-                    },
+                    crate::validator_backend::validator_type_name(&node_0.validator_type),
                     cleaned_version
                 )
             }),
             Cell::from({
                 let version = node_1.version.as_deref().unwrap_or("");
-                let cleaned_version = version
-                    .replace("Firedancer ", "")
-                    .replace("Agave ", "")
-                    .replace("Jito ", "");
+                let cleaned_version = crate::validator_backend::strip_client_prefix(version);
                 format!(
                     "{} {}",
-                    match node_1.validator_type {
-                        crate::types::ValidatorType::Firedancer => "Firedancer",
-                        crate::types::ValidatorType::Agave => "Agave",
-                        crate::types::ValidatorType::Jito => "Jito",
-                        crate::types::ValidatorType::Unknown => "Unknown",
-                    },
+                    crate::validator_backend::validator_type_name(&node_1.validator_type),
                     cleaned_version
                 )
             }),
@@ -3163,59 +2889,6 @@ fn draw_footer(f: &mut ratatui::Frame, area: Rect, ui_state: &UiState, app_state
     f.render_widget(footer, area);
 }
 
-/// Execute emergency failover for a validator
-#[allow(dead_code)] // This is called from tokio::spawn
-async fn execute_emergency_failover(
-    validator_status: crate::ValidatorStatus,
-    alert_manager: AlertManager,
-    ssh_pool: Arc<crate::ssh::AsyncSshPool>,
-    detected_ssh_keys: std::collections::HashMap<String, String>,
-    emergency_takeover_flag: Arc<RwLock<bool>>,
-) {
-    // Find active and standby nodes
-    let (active_node, standby_node) = match (
-        validator_status
-            .nodes_with_status
-            .iter()
-            .find(|n| n.status == crate::types::NodeStatus::Active),
-        validator_status
-            .nodes_with_status
-            .iter()
-            .find(|n| n.status == crate::types::NodeStatus::Standby),
-    ) {
-        (Some(active), Some(standby)) => (active.clone(), standby.clone()),
-        _ => {
-            eprintln!("❌ Emergency failover failed: could not identify active/standby nodes");
-            return;
-        }
-    };
-
-    // Set the emergency takeover flag to suspend UI rendering
-    *emergency_takeover_flag.write().await = true;
-
-    // Wait a moment for the UI to stop rendering and cleanup terminal
-    tokio::time::sleep(Duration::from_millis(300)).await;
-
-    let mut emergency_failover = crate::emergency_failover::EmergencyFailover::new(
-        active_node,
-        standby_node,
-        validator_status.validator_pair,
-        ssh_pool,
-        detected_ssh_keys,
-        alert_manager,
-    );
-
-    if let Err(e) = emergency_failover.execute_emergency_takeover().await {
-        eprintln!("❌ Emergency failover error: {}", e);
-    }
-
-    // Wait a moment for the user to see the results
-    tokio::time::sleep(Duration::from_secs(3)).await;
-
-    // Clear the emergency takeover flag to resume UI
-    *emergency_takeover_flag.write().await = false;
-}
-
 /// Draw the switch UI
 fn draw_switch_ui(f: &mut ratatui::Frame, app_state: &AppState, ui_state: &UiState) {
     let chunks = Layout::default()
@@ -3238,55 +2911,77 @@ fn draw_switch_ui(f: &mut ratatui::Frame, app_state: &AppState, ui_state: &UiSta
         .block(Block::default().borders(Borders::BOTTOM));
     f.render_widget(header, chunks[0]);
 
-    // Content area
+    // Content area: the status box grows with the number of nodes
+    let node_count = app_state
+        .validator_statuses
+        .get(ui_state.selected_validator_index)
+        .map_or(2, |v| v.nodes_with_status.len());
     let content_chunks = Layout::default()
         .direction(Direction::Vertical)
         .margin(2)
         .constraints([
-            Constraint::Length(10), // Status info
-            Constraint::Length(10), // Actions
-            Constraint::Min(0),     // Messages
+            Constraint::Length((2 * node_count as u16 + 6).max(10)), // Status info
+            Constraint::Length(10),                                  // Actions
+            Constraint::Min(0),                                      // Messages
         ])
         .split(chunks[1]);
 
     // Current status
     if !app_state.validator_statuses.is_empty() {
         let validator_status = &app_state.validator_statuses[ui_state.selected_validator_index];
+        let nodes = &validator_status.nodes_with_status;
 
-        let active_node = validator_status
-            .nodes_with_status
+        // The switch ranks the standbys again with fresh slot lags right before it starts
+        let active_index = nodes
             .iter()
-            .find(|n| n.status == crate::types::NodeStatus::Active);
-        let standby_node = validator_status
-            .nodes_with_status
-            .iter()
-            .find(|n| n.status == crate::types::NodeStatus::Standby);
+            .position(|n| n.status == crate::types::NodeStatus::Active);
+        let standby_index = active_index.and_then(|active_index| {
+            crate::standby_selection::rank_standby_candidates(nodes, Some(active_index), &[])
+                .first()
+                .map(|c| c.index)
+        });
 
         let mut status_text = vec![];
         status_text.push(
             Line::from("Current State:").style(Style::default().add_modifier(Modifier::BOLD)),
         );
 
-        if let (Some(active), Some(standby)) = (active_node, standby_node) {
-            status_text.push(
-                Line::from(format!("  {} → ACTIVE", active.node.label))
-                    .style(Style::default().fg(Color::Green)),
-            );
-            status_text.push(
-                Line::from(format!("  {} → STANDBY", standby.node.label))
-                    .style(Style::default().fg(Color::Yellow)),
-            );
+        if let (Some(active_index), Some(standby_index)) = (active_index, standby_index) {
+            for node in nodes {
+                let (role, color) = match node.status {
+                    crate::types::NodeStatus::Active => ("ACTIVE", Color::Green),
+                    crate::types::NodeStatus::Standby => ("STANDBY", Color::Yellow),
+                    crate::types::NodeStatus::Unknown => ("UNKNOWN", Color::DarkGray),
+                };
+                status_text.push(
+                    Line::from(format!("  {} → {}", node.node.label, role))
+                        .style(Style::default().fg(color)),
+                );
+            }
             status_text.push(Line::from(""));
             status_text.push(
                 Line::from("After Switch:").style(Style::default().add_modifier(Modifier::BOLD)),
             );
             status_text.push(
-                Line::from(format!("  {} → STANDBY (was active)", active.node.label))
-                    .style(Style::default().fg(Color::Yellow)),
+                Line::from(format!(
+                    "  {} → STANDBY (was active)",
+                    nodes[active_index].node.label
+                ))
+                .style(Style::default().fg(Color::Yellow)),
             );
             status_text.push(
-                Line::from(format!("  {} → ACTIVE (was standby)", standby.node.label))
-                    .style(Style::default().fg(Color::Green)),
+                Line::from(if nodes.len() > 2 {
+                    format!(
+                        "  {} → ACTIVE (best-ranked standby, slot lag re-checked before switching)",
+                        nodes[standby_index].node.label
+                    )
+                } else {
+                    format!(
+                        "  {} → ACTIVE (was standby)",
+                        nodes[standby_index].node.label
+                    )
+                })
+                .style(Style::default().fg(Color::Green)),
             );
         } else {
             status_text.push(
@@ -3571,12 +3266,11 @@ async fn refresh_ssh_connectivity(
 
     // Update UI state
     let mut state = ui_state.write().await;
-    if let Some(ssh_data) = state.ssh_health_data.get_mut(validator_idx) {
-        let ssh_status = if node_idx == 0 {
-            &mut ssh_data.node_0
-        } else {
-            &mut ssh_data.node_1
-        };
+    if let Some(ssh_status) = state
+        .ssh_health_data
+        .get_mut(validator_idx)
+        .and_then(|ssh_data| ssh_data.nodes.get_mut(node_idx))
+    {
 
         ssh_status.is_healthy = is_healthy;
         if is_healthy {
@@ -3589,12 +3283,12 @@ async fn refresh_ssh_connectivity(
     }
 
     // Clear the refresh flag
-    if let Some(refresh_state) = state.field_refresh_states.get_mut(validator_idx) {
-        if node_idx == 0 {
-            refresh_state.node_0.ssh_connectivity_refreshing = false;
-        } else {
-            refresh_state.node_1.ssh_connectivity_refreshing = false;
-        }
+    if let Some(field_state) = state
+        .field_refresh_states
+        .get_mut(validator_idx)
+        .and_then(|refresh_state| refresh_state.nodes.get_mut(node_idx))
+    {
+        field_state.ssh_connectivity_refreshing = false;
     }
 
     // Update refresh timestamp
@@ -3625,12 +3319,11 @@ async fn refresh_rpc_health(
 
     // Update UI state
     let mut state = ui_state.write().await;
-    if let Some(rpc_data) = state.rpc_health_data.get_mut(validator_idx) {
-        let rpc_status = if node_idx == 0 {
-            &mut rpc_data.node_0
-        } else {
-            &mut rpc_data.node_1
-        };
+    if let Some(rpc_status) = state
+        .rpc_health_data
+        .get_mut(validator_idx)
+        .and_then(|rpc_data| rpc_data.nodes.get_mut(node_idx))
+    {
 
         rpc_status.is_healthy = is_healthy;
         rpc_status.last_check = Some(Instant::now());
@@ -3638,12 +3331,12 @@ async fn refresh_rpc_health(
     }
 
     // Clear the refresh flag
-    if let Some(refresh_state) = state.field_refresh_states.get_mut(validator_idx) {
-        if node_idx == 0 {
-            refresh_state.node_0.rpc_health_refreshing = false;
-        } else {
-            refresh_state.node_1.rpc_health_refreshing = false;
-        }
+    if let Some(field_state) = state
+        .field_refresh_states
+        .get_mut(validator_idx)
+        .and_then(|refresh_state| refresh_state.nodes.get_mut(node_idx))
+    {
+        field_state.rpc_health_refreshing = false;
     }
 }
 
@@ -3910,12 +3603,11 @@ async fn refresh_node_status_and_identity(
         }
 
         // Clear refreshing flags
-        if let Some(refresh_state) = ui_state_write.field_refresh_states.get_mut(validator_idx) {
-            let field_state = if node_idx == 0 {
-                &mut refresh_state.node_0
-            } else {
-                &mut refresh_state.node_1
-            };
+        if let Some(field_state) = ui_state_write
+            .field_refresh_states
+            .get_mut(validator_idx)
+            .and_then(|refresh_state| refresh_state.nodes.get_mut(node_idx))
+        {
             field_state.status_refreshing = false;
             field_state.identity_refreshing = false;
         }
@@ -3932,74 +3624,24 @@ async fn refresh_node_version(
     ui_state: Arc<RwLock<UiState>>,
 ) {
     // Extract version based on validator type and using proper executable paths
-    let (_validator_type, _version) = match node.validator_type {
-        crate::types::ValidatorType::Firedancer => {
-            if let Some(ref fdctl_exec) = node.fdctl_executable {
-                let version_cmd = format!("timeout 10 {} version 2>/dev/null", fdctl_exec);
-                let version_output = ssh_pool
-                    .execute_command(&node.node, &ssh_key, &version_cmd)
-                    .await
-                    .unwrap_or_else(|_| "Unknown".to_string());
-
-                // Parse fdctl version output - first part is version
-                let version = if let Some(line) = version_output.lines().next() {
-                    if let Some(version_match) = line.split_whitespace().next() {
-                        Some(format!("Firedancer {}", version_match))
-                    } else {
-                        Some("Firedancer Unknown".to_string())
-                    }
-                } else {
-                    Some("Firedancer Unknown".to_string())
-                };
-
-                (crate::types::ValidatorType::Firedancer, version)
-            } else {
-                (
-                    crate::types::ValidatorType::Firedancer,
-                    Some("Firedancer Unknown".to_string()),
-                )
-            }
-        }
-        crate::types::ValidatorType::Agave | crate::types::ValidatorType::Jito => {
-            if let Some(ref agave_exec) = node.agave_validator_executable {
-                let version_cmd = format!("timeout 10 {} --version 2>/dev/null", agave_exec);
-                let version_output = ssh_pool
-                    .execute_command(&node.node, &ssh_key, &version_cmd)
-                    .await
-                    .unwrap_or_else(|_| "Unknown".to_string());
-
-                // Parse version output
-                let version = if let Some(line) = version_output.lines().next() {
-                    if line.starts_with("agave-validator ") || line.starts_with("solana-cli ") {
-                        // Extract version after the executable name
-                        line.split_whitespace().nth(1).map(|v| v.to_string())
-                    } else if line.contains("jito-") {
-                        // Jito validator format
-                        Some(line.trim().to_string())
-                    } else {
-                        Some(line.trim().to_string())
-                    }
-                } else {
-                    None
-                };
-
-                // Determine if it's Jito based on version output
-                let validator_type = if version.as_ref().map_or(false, |v| v.contains("jito")) {
-                    crate::types::ValidatorType::Jito
-                } else {
-                    crate::types::ValidatorType::Agave
-                };
-
-                (validator_type, version)
-            } else {
-                (node.validator_type.clone(), None)
-            }
-        }
-        crate::types::ValidatorType::Unknown => {
+    let (_validator_type, _version) =
+        match crate::validator_backend::backend_for(&node.validator_type) {
+            Some(backend) => match backend.main_executable(
+                node.agave_validator_executable.as_deref(),
+                node.fdctl_executable.as_deref(),
+            ) {
+                Some(executable) => {
+                    let version_output = ssh_pool
+                        .execute_command(&node.node, &ssh_key, &backend.version_command(executable))
+                        .await
+                        .unwrap_or_else(|_| "Unknown".to_string());
+                    backend.parse_version(&version_output)
+                }
+                None => (node.validator_type.clone(), None),
+            },
             // Try to detect validator type
-            (crate::types::ValidatorType::Unknown, None)
-        }
-    };
+            None => (crate::types::ValidatorType::Unknown, None),
+        };
 
     // Update UI state with the new version info
     {
@@ -4015,12 +3657,11 @@ async fn refresh_node_version(
         }
 
         // Clear refreshing flag
-        if let Some(refresh_state) = ui_state_write.field_refresh_states.get_mut(validator_idx) {
-            let field_state = if node_idx == 0 {
-                &mut refresh_state.node_0
-            } else {
-                &mut refresh_state.node_1
-            };
+        if let Some(field_state) = ui_state_write
+            .field_refresh_states
+            .get_mut(validator_idx)
+            .and_then(|refresh_state| refresh_state.nodes.get_mut(node_idx))
+        {
             field_state.version_refreshing = false;
         }
     }
@@ -4036,16 +3677,12 @@ async fn refresh_swap_readiness(
     // Set refreshing state
     {
         let mut ui_write = ui_state.write().await;
-        if validator_idx < ui_write.field_refresh_states.len() {
-            if node_idx == 0 {
-                ui_write.field_refresh_states[validator_idx]
-                    .node_0
-                    .swap_readiness_refreshing = true;
-            } else {
-                ui_write.field_refresh_states[validator_idx]
-                    .node_1
-                    .swap_readiness_refreshing = true;
-            }
+        if let Some(field_state) = ui_write
+            .field_refresh_states
+            .get_mut(validator_idx)
+            .and_then(|refresh_state| refresh_state.nodes.get_mut(node_idx))
+        {
+            field_state.swap_readiness_refreshing = true;
         }
     }
 
@@ -4064,6 +3701,13 @@ async fn refresh_swap_readiness(
                     ssh_key,
                     node.ledger_path.as_ref(),
                     Some(node.status == crate::types::NodeStatus::Standby),
+                    // Keyless standbys only receive the funded keypair at switch time
+                    node.status == crate::types::NodeStatus::Active
+                        || !app_state
+                            .config
+                            .switch_policy
+                            .as_ref()
+                            .is_some_and(|policy| policy.keyless_standby),
                 )
                 .await;
                 let (swap_ready, swap_issues) = (Some(ready), issues);
@@ -4090,16 +3734,12 @@ async fn refresh_swap_readiness(
     // Clear refreshing state
     {
         let mut ui_write = ui_state.write().await;
-        if validator_idx < ui_write.field_refresh_states.len() {
-            if node_idx == 0 {
-                ui_write.field_refresh_states[validator_idx]
-                    .node_0
-                    .swap_readiness_refreshing = false;
-            } else {
-                ui_write.field_refresh_states[validator_idx]
-                    .node_1
-                    .swap_readiness_refreshing = false;
-            }
+        if let Some(field_state) = ui_write
+            .field_refresh_states
+            .get_mut(validator_idx)
+            .and_then(|refresh_state| refresh_state.nodes.get_mut(node_idx))
+        {
+            field_state.swap_readiness_refreshing = false;
         }
     }
 }
@@ -4121,9 +3761,10 @@ pub async fn show_enhanced_status_ui(app_state: &AppState) -> Result<()> {
         // Use the switch command with confirmation already provided
         let mut app_state_mut = app_state.clone();
         let result = crate::commands::switch::switch_command_with_confirmation(
-            false, // not a dry run
+            &crate::commands::SwitchOptions::default(), // live switch
             &mut app_state_mut,
             false, // don't require confirmation again
+            crate::switch_journal::SwitchTrigger::Tui,
         )
         .await?;
 
@@ -1,10 +1,34 @@
-use crate::commands::error_handler::ProgressSpinner;
+use crate::commands::error_handler::{ProgressSpinner, SwitchError};
+use crate::switch_hooks::HookContext;
+use crate::switch_journal::{duration_ms, SwitchRecord, SwitchTrigger};
+use crate::switch_lock::SwitchLock;
+use crate::switch_plan::{PlanNode, PlanStep, PlanTower, SwitchPlan, PLAN_FORMAT_VERSION};
+use crate::switch_state::{SwitchPhase, SwitchState, SwitchStateStore};
+use crate::tower_file::StaleTower;
+use crate::types::HookEvent;
 use anyhow::{anyhow, Result};
 use colored::*;
 use std::io::Write;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
+// How often to poll the vote account while waiting for the first landed vote
+const VOTE_CONFIRMATION_POLL_INTERVAL: Duration = Duration::from_secs(1);
+// Slots past the activation point the first vote must reach, so a vote the old active
+// sent just before demotion that lands late is not taken for the new active's
+const VOTE_CONFIRMATION_SLOT_MARGIN: u64 = 2;
+// Lists the validator processes running on a node, used to pick the set-identity command
+const VALIDATOR_PROCESS_QUERY: &str =
+    "ps aux | grep -E 'solana-validator|agave|fdctl|firedancer' | grep -v grep";
+const SCHEDULE_KEEPALIVE_INTERVAL: Duration = Duration::from_secs(30);
+// Retry interval after a failed switch lock renewal during a long wait
+const LOCK_RENEW_RETRY: Duration = Duration::from_secs(5);
+const SCHEDULE_SLOT_POLL_INTERVAL: Duration = Duration::from_secs(5);
+const SCHEDULE_READINESS_RECHECK: Duration = Duration::from_secs(60);
+// getIdentity attempts while confirming the old active dropped the funded identity
+const UNFUNDED_CONFIRMATION_ATTEMPTS: u32 = 5;
+const UNFUNDED_CONFIRMATION_RETRY: Duration = Duration::from_millis(200);
+
 // Check if we're in silent mode (called from Telegram)
 fn is_silent_mode() -> bool {
     std::env::var("SVS_SILENT_MODE").unwrap_or_default() == "1"
@@ -42,19 +66,50 @@ impl ConditionalSpinner {
     }
 }
 
-pub async fn switch_command(dry_run: bool, app_state: &mut crate::AppState) -> Result<bool> {
+/// Options for `svs switch`
+#[derive(Debug, Clone, Default)]
+pub struct SwitchOptions {
+    pub dry_run: bool,
+    /// Start right away instead of waiting for a window without our leader slots
+    pub no_wait: bool,
+    /// Hold the switch until a planned time or the next epoch boundary
+    pub schedule: Option<crate::switch_schedule::SwitchSchedule>,
+    /// Reviewed plan to execute; the switch aborts if the cluster no longer matches it
+    pub plan: Option<SwitchPlan>,
+    /// Write the plan of a successful dry run to this file
+    pub plan_out: Option<std::path::PathBuf>,
+    /// Label of the standby to activate instead of the best-ranked one
+    pub standby: Option<String>,
+    /// Remove a switch lock held by another svs instance
+    pub break_lock: bool,
+}
+
+pub async fn switch_command(
+    options: SwitchOptions,
+    app_state: &mut crate::AppState,
+) -> Result<bool> {
     // Clear screen and ensure clean output after menu selection
     print!("\x1B[2J\x1B[1;1H");
     std::io::stdout().flush()?;
 
-    switch_command_with_confirmation(dry_run, app_state, !dry_run).await
+    let require_confirmation = !options.dry_run;
+    switch_command_with_confirmation(
+        &options,
+        app_state,
+        require_confirmation,
+        SwitchTrigger::Manual,
+    )
+    .await
 }
 
 pub async fn switch_command_with_confirmation(
-    dry_run: bool,
+    options: &SwitchOptions,
     app_state: &mut crate::AppState,
     require_confirmation: bool,
+    trigger: SwitchTrigger,
 ) -> Result<bool> {
+    let dry_run = options.dry_run;
+
     // Validate we have at least one validator configured
     if app_state.config.validators.is_empty() {
         return Err(anyhow!("No validators configured"));
@@ -76,54 +131,30 @@ pub async fn switch_command_with_confirmation(
         return Ok(false);
     }
 
-    // Find active and standby nodes with full status information
-    let active_node_with_status = validator_status
-        .nodes_with_status
-        .iter()
-        .find(|n| n.status == crate::types::NodeStatus::Active);
-    let standby_node_with_status = validator_status
-        .nodes_with_status
-        .iter()
-        .find(|n| n.status == crate::types::NodeStatus::Standby);
-
-    let (active_node_with_status, standby_node_with_status) =
-        match (active_node_with_status, standby_node_with_status) {
-            (Some(active), Some(standby)) => (active, standby),
-            _ => {
-                // Handle special case: both nodes are standby
-                let standby_nodes: Vec<_> = validator_status
-                    .nodes_with_status
-                    .iter()
-                    .filter(|n| n.status == crate::types::NodeStatus::Standby)
-                    .collect();
-                
-                if standby_nodes.len() == 2 {
-                    println_if_not_silent!(
-                        "\n{}",
-                        "⚠️  Both nodes are in STANDBY state - Recovery Mode".yellow().bold()
-                    );
-                    println_if_not_silent!(
-                        "Will activate {} and keep {} as standby",
-                        validator_status.nodes_with_status[1].node.label,
-                        validator_status.nodes_with_status[0].node.label
-                    );
-                    // Switch second node to active, keep first as standby
-                    (
-                        &validator_status.nodes_with_status[0], // Will remain standby
-                        &validator_status.nodes_with_status[1], // Will become active
-                    )
-                } else {
-                    // Fallback: use first two nodes if we can't determine status
-                    if validator_status.nodes_with_status.len() < 2 {
-                        return Err(anyhow!("Validator must have at least 2 nodes configured for switching"));
-                    }
-                    (
-                        &validator_status.nodes_with_status[0],
-                        &validator_status.nodes_with_status[1],
-                    )
-                }
-            }
-        };
+    let targets = select_switch_targets(
+        app_state,
+        app_state.selected_validator_index,
+        options.standby.as_deref(),
+    )
+    .await?;
+    let (active_index, standby_index) = (targets.active_index, targets.standby_index);
+    let recovery_mode = targets.recovery_mode;
+    let nodes = &validator_status.nodes_with_status;
+
+    if recovery_mode {
+        println_if_not_silent!(
+            "\n{}",
+            "⚠️  No node is ACTIVE - Recovery Mode".yellow().bold()
+        );
+        println_if_not_silent!(
+            "Will activate {} and keep {} as standby",
+            nodes[standby_index].node.label,
+            nodes[active_index].node.label
+        );
+    }
+
+    let active_node_with_status = &nodes[active_index];
+    let standby_node_with_status = &nodes[standby_index];
 
     println_if_not_silent!(
         "\n{}",
@@ -149,73 +180,8 @@ pub async fn switch_command_with_confirmation(
     }
 
     // Targeted validation: Check only what's needed for this specific switch
-    let mut validation_errors = Vec::new();
-    let mut validation_warnings = Vec::new();
-
-    // Check target (standby) node - this is critical for switch success
-    if standby_node_with_status.status == crate::types::NodeStatus::Unknown {
-        validation_errors.push(format!(
-            "Target node {} is unreachable (SSH connection failed)",
-            standby_node_with_status.node.label
-        ));
-    } else {
-        // Since we skip swap readiness checks at startup, we need to check now
-        // For standby nodes, we check all requirements except tower file
-        println_if_not_silent!("🔍 Checking target node swap readiness...");
-
-        if let Some(ssh_key) = app_state
-            .detected_ssh_keys
-            .get(&standby_node_with_status.node.host)
-        {
-            let (is_ready, issues) = crate::startup::check_node_swap_readiness(
-                &app_state.ssh_pool,
-                &standby_node_with_status.node,
-                ssh_key,
-                standby_node_with_status.ledger_path.as_ref(),
-                Some(true), // is_standby = true, skip tower check
-            )
-            .await;
-
-            if !is_ready {
-                validation_errors.push(format!(
-                    "Target node {} is not swap-ready: {}",
-                    standby_node_with_status.node.label,
-                    issues.join(", ")
-                ));
-            }
-        }
-    }
-
-    // Check if we can get SSH key for target node
-    if !app_state
-        .detected_ssh_keys
-        .contains_key(&standby_node_with_status.node.host)
-    {
-        validation_errors.push(format!(
-            "No SSH key available for target node {}",
-            standby_node_with_status.node.label
-        ));
-    }
-
-    // Check source (active) node - this is preferred but not critical (emergency scenarios)
-    if active_node_with_status.status == crate::types::NodeStatus::Unknown {
-        validation_warnings.push(format!(
-            "Source node {} is unreachable - will skip optional steps (tower copy, graceful shutdown)",
-            active_node_with_status.node.label
-        ));
-    }
-    // Skip detailed swap readiness check for source node - not critical for switch
-
-    // Check if we can get SSH key for source node
-    if !app_state
-        .detected_ssh_keys
-        .contains_key(&active_node_with_status.node.host)
-    {
-        validation_warnings.push(format!(
-            "No SSH key available for source node {} - will skip optional steps",
-            active_node_with_status.node.label
-        ));
-    }
+    let (validation_errors, validation_warnings) =
+        check_switch_preflight(app_state, active_node_with_status, standby_node_with_status).await;
 
     // Show validation results
     if !validation_errors.is_empty() {
@@ -269,6 +235,13 @@ pub async fn switch_command_with_confirmation(
         app_state.ssh_pool.clone(),
         app_state.detected_ssh_keys.clone(),
     );
+    switch_manager.switch_policy = app_state.config.switch_policy.clone().unwrap_or_default();
+    switch_manager.wait_for_leader_window = !options.no_wait;
+    switch_manager.schedule = options.schedule.clone();
+    switch_manager.plan = options.plan.clone();
+    switch_manager.break_lock = options.break_lock;
+    switch_manager.alert_config = app_state.config.alert_config.clone();
+    switch_manager.trigger = trigger;
 
     // Pre-warm SSH connections to both nodes for faster switching
     if !dry_run {
@@ -304,6 +277,30 @@ pub async fn switch_command_with_confirmation(
         .execute_switch(dry_run, require_confirmation)
         .await;
 
+    // Save the reviewed plan once the dry run went through
+    if let (Some(path), Ok(_)) = (&options.plan_out, &switch_result) {
+        let plan = switch_manager.build_plan().await?;
+        plan.save(path)?;
+        println_if_not_silent!(
+            "\n{}",
+            format!("📝 Switch plan written to {}", path.display()).bright_green()
+        );
+        println_if_not_silent!(
+            "   Review it, then execute with: svs switch --plan {}",
+            path.display()
+        );
+    }
+
+    // Record the attempt in the switch journal (skip switches cancelled at the prompt)
+    let cancelled = !dry_run && matches!(switch_result, Ok(false));
+    if !cancelled {
+        crate::switch_journal::record_switch(&switch_manager.journal_record(
+            trigger,
+            dry_run,
+            switch_result.as_ref().err(),
+        ));
+    }
+
     // Send Telegram notification for switch result (only for live switches)
     if !dry_run {
         if let Some(alert_config) = &app_state.config.alert_config {
@@ -318,21 +315,37 @@ pub async fn switch_command_with_confirmation(
                             &active_node_with_status.node.label,
                             &standby_node_with_status.node.label,
                             switch_manager.identity_switch_time,
+                            switch_manager.first_vote_time,
                             None,
                         )
                         .await;
                 }
                 Err(e) => {
-                    // Send failure notification
-                    let _ = alert_manager
-                        .send_switch_result(
-                            false,
-                            &active_node_with_status.node.label,
-                            &standby_node_with_status.node.label,
-                            None,
-                            Some(&e.to_string()),
-                        )
-                        .await;
+                    if let Some(report) = &switch_manager.rollback_report {
+                        // Switch failed part-way - report what the rollback did
+                        let _ = alert_manager
+                            .send_switch_rollback_alert(
+                                &validator_pair.identity_pubkey,
+                                &active_node_with_status.node.label,
+                                &standby_node_with_status.node.label,
+                                &report.failed_step,
+                                &report.error,
+                                report.rollback_error.as_deref(),
+                            )
+                            .await;
+                    } else {
+                        // Send failure notification
+                        let _ = alert_manager
+                            .send_switch_result(
+                                false,
+                                &active_node_with_status.node.label,
+                                &standby_node_with_status.node.label,
+                                None,
+                                None,
+                                Some(&e.to_string()),
+                            )
+                            .await;
+                    }
                 }
             }
         }
@@ -386,32 +399,12 @@ pub async fn switch_command_with_confirmation(
 
         // Update the node statuses in app_state to reflect the switch
         if !dry_run && show_status && app_state.validator_statuses.len() > 0 {
-            // Find the indices of active and standby nodes
-            let mut active_idx = None;
-            let mut standby_idx = None;
-
-            for (idx, node_with_status) in app_state.validator_statuses
-                [app_state.selected_validator_index]
-                .nodes_with_status
-                .iter()
-                .enumerate()
-            {
-                match node_with_status.status {
-                    crate::types::NodeStatus::Active => active_idx = Some(idx),
-                    crate::types::NodeStatus::Standby => standby_idx = Some(idx),
-                    _ => {}
-                }
-            }
-
-            // Swap the statuses
-            if let (Some(active), Some(standby)) = (active_idx, standby_idx) {
-                app_state.validator_statuses[app_state.selected_validator_index]
-                    .nodes_with_status[active]
-                    .status = crate::types::NodeStatus::Standby;
-                app_state.validator_statuses[app_state.selected_validator_index]
-                    .nodes_with_status[standby]
-                    .status = crate::types::NodeStatus::Active;
+            let nodes = &mut app_state.validator_statuses[app_state.selected_validator_index]
+                .nodes_with_status;
+            if nodes[active_index].status == crate::types::NodeStatus::Active {
+                nodes[active_index].status = crate::types::NodeStatus::Standby;
             }
+            nodes[standby_index].status = crate::types::NodeStatus::Active;
         }
 
         println_if_not_silent!();
@@ -432,164 +425,1095 @@ pub async fn switch_command_with_confirmation(
     Ok(show_status)
 }
 
-pub(crate) struct SwitchManager {
-    active_node_with_status: crate::types::NodeWithStatus,
-    standby_node_with_status: crate::types::NodeWithStatus,
-    #[allow(dead_code)]
-    validator_pair: crate::types::ValidatorPair,
-    ssh_pool: Arc<crate::ssh::AsyncSshPool>,
-    detected_ssh_keys: std::collections::HashMap<String, String>,
-    tower_file_name: Option<String>,
-    tower_transfer_time: Option<Duration>,
-    identity_switch_time: Option<Duration>,
-    active_switch_time: Option<Duration>,
-    standby_switch_time: Option<Duration>,
+/// Nodes picked for switching one validator
+pub(crate) struct SwitchTargets {
+    pub active_index: usize,
+    pub standby_index: usize,
+    /// No node was active; the node at `active_index` stays on standby
+    pub recovery_mode: bool,
 }
 
-impl SwitchManager {
-    pub(crate) fn new(
-        active_node_with_status: crate::types::NodeWithStatus,
-        standby_node_with_status: crate::types::NodeWithStatus,
-        validator_pair: crate::types::ValidatorPair,
-        ssh_pool: Arc<crate::ssh::AsyncSshPool>,
-        detected_ssh_keys: std::collections::HashMap<String, String>,
-    ) -> Self {
-        Self {
-            active_node_with_status,
-            standby_node_with_status,
-            validator_pair,
-            ssh_pool,
-            detected_ssh_keys,
-            tower_file_name: None,
-            tower_transfer_time: None,
-            identity_switch_time: None,
-            active_switch_time: None,
-            standby_switch_time: None,
-        }
-    }
-
-    fn get_ssh_key_for_node(&self, host: &str) -> Result<String> {
-        // Use detected key if available
-        self.detected_ssh_keys
-            .get(host)
-            .cloned()
-            .ok_or_else(|| anyhow!("No SSH key detected for host: {}", host))
+/// Pick the active node and the standby to activate: the operator's choice, the only
+/// other node, or the best-ranked one
+pub(crate) async fn select_switch_targets(
+    app_state: &crate::AppState,
+    validator_idx: usize,
+    standby_label: Option<&str>,
+) -> Result<SwitchTargets> {
+    let validator_status = &app_state.validator_statuses[validator_idx];
+    let validator_pair = &validator_status.validator_pair;
+    if validator_status.nodes_with_status.len() < 2 {
+        return Err(anyhow!(
+            "Single node configuration - switching not available"
+        ));
     }
 
-    async fn execute_switch(&mut self, dry_run: bool, require_confirmation: bool) -> Result<bool> {
-        // Show confirmation dialog (except for dry run or when explicitly disabled)
-        if !dry_run && require_confirmation {
-            println!(
-                "\n{}",
-                "⚠️  Validator Switch Confirmation".bright_yellow().bold()
-            );
-            println!("{}", "━".repeat(50).dimmed());
-            println!();
-            println!(
-                "  {} → {}",
-                format!(
-                    "🟢 ACTIVE: {} ({}) {}",
-                    self.active_node_with_status.node.label,
-                    self.active_node_with_status.node.host,
-                    self.active_node_with_status
-                        .version
-                        .as_ref()
-                        .unwrap_or(&"Unknown".to_string())
-                )
-                .bright_green(),
-                "🔄 STANDBY".dimmed()
-            );
-            println!(
-                "  {} → {}",
-                format!(
-                    "⚪ STANDBY: {} ({}) {}",
-                    self.standby_node_with_status.node.label,
-                    self.standby_node_with_status.node.host,
-                    self.standby_node_with_status
-                        .version
-                        .as_ref()
-                        .unwrap_or(&"Unknown".to_string())
-                )
-                .white(),
-                "🟢 ACTIVE".bright_green()
-            );
-            println!();
-            println!(
-                "  {}",
-                "This will switch your validator identity between nodes.".yellow()
-            );
-            println!("  {}", "Estimated time: ~10 seconds".dimmed());
-            println!();
-
-            // Use inquire for confirmation
-            use inquire::Confirm;
-            let confirmed = Confirm::new("Do you want to proceed with the validator switch?")
-                .with_default(false)
-                .prompt()?;
-
-            if !confirmed {
-                println!("\n{}", "❌ Validator switch cancelled by user".red());
-                return Ok(false);
-            }
-            println!();
-            // Ensure output is flushed after confirmation
-            std::io::stdout().flush()?;
+    // Find the active node; without one (recovery mode) the first node stays on standby
+    let nodes = &validator_status.nodes_with_status;
+    let recovery_mode = !nodes
+        .iter()
+        .any(|n| n.status == crate::types::NodeStatus::Active);
+    let active_index = nodes
+        .iter()
+        .position(|n| n.status == crate::types::NodeStatus::Active)
+        .unwrap_or(0);
+
+    // Pick the standby to activate: the operator's choice, the only other node, or the best-ranked one
+    let standby_index = if let Some(label) = standby_label {
+        let idx = nodes
+            .iter()
+            .position(|n| n.node.label == label)
+            .ok_or_else(|| anyhow!("No node labelled '{}' in this validator", label))?;
+        if idx == active_index {
+            return Err(anyhow!(
+                "Node '{}' is the active node, pick a standby",
+                label
+            ));
         }
-
-        // Start timing the entire switch operation
-        let total_switch_start = Instant::now();
-
-        // Step 1: Switch active node to unfunded identity
-        println_if_not_silent!(
-            "\n{}",
-            "🔄 Step 1: Switch Active Node to Unfunded Identity"
-                .bright_blue()
-                .bold()
+        idx
+    } else if nodes.len() == 2 {
+        1 - active_index
+    } else {
+        let spinner = ConditionalSpinner::new("Ranking standby nodes...");
+        let slot_lags = crate::standby_selection::fetch_slot_lags(
+            &app_state.ssh_pool,
+            nodes,
+            &app_state.detected_ssh_keys,
+            &validator_pair.rpc,
+        )
+        .await;
+        let candidates = crate::standby_selection::rank_standby_candidates(
+            nodes,
+            Some(active_index),
+            &slot_lags,
         );
-        let active_switch_start = Instant::now();
-        self.switch_primary_to_unfunded(dry_run).await?;
-        self.active_switch_time = Some(active_switch_start.elapsed());
-        if !dry_run {
+        spinner.stop_with_message("✅ Standby nodes ranked");
+
+        println_if_not_silent!("\n{}", "🎯 Standby candidates (best first):".bright_cyan());
+        for (rank, candidate) in candidates.iter().enumerate() {
             println_if_not_silent!(
-                "   ✓ Completed in {}",
-                format!("{}ms", self.active_switch_time.unwrap().as_millis())
-                    .bright_yellow()
-                    .bold()
+                "   {}. {} - {}",
+                rank + 1,
+                candidate.label.bright_white(),
+                candidate.describe().dimmed()
             );
         }
+        println_if_not_silent!("   Use --standby <label> to pick another node");
 
-        // Step 2: Transfer tower file
-        println_if_not_silent!(
-            "\n{}",
-            "📤 Step 2: Transfer Tower File".bright_blue().bold()
-        );
-        self.transfer_tower_file(dry_run).await?;
-        // Note: tower_transfer_time is set inside transfer_tower_file method
+        candidates
+            .first()
+            .map(|c| c.index)
+            .ok_or_else(|| anyhow!("No standby node available for switching"))?
+    };
 
-        // Step 3: Switch standby node to funded identity
-        println_if_not_silent!(
-            "\n{}",
-            "🚀 Step 3: Switch Standby Node to Funded Identity"
-                .bright_blue()
-                .bold()
-        );
-        let standby_switch_start = Instant::now();
-        self.switch_backup_to_funded(dry_run).await?;
-        self.standby_switch_time = Some(standby_switch_start.elapsed());
-        if !dry_run {
-            println_if_not_silent!(
-                "   ✓ Completed in {}",
-                format!("{}ms", self.standby_switch_time.unwrap().as_millis())
-                    .bright_yellow()
-                    .bold()
-            );
-        }
+    Ok(SwitchTargets {
+        active_index,
+        standby_index,
+        recovery_mode,
+    })
+}
 
-        // Record total identity switch time
-        if !dry_run {
+/// Critical issues and warnings for switching from `active_node_with_status` to
+/// `standby_node_with_status`. Any critical issue prevents the switch.
+pub(crate) async fn check_switch_preflight(
+    app_state: &crate::AppState,
+    active_node_with_status: &crate::types::NodeWithStatus,
+    standby_node_with_status: &crate::types::NodeWithStatus,
+) -> (Vec<String>, Vec<String>) {
+    let mut validation_errors = Vec::new();
+    let mut validation_warnings = Vec::new();
+
+    // Check target (standby) node - this is critical for switch success
+    if standby_node_with_status.status == crate::types::NodeStatus::Unknown {
+        validation_errors.push(format!(
+            "Target node {} is unreachable (SSH connection failed)",
+            standby_node_with_status.node.label
+        ));
+    } else {
+        // Since we skip swap readiness checks at startup, we need to check now
+        // For standby nodes, we check all requirements except tower file
+        println_if_not_silent!("🔍 Checking target node swap readiness...");
+
+        if let Some(ssh_key) = app_state
+            .detected_ssh_keys
+            .get(&standby_node_with_status.node.host)
+        {
+            let (is_ready, issues) = crate::startup::check_node_swap_readiness(
+                &app_state.ssh_pool,
+                &standby_node_with_status.node,
+                ssh_key,
+                standby_node_with_status.ledger_path.as_ref(),
+                Some(true), // is_standby = true, skip tower check
+                !app_state
+                    .config
+                    .switch_policy
+                    .as_ref()
+                    .is_some_and(|policy| policy.keyless_standby),
+            )
+            .await;
+
+            if !is_ready {
+                validation_errors.push(format!(
+                    "Target node {} is not swap-ready: {}",
+                    standby_node_with_status.node.label,
+                    issues.join(", ")
+                ));
+            }
+        }
+    }
+
+    // Check if we can get SSH key for target node
+    if !app_state
+        .detected_ssh_keys
+        .contains_key(&standby_node_with_status.node.host)
+    {
+        validation_errors.push(format!(
+            "No SSH key available for target node {}",
+            standby_node_with_status.node.label
+        ));
+    }
+
+    // Check source (active) node - this is preferred but not critical (emergency scenarios)
+    if active_node_with_status.status == crate::types::NodeStatus::Unknown {
+        validation_warnings.push(format!(
+            "Source node {} is unreachable - will skip optional steps (tower copy, graceful shutdown)",
+            active_node_with_status.node.label
+        ));
+    }
+    // Skip detailed swap readiness check for source node - not critical for switch
+
+    // Check if we can get SSH key for source node
+    if !app_state
+        .detected_ssh_keys
+        .contains_key(&active_node_with_status.node.host)
+    {
+        validation_warnings.push(format!(
+            "No SSH key available for source node {} - will skip optional steps",
+            active_node_with_status.node.label
+        ));
+    }
+
+    (validation_errors, validation_warnings)
+}
+
+/// Offer to resume or roll back switches svs did not finish, e.g. after a crash or
+/// Ctrl-C between steps. Called at startup, before anything else touches the nodes.
+pub async fn handle_interrupted_switches(app_state: &mut crate::AppState) -> Result<()> {
+    use inquire::Select;
+
+    let store = SwitchStateStore::new()?;
+    for state in store.load_unfinished()? {
+        let Some(validator_idx) = app_state
+            .validator_statuses
+            .iter()
+            .position(|v| v.validator_pair.identity_pubkey == state.validator_identity)
+        else {
+            continue;
+        };
+        let validator_status = &app_state.validator_statuses[validator_idx];
+        let nodes = &validator_status.nodes_with_status;
+        let from_index = nodes.iter().position(|n| n.node.label == state.from_node);
+        let to_index = nodes.iter().position(|n| n.node.label == state.to_node);
+
+        println!(
+            "\n{}",
+            "⚠️  Unfinished validator switch found"
+                .bright_yellow()
+                .bold()
+        );
+        println!("{}", "━".repeat(50).dimmed());
+        println!("  Validator: {}", state.validator_identity);
+        println!(
+            "  Switch:    {} → {} (started {} on {})",
+            state.from_node, state.to_node, state.started_at, state.operator_host
+        );
+        println!(
+            "  Stopped:   {} ({})",
+            state.phase.describe().bright_yellow(),
+            state.updated_at
+        );
+
+        let (Some(from_index), Some(to_index)) = (from_index, to_index) else {
+            println!(
+                "  {}",
+                "One of the nodes is no longer configured - check both nodes manually".red()
+            );
+            continue;
+        };
+
+        let resume = format!("Resume: finish switching to {}", state.to_node);
+        let rollback = format!(
+            "Roll back: restore {} to the funded identity",
+            state.from_node
+        );
+        let later = "Decide later (asked again on next launch)".to_string();
+        let discard = "Discard: I already resolved it manually".to_string();
+        let mut options = vec![resume.clone()];
+        // Once the standby runs the funded identity, going back is a new switch
+        if state.phase < SwitchPhase::StandbyPromoted {
+            options.push(rollback.clone());
+        }
+        options.push(later.clone());
+        options.push(discard.clone());
+
+        let selection = Select::new("How do you want to finish this switch?", options).prompt()?;
+        if selection == later {
+            continue;
+        }
+        if selection == discard {
+            store.remove(&state.validator_identity)?;
+            println!("{}", "🗑️  Switch state discarded".dimmed());
+            continue;
+        }
+
+        let mut switch_manager = SwitchManager::new(
+            nodes[from_index].clone(),
+            nodes[to_index].clone(),
+            validator_status.validator_pair.clone(),
+            app_state.ssh_pool.clone(),
+            app_state.detected_ssh_keys.clone(),
+        );
+        switch_manager.switch_policy = app_state.config.switch_policy.clone().unwrap_or_default();
+        // The validator may not be voting right now, so no waiting for a leader window
+        switch_manager.wait_for_leader_window = false;
+        switch_manager.alert_config = app_state.config.alert_config.clone();
+
+        let phase = state.phase;
+        let nodes = &mut app_state.validator_statuses[validator_idx].nodes_with_status;
+        if selection == resume {
+            switch_manager.resume_from = Some(phase);
+            switch_manager.switch_state = Some(state);
+            let result = switch_manager.execute_switch(false, false).await;
+            crate::switch_journal::record_switch(&switch_manager.journal_record(
+                SwitchTrigger::Manual,
+                false,
+                result.as_ref().err(),
+            ));
+            match result {
+                Ok(_) => {
+                    nodes[from_index].status = crate::types::NodeStatus::Standby;
+                    nodes[to_index].status = crate::types::NodeStatus::Active;
+                    println!(
+                        "\n{}",
+                        "✅ Interrupted switch resumed and completed".bright_green()
+                    );
+                }
+                Err(e) => println!(
+                    "\n{}",
+                    format!("❌ Resuming the switch failed: {}", e).red()
+                ),
+            }
+        } else {
+            match switch_manager.rollback_interrupted(state).await {
+                Ok(()) => {
+                    nodes[from_index].status = crate::types::NodeStatus::Active;
+                    nodes[to_index].status = crate::types::NodeStatus::Standby;
+                    println!("\n{}", "✅ Interrupted switch rolled back".bright_green());
+                }
+                Err(e) => println!("\n{}", format!("❌ Rollback failed: {}", e).red()),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Outcome of the automatic rollback after a switch failed part-way
+#[derive(Debug, Clone)]
+pub(crate) struct RollbackReport {
+    pub failed_step: String,
+    pub error: String,
+    pub standby_status: String,
+    pub primary_restored: bool,
+    pub rollback_error: Option<String>,
+}
+
+impl RollbackReport {
+    /// Describe the resulting node states as a `SwitchError::PartialSwitch`
+    pub(crate) fn to_switch_error(&self) -> SwitchError {
+        let active_status = if self.primary_restored {
+            "Rolled back - restored to funded identity".to_string()
+        } else {
+            format!(
+                "Left on unfunded identity - rollback failed: {}",
+                self.rollback_error.as_deref().unwrap_or("unknown error")
+            )
+        };
+
+        SwitchError::PartialSwitch {
+            active_status,
+            standby_status: format!(
+                "{} ({} failed: {})",
+                self.standby_status, self.failed_step, self.error
+            ),
+        }
+    }
+}
+
+/// Size and SHA-256 of a tower file, used to verify the copy end to end
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct FileDigest {
+    pub sha256: String,
+    pub size: u64,
+}
+
+/// Parse the output of `sha256sum <file> && stat -c %s <file>`
+pub(crate) fn parse_digest_output(output: &str) -> Result<FileDigest> {
+    let mut lines = output
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty());
+
+    let sha256 = lines
+        .next()
+        .and_then(|line| line.split_whitespace().next())
+        .filter(|hash| hash.len() == 64 && hash.chars().all(|c| c.is_ascii_hexdigit()))
+        .ok_or_else(|| anyhow!("Unexpected sha256sum output: {}", output.trim()))?
+        .to_lowercase();
+
+    let size = lines
+        .next()
+        .and_then(|line| line.parse::<u64>().ok())
+        .ok_or_else(|| anyhow!("Unexpected stat output: {}", output.trim()))?;
+
+    Ok(FileDigest { sha256, size })
+}
+
+/// Decode the base64 read from the active node back into the raw tower bytes
+pub(crate) fn decode_base64(encoded: &str) -> Result<Vec<u8>> {
+    use base64::Engine;
+
+    let compact: String = encoded.split_whitespace().collect();
+    base64::engine::general_purpose::STANDARD
+        .decode(compact)
+        .map_err(|e| anyhow!("Tower data is not valid base64: {}", e))
+}
+
+/// Size and SHA-256 of bytes held locally
+pub(crate) fn digest_bytes(bytes: &[u8]) -> FileDigest {
+    FileDigest {
+        sha256: solana_sdk::hash::hash(bytes)
+            .to_bytes()
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect(),
+        size: bytes.len() as u64,
+    }
+}
+
+/// A program and its arguments run on a remote node over SSH
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct RemoteCommand {
+    pub program: String,
+    pub args: Vec<String>,
+}
+
+impl RemoteCommand {
+    /// The command line as a shell would see it, used for display and switch plans
+    pub(crate) fn display(&self) -> String {
+        std::iter::once(self.program.as_str())
+            .chain(self.args.iter().map(String::as_str))
+            .map(shell_quote)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    async fn run(
+        &self,
+        ssh_pool: &crate::ssh::AsyncSshPool,
+        node: &crate::types::NodeConfig,
+        ssh_key: &str,
+    ) -> Result<String> {
+        let args: Vec<&str> = self.args.iter().map(String::as_str).collect();
+        ssh_pool
+            .execute_command_with_args(node, ssh_key, &self.program, &args)
+            .await
+    }
+}
+
+pub(crate) fn shell_quote(arg: &str) -> String {
+    let is_plain = !arg.is_empty()
+        && arg
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./=:@,+%".contains(c));
+    if is_plain {
+        arg.to_string()
+    } else {
+        format!("'{}'", arg.replace('\'', "'\\''"))
+    }
+}
+
+/// Pick the set-identity command for the validator running on `node`, based on
+/// its `ps` output. Returns a short description and the command.
+pub(crate) fn set_identity_command(
+    node: &mut crate::types::NodeWithStatus,
+    process_info: &str,
+    identity_keypair: &str,
+    require_tower: bool,
+) -> Result<(&'static str, RemoteCommand)> {
+    let backend = crate::validator_backend::backend_for_process(&node.validator_type, process_info)
+        .ok_or_else(|| {
+            anyhow!(
+                "Unsupported validator type for set-identity on {}",
+                node.node.label
+            )
+        })?;
+    let command =
+        backend.set_identity_command(node, process_info, identity_keypair, require_tower)?;
+    Ok((backend.set_identity_description(), command))
+}
+
+/// Move a node to its unfunded identity outside of a switch, e.g. to fence a second voter
+pub(crate) async fn set_node_unfunded(
+    ssh_pool: &crate::ssh::AsyncSshPool,
+    node: &crate::types::NodeWithStatus,
+    ssh_key: &str,
+) -> Result<()> {
+    let mut node = node.clone();
+    let process_info = ssh_pool
+        .execute_command(&node.node, ssh_key, VALIDATOR_PROCESS_QUERY)
+        .await?;
+    let identity_keypair = node.node.paths.unfunded_identity.clone();
+    let (_, command) = set_identity_command(&mut node, &process_info, &identity_keypair, false)?;
+    command.run(ssh_pool, &node.node, ssh_key).await?;
+    Ok(())
+}
+
+/// Remote command printing the SHA-256 and size of a file
+fn digest_command(path: &str) -> String {
+    format!("sha256sum '{}' && stat -c %s '{}'", path, path)
+}
+
+/// Remote command moving the verified tower copy into place
+fn tower_rename_command(staging: &str, destination: &str) -> String {
+    format!(
+        "sync '{}' 2>/dev/null; mv -f '{}' '{}' && echo 'renamed'",
+        staging, staging, destination
+    )
+}
+
+/// Remote command printing the public half of a keypair file (bytes 33-64), so the
+/// secret key never leaves the node
+fn keypair_pubkey_command(path: &str) -> String {
+    format!(
+        "sed 's/[^0-9,]//g' '{}' | tr -d '\\n' | cut -d, -f33-64",
+        path
+    )
+}
+
+/// Parse the comma-separated public key bytes printed by `keypair_pubkey_command`
+pub(crate) fn parse_keypair_pubkey_output(output: &str) -> Result<String> {
+    let bytes = output
+        .trim()
+        .split(',')
+        .map(|b| b.trim().parse::<u8>())
+        .collect::<std::result::Result<Vec<u8>, _>>()
+        .map_err(|e| anyhow!("Invalid keypair file contents: {}", e))?;
+    let bytes: [u8; 32] = bytes
+        .try_into()
+        .map_err(|b: Vec<u8>| anyhow!("Expected 32 public key bytes, got {}", b.len()))?;
+    Ok(solana_sdk::pubkey::Pubkey::new_from_array(bytes).to_string())
+}
+
+/// Whether the old active node gave up the funded identity after step 1
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum UnfundedCheck {
+    /// The node reports its unfunded identity
+    Confirmed,
+    /// The node still reports the funded identity
+    StillFunded,
+    /// The node could not be asked, or reports an unexpected identity
+    Unconfirmed(String),
+}
+
+/// Compare the identity reported by the old active with the funded and unfunded pubkeys
+pub(crate) fn check_reported_identity(
+    reported: &str,
+    funded: &str,
+    unfunded: Option<&str>,
+) -> UnfundedCheck {
+    if reported == funded {
+        return UnfundedCheck::StillFunded;
+    }
+    match unfunded {
+        Some(unfunded) if unfunded != reported => UnfundedCheck::Unconfirmed(format!(
+            "reports {} instead of the unfunded identity {}",
+            reported, unfunded
+        )),
+        _ => UnfundedCheck::Confirmed,
+    }
+}
+
+/// Steps of the switch pipeline with their own timeout, retries and requirement
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SwitchStep {
+    DemotePrimary,
+    TransferTower,
+    PromoteStandby,
+    Verify,
+}
+
+impl SwitchStep {
+    pub(crate) fn label(&self) -> &'static str {
+        match self {
+            SwitchStep::DemotePrimary => "Active → Unfunded",
+            SwitchStep::TransferTower => "Tower transfer",
+            SwitchStep::PromoteStandby => "Standby → Funded",
+            SwitchStep::Verify => "Verification",
+        }
+    }
+
+    pub(crate) fn policy<'a>(
+        &self,
+        steps: &'a crate::types::StepPolicies,
+    ) -> &'a crate::types::StepPolicy {
+        match self {
+            SwitchStep::DemotePrimary => &steps.demote_primary,
+            SwitchStep::TransferTower => &steps.transfer_tower,
+            SwitchStep::PromoteStandby => &steps.promote_standby,
+            SwitchStep::Verify => &steps.verify,
+        }
+    }
+}
+
+/// Errors worth retrying: the SSH connection or a command on it failed, or the step timed out.
+/// A command that ran and failed is not retried.
+pub(crate) fn is_transient_error(error: &anyhow::Error) -> bool {
+    if error.is::<StaleTower>() {
+        return false;
+    }
+    let message = format!("{:#}", error).to_lowercase();
+    [
+        "failed to connect",
+        "failed to execute command",
+        "failed to spawn",
+        "connection",
+        "broken pipe",
+        "timed out",
+    ]
+    .iter()
+    .any(|pattern| message.contains(pattern))
+}
+
+/// Permissions of the funded keypair pushed to the new active in keyless standby mode
+const FUNDED_KEYPAIR_MODE: &str = "600";
+
+/// Public key of a keypair file (a JSON array of the 64 keypair bytes)
+pub(crate) fn keypair_file_pubkey(contents: &[u8]) -> Result<String> {
+    let bytes: Vec<u8> = serde_json::from_slice(contents)
+        .map_err(|e| anyhow!("Invalid keypair file contents: {}", e))?;
+    if bytes.len() != 64 {
+        return Err(anyhow!("Expected 64 keypair bytes, got {}", bytes.len()));
+    }
+    let pubkey: [u8; 32] = bytes[32..].try_into()?;
+    Ok(solana_sdk::pubkey::Pubkey::new_from_array(pubkey).to_string())
+}
+
+/// Remote command overwriting and removing a keypair file, falling back to `rm`
+/// where `shred` is not available
+fn secure_delete_command(path: &str) -> String {
+    let path = shell_quote(path);
+    format!(
+        "shred -u -z {path} 2>/dev/null || rm -f {path}; test -e {path} && echo 'present' || echo 'removed'",
+        path = path
+    )
+}
+
+/// Remote command reporting whether a non-empty tower file is in place
+fn tower_check_command(path: &str) -> String {
+    format!("test -s '{}' && echo 'present' || echo 'missing'", path)
+}
+
+pub(crate) struct SwitchManager {
+    active_node_with_status: crate::types::NodeWithStatus,
+    standby_node_with_status: crate::types::NodeWithStatus,
+    validator_pair: crate::types::ValidatorPair,
+    ssh_pool: Arc<crate::ssh::AsyncSshPool>,
+    detected_ssh_keys: std::collections::HashMap<String, String>,
+    tower_file_name: Option<String>,
+    tower_transfer_time: Option<Duration>,
+    pub(crate) identity_switch_time: Option<Duration>,
+    active_switch_time: Option<Duration>,
+    standby_switch_time: Option<Duration>,
+    first_vote_time: Option<Duration>,
+    /// When set-identity returned on the standby, the start of time to first vote
+    activated_at: Option<Instant>,
+    /// Last vote slot on-chain just before the standby took the funded identity
+    pre_activation_vote: Option<u64>,
+    /// Cluster slot right after set-identity returned on the standby
+    set_identity_slot: Option<u64>,
+    switch_started: Option<Instant>,
+    pub(crate) rollback_report: Option<RollbackReport>,
+    pub(crate) switch_policy: crate::types::SwitchPolicy,
+    pub(crate) wait_for_leader_window: bool,
+    pub(crate) schedule: Option<crate::switch_schedule::SwitchSchedule>,
+    plan: Option<SwitchPlan>,
+    /// Tower transfer error the operator chose to activate the standby despite
+    pub(crate) activated_without_tower: Option<String>,
+    /// Why the tower's freshness could not be checked, if activated anyway by policy
+    pub(crate) tower_unverified: Option<String>,
+    /// Why the old active could not be confirmed on its unfunded identity, if activated anyway
+    pub(crate) unfunded_unconfirmed: Option<String>,
+    /// `ps` output of the old active from step 1, used to find its RPC port
+    active_process_info: Option<String>,
+    /// Base64 funded keypair, loaded once per switch in keyless standby mode
+    pub(crate) funded_keypair: Option<String>,
+    /// Why the funded keypair could not be removed from the old active, if it failed
+    keypair_cleanup_error: Option<String>,
+    /// Progress persisted after every step of a live switch
+    switch_state: Option<SwitchState>,
+    /// Last step an interrupted switch completed; those steps are skipped
+    resume_from: Option<SwitchPhase>,
+    /// Remove switch locks held by other svs instances (`--break-lock`)
+    pub(crate) break_lock: bool,
+    /// Used to alert when another operator's switch lock was broken
+    pub(crate) alert_config: Option<crate::types::AlertConfig>,
+    switch_lock: Option<SwitchLock>,
+    /// Progress line of this switch in a batch switch
+    pub(crate) progress: Option<indicatif::ProgressBar>,
+    /// Best-effort steps that failed without stopping the switch
+    best_effort_failures: Vec<String>,
+    /// Passed to hooks, e.g. to tell dashboard switches from CLI ones
+    pub(crate) trigger: SwitchTrigger,
+    hook_failures: Vec<String>,
+}
+
+impl SwitchManager {
+    pub(crate) fn new(
+        active_node_with_status: crate::types::NodeWithStatus,
+        standby_node_with_status: crate::types::NodeWithStatus,
+        validator_pair: crate::types::ValidatorPair,
+        ssh_pool: Arc<crate::ssh::AsyncSshPool>,
+        detected_ssh_keys: std::collections::HashMap<String, String>,
+    ) -> Self {
+        Self {
+            active_node_with_status,
+            standby_node_with_status,
+            validator_pair,
+            ssh_pool,
+            detected_ssh_keys,
+            tower_file_name: None,
+            tower_transfer_time: None,
+            identity_switch_time: None,
+            active_switch_time: None,
+            standby_switch_time: None,
+            first_vote_time: None,
+            activated_at: None,
+            pre_activation_vote: None,
+            set_identity_slot: None,
+            switch_started: None,
+            rollback_report: None,
+            switch_policy: crate::types::SwitchPolicy::default(),
+            wait_for_leader_window: true,
+            schedule: None,
+            plan: None,
+            activated_without_tower: None,
+            tower_unverified: None,
+            unfunded_unconfirmed: None,
+            active_process_info: None,
+            funded_keypair: None,
+            keypair_cleanup_error: None,
+            switch_state: None,
+            resume_from: None,
+            break_lock: false,
+            alert_config: None,
+            switch_lock: None,
+            progress: None,
+            best_effort_failures: Vec::new(),
+            trigger: SwitchTrigger::Manual,
+            hook_failures: Vec::new(),
+        }
+    }
+
+    fn get_ssh_key_for_node(&self, host: &str) -> Result<String> {
+        // Use detected key if available
+        self.detected_ssh_keys
+            .get(host)
+            .cloned()
+            .ok_or_else(|| anyhow!("No SSH key detected for host: {}", host))
+    }
+
+    pub(crate) async fn execute_switch(
+        &mut self,
+        dry_run: bool,
+        require_confirmation: bool,
+    ) -> Result<bool> {
+        let result = self
+            .execute_switch_steps(dry_run, require_confirmation)
+            .await;
+        if let Err(e) = &result {
+            // Not when the switch never got the lock, it belongs to someone else then
+            if !dry_run && self.switch_lock.is_some() {
+                let _ = self.run_hooks(HookEvent::OnFailure, false, Some(e)).await;
+            }
+        }
+        self.release_switch_lock().await;
+        result
+    }
+
+    async fn execute_switch_steps(
+        &mut self,
+        dry_run: bool,
+        require_confirmation: bool,
+    ) -> Result<bool> {
+        // Show confirmation dialog (except for dry run or when explicitly disabled)
+        if !dry_run && require_confirmation {
+            println!(
+                "\n{}",
+                "⚠️  Validator Switch Confirmation".bright_yellow().bold()
+            );
+            println!("{}", "━".repeat(50).dimmed());
+            println!();
+            println!(
+                "  {} → {}",
+                format!(
+                    "🟢 ACTIVE: {} ({}) {}",
+                    self.active_node_with_status.node.label,
+                    self.active_node_with_status.node.host,
+                    self.active_node_with_status
+                        .version
+                        .as_ref()
+                        .unwrap_or(&"Unknown".to_string())
+                )
+                .bright_green(),
+                "🔄 STANDBY".dimmed()
+            );
+            println!(
+                "  {} → {}",
+                format!(
+                    "⚪ STANDBY: {} ({}) {}",
+                    self.standby_node_with_status.node.label,
+                    self.standby_node_with_status.node.host,
+                    self.standby_node_with_status
+                        .version
+                        .as_ref()
+                        .unwrap_or(&"Unknown".to_string())
+                )
+                .white(),
+                "🟢 ACTIVE".bright_green()
+            );
+            println!();
+            println!(
+                "  {}",
+                "This will switch your validator identity between nodes.".yellow()
+            );
+            println!("  {}", "Estimated time: ~10 seconds".dimmed());
+            if let Some(schedule) = &self.schedule {
+                println!(
+                    "  {}",
+                    format!("Scheduled for: {}", schedule.describe()).bright_cyan()
+                );
+            }
+            if let Some(plan) = &self.plan {
+                println!(
+                    "  {}",
+                    format!(
+                        "Executing plan created by {} at {}",
+                        plan.created_by, plan.created_at
+                    )
+                    .bright_cyan()
+                );
+            }
+            println!();
+
+            // Use inquire for confirmation
+            use inquire::Confirm;
+            let confirmed = Confirm::new("Do you want to proceed with the validator switch?")
+                .with_default(false)
+                .prompt()?;
+
+            if !confirmed {
+                println!("\n{}", "❌ Validator switch cancelled by user".red());
+                return Ok(false);
+            }
+            println!();
+            // Ensure output is flushed after confirmation
+            std::io::stdout().flush()?;
+        }
+
+        // Nobody else may drive these nodes until the switch is over, hooks and waits included
+        if !dry_run {
+            self.acquire_switch_lock().await?;
+        }
+        let lock_ttl = self.switch_policy.lock_ttl_seconds;
+
+        // Hold until the planned start time
+        if let Some(schedule) = self.schedule.clone() {
+            if dry_run {
+                println_if_not_silent!("🗓️  Scheduled for: {}", schedule.describe());
+            } else {
+                let mut lock = self.switch_lock.take();
+                let started = keep_lock_alive(
+                    &mut lock,
+                    &self.ssh_pool,
+                    &self.detected_ssh_keys,
+                    lock_ttl,
+                    self.wait_for_scheduled_start(&schedule),
+                )
+                .await;
+                self.switch_lock = lock;
+                if !started?? {
+                    println!("\n{}", "❌ Scheduled switch aborted by user".red());
+                    return Ok(false);
+                }
+            }
+        }
+
+        // Operator hooks run before the leader window wait, so they can't use up the window
+        if self.resume_from.is_none() {
+            self.run_hooks(HookEvent::PreSwitch, dry_run, None).await?;
+        }
+
+        // Avoid switching during our own leader slots
+        if self.wait_for_leader_window {
+            let mut lock = self.switch_lock.take();
+            let waited = keep_lock_alive(
+                &mut lock,
+                &self.ssh_pool,
+                &self.detected_ssh_keys,
+                lock_ttl,
+                self.wait_for_safe_leader_window(dry_run, None),
+            )
+            .await;
+            self.switch_lock = lock;
+            waited?;
+        }
+
+        // A reviewed plan is only executed if nothing changed since it was made;
+        // checked under the lock so nothing can change before the steps run
+        if let Some(plan) = self.plan.clone() {
+            self.verify_plan(&plan).await?;
+        }
+
+        // Read the unfunded pubkey now so confirming step 1 costs a single RPC call later
+        let unfunded_pubkey = if dry_run {
+            None
+        } else {
+            self.read_unfunded_pubkey().await
+        };
+
+        // Keyless standby: make sure the funded keypair can be pushed before anything changes
+        if self.switch_policy.keyless_standby
+            && self
+                .resume_from
+                .is_none_or(|phase| phase < SwitchPhase::StandbyPromoted)
+        {
+            self.load_funded_keypair().await?;
+        }
+
+        // Start timing the entire switch operation
+        let total_switch_start = Instant::now();
+        self.switch_started = Some(total_switch_start);
+
+        // Steps already completed by an interrupted switch are skipped
+        let completed = |phase: SwitchPhase| self.resume_from.is_some_and(|from| from >= phase);
+        let (primary_demoted, tower_copied, standby_promoted) = (
+            completed(SwitchPhase::PrimaryDemoted),
+            completed(SwitchPhase::TowerCopied),
+            completed(SwitchPhase::StandbyPromoted),
+        );
+        if !dry_run && self.switch_state.is_none() {
+            self.switch_state = Some(SwitchState::new(
+                &self.validator_pair,
+                &self.active_node_with_status.node.label,
+                &self.standby_node_with_status.node.label,
+            ));
+            self.save_phase(self.resume_from.unwrap_or(SwitchPhase::Started));
+        }
+
+        // Step 1: Switch active node to unfunded identity
+        println_if_not_silent!(
+            "\n{}",
+            "🔄 Step 1: Switch Active Node to Unfunded Identity"
+                .bright_blue()
+                .bold()
+        );
+        if primary_demoted {
+            println_if_not_silent!("   ✓ Already completed before the interruption");
+        } else {
+            let active_switch_start = Instant::now();
+            if let Err(e) = self
+                .run_step(SwitchStep::DemotePrimary, dry_run, None)
+                .await
+            {
+                if dry_run || self.switch_policy.steps.demote_primary.is_required(true) {
+                    return Err(e);
+                }
+                self.skip_failed_step(SwitchStep::DemotePrimary, e);
+            }
+            self.active_switch_time = Some(active_switch_start.elapsed());
+            if !dry_run {
+                println_if_not_silent!(
+                    "   ✓ Completed in {}",
+                    format!("{}ms", self.active_switch_time.unwrap().as_millis())
+                        .bright_yellow()
+                        .bold()
+                );
+                self.save_phase(SwitchPhase::PrimaryDemoted);
+            }
+        }
+
+        // Step 2: Transfer tower file
+        println_if_not_silent!(
+            "\n{}",
+            "📤 Step 2: Transfer Tower File".bright_blue().bold()
+        );
+        if tower_copied {
+            println_if_not_silent!("   ✓ Already completed before the interruption");
+        } else if let Err(e) = self
+            .run_step(SwitchStep::TransferTower, dry_run, None)
+            .await
+        {
+            if dry_run {
+                return Err(e);
+            }
+            if e.is::<StaleTower>() {
+                // Never overridable, whatever require_tower and the step policy say
+                return Err(self
+                    .rollback_partial_switch("Tower transfer", false, e)
+                    .await);
+            }
+            if self.switch_policy.require_tower {
+                if !self.confirm_activation_without_tower(&e) {
+                    return Err(self
+                        .rollback_partial_switch("Tower transfer", false, e)
+                        .await);
+                }
+                self.activated_without_tower = Some(e.to_string());
+            } else if self.switch_policy.steps.transfer_tower.is_required(true) {
+                return Err(self
+                    .rollback_partial_switch("Tower transfer", false, e)
+                    .await);
+            } else {
+                self.skip_failed_step(SwitchStep::TransferTower, e);
+            }
+        }
+        // Note: tower_transfer_time is set inside transfer_tower_file method
+        if !tower_copied {
+            self.run_hooks(HookEvent::AfterTowerTransfer, dry_run, None)
+                .await?;
+        }
+
+        // The standby may only take the funded identity once the old active gave it up
+        let active_label = self.active_node_with_status.node.label.clone();
+        if standby_promoted {
+            // Nothing left to confirm, the standby already runs the funded identity
+        } else if dry_run {
+            println_if_not_silent!(
+                "\n🔍 Would confirm via getIdentity that {} reports its unfunded identity",
+                active_label
+            );
+        } else {
+            // Votes past this slot can only come from the standby once it's promoted
+            let (unfunded_check, pre_activation_vote) = tokio::join!(
+                self.confirm_active_unfunded(unfunded_pubkey.as_deref()),
+                crate::solana_rpc::fetch_vote_account_data(
+                    &self.validator_pair.rpc,
+                    &self.validator_pair.vote_pubkey,
+                )
+            );
+            self.pre_activation_vote = pre_activation_vote
+                .ok()
+                .map(|data| data.vote_account_info.last_vote);
+            match unfunded_check {
+                UnfundedCheck::Confirmed => {
+                    println_if_not_silent!("\n✅ {} reports its unfunded identity", active_label);
+                }
+                UnfundedCheck::StillFunded => {
+                    let e = anyhow!("{} still reports the funded identity", active_label);
+                    return Err(self
+                        .rollback_partial_switch("Old active identity check", false, e)
+                        .await);
+                }
+                UnfundedCheck::Unconfirmed(reason) => {
+                    let e = anyhow!(
+                        "Could not confirm {} dropped the funded identity: {}",
+                        active_label,
+                        reason
+                    );
+                    if !self.confirm_activation_unconfirmed(&e) {
+                        return Err(self
+                            .rollback_partial_switch("Old active identity check", false, e)
+                            .await);
+                    }
+                    self.unfunded_unconfirmed = Some(reason);
+                }
+            }
+            self.save_phase(SwitchPhase::TowerCopied);
+        }
+
+        // Step 3: Switch standby node to funded identity
+        println_if_not_silent!(
+            "\n{}",
+            "🚀 Step 3: Switch Standby Node to Funded Identity"
+                .bright_blue()
+                .bold()
+        );
+        if standby_promoted {
+            println_if_not_silent!("   ✓ Already completed before the interruption");
+        } else {
+            let standby_switch_start = Instant::now();
+            if let Err(e) = self
+                .run_step(SwitchStep::PromoteStandby, dry_run, None)
+                .await
+            {
+                if dry_run {
+                    return Err(e);
+                }
+                return Err(self
+                    .rollback_partial_switch("Standby activation", true, e)
+                    .await);
+            }
+            self.activated_at = Some(Instant::now());
+            self.standby_switch_time = Some(standby_switch_start.elapsed());
+            if !dry_run {
+                self.set_identity_slot =
+                    crate::leader_window::fetch_current_slot(&self.validator_pair.rpc)
+                        .await
+                        .ok();
+                println_if_not_silent!(
+                    "   ✓ Completed in {}",
+                    format!("{}ms", self.standby_switch_time.unwrap().as_millis())
+                        .bright_yellow()
+                        .bold()
+                );
+                self.save_phase(SwitchPhase::StandbyPromoted);
+            }
+            self.run_hooks(HookEvent::AfterActivation, dry_run, None)
+                .await?;
+        }
+
+        // Record total identity switch time
+        if !dry_run {
             self.identity_switch_time = Some(total_switch_start.elapsed());
         }
 
+        // Keyless standby: the old active no longer needs the funded keypair
+        if self.switch_policy.keyless_standby {
+            let active = &self.active_node_with_status.node;
+            if dry_run {
+                println_if_not_silent!(
+                    "\n🔒 Would securely delete {} from {}",
+                    active.paths.funded_identity,
+                    active.label
+                );
+            } else {
+                match self.remove_old_active_funded_keypair().await {
+                    Ok(()) => println_if_not_silent!(
+                        "\n🔒 Funded keypair removed from {}",
+                        self.active_node_with_status.node.label
+                    ),
+                    Err(e) => {
+                        println_if_not_silent!(
+                            "\n{}",
+                            format!("⚠️  Could not remove the funded keypair: {}", e).yellow()
+                        );
+                        self.keypair_cleanup_error = Some(e.to_string());
+                    }
+                }
+            }
+        }
+
         // Step 4: Verify new active node health (former standby)
         println_if_not_silent!(
             "\n{}",
@@ -597,187 +1521,906 @@ impl SwitchManager {
                 .bright_blue()
                 .bold()
         );
-        self.verify_backup_catchup(dry_run).await?;
+        if let Err(e) = self.run_step(SwitchStep::Verify, dry_run, None).await {
+            if dry_run || self.switch_policy.steps.verify.is_required(true) {
+                return Err(e);
+            }
+            self.skip_failed_step(SwitchStep::Verify, e);
+        }
+        self.confirm_first_landed_vote(dry_run).await;
+        self.save_phase(SwitchPhase::Verified);
+
+        // Summary
+        self.print_summary(dry_run);
+
+        Ok(!dry_run)
+    }
+
+    pub(crate) async fn switch_primary_to_unfunded(&mut self, dry_run: bool) -> Result<()> {
+        // Detect validator type to use appropriate command
+        let ssh_key = self.get_ssh_key_for_node(&self.active_node_with_status.node.host)?;
+        let process_info = self
+            .ssh_pool
+            .execute_command(
+                &self.active_node_with_status.node,
+                &ssh_key,
+                VALIDATOR_PROCESS_QUERY,
+            )
+            .await?;
+
+        let identity_keypair = self
+            .active_node_with_status
+            .node
+            .paths
+            .unfunded_identity
+            .clone();
+        let (subtitle, command) = set_identity_command(
+            &mut self.active_node_with_status,
+            &process_info,
+            &identity_keypair,
+            false,
+        )?;
+        self.active_process_info = Some(process_info);
+
+        println_if_not_silent!("{}", subtitle.dimmed());
+        println_if_not_silent!(
+            "ssh {}@{} '{}'",
+            self.active_node_with_status.node.user,
+            self.active_node_with_status.node.host,
+            command.display()
+        );
+
+        if !dry_run {
+            let spinner =
+                ConditionalSpinner::new("Switching active validator to unfunded identity...");
+            command
+                .run(&self.ssh_pool, &self.active_node_with_status.node, &ssh_key)
+                .await?;
+            // No sleep - move immediately to next step!
+            spinner.stop_with_message("✅ Active validator switched to unfunded identity");
+        }
+
+        Ok(())
+    }
+
+    /// Build the switch journal record for this attempt
+    pub(crate) fn journal_record(
+        &self,
+        trigger: SwitchTrigger,
+        dry_run: bool,
+        error: Option<&anyhow::Error>,
+    ) -> SwitchRecord {
+        let mut record = SwitchRecord::new(
+            trigger,
+            dry_run,
+            &self.validator_pair,
+            &self.active_node_with_status,
+            &self.standby_node_with_status,
+        );
+
+        record.success = error.is_none();
+        record.steps.active_to_unfunded_ms = duration_ms(self.active_switch_time);
+        record.steps.tower_transfer_ms = duration_ms(self.tower_transfer_time);
+        record.steps.standby_to_funded_ms = duration_ms(self.standby_switch_time);
+        record.steps.identity_switch_ms = duration_ms(self.identity_switch_time);
+        record.steps.first_vote_ms = duration_ms(self.first_vote_time);
+        record.steps.total_ms = duration_ms(self.switch_started.map(|start| start.elapsed()));
+
+        if let Some(report) = &self.rollback_report {
+            record
+                .errors
+                .push(format!("{} failed: {}", report.failed_step, report.error));
+            match &report.rollback_error {
+                Some(rollback_error) => record
+                    .errors
+                    .push(format!("Rollback failed: {}", rollback_error)),
+                None => record
+                    .errors
+                    .push("Rolled back: original active restored to funded identity".to_string()),
+            }
+        } else if let Some(e) = error {
+            record.errors.push(e.to_string());
+        }
+        record
+            .errors
+            .extend(self.best_effort_failures.iter().cloned());
+        if let Some(tower_error) = &self.activated_without_tower {
+            record.errors.push(format!(
+                "Tower transfer failed, standby activated without tower by operator decision: {}",
+                tower_error
+            ));
+        }
+        if let Some(reason) = &self.tower_unverified {
+            record.errors.push(format!(
+                "Tower freshness not verified, standby activated anyway (switch_policy.allow_unverified_tower): {}",
+                reason
+            ));
+        }
+        if let Some(reason) = &self.unfunded_unconfirmed {
+            record.errors.push(format!(
+                "Old active not confirmed on unfunded identity, standby activated anyway: {}",
+                reason
+            ));
+        }
+        if let Some(phase) = self.resume_from {
+            record.errors.push(format!(
+                "Resumed after an interruption ({})",
+                phase.describe()
+            ));
+        }
+        if let Some(cleanup_error) = &self.keypair_cleanup_error {
+            record.errors.push(format!(
+                "Funded keypair not removed from old active: {}",
+                cleanup_error
+            ));
+        }
+
+        record
+    }
+
+    /// Put the original active node back on its funded identity after step 2 or 3
+    /// failed, so the validator keeps voting. Returns the `PartialSwitch` error
+    /// describing the state both nodes were left in.
+    async fn rollback_partial_switch(
+        &mut self,
+        failed_step: &str,
+        standby_touched: bool,
+        error: anyhow::Error,
+    ) -> anyhow::Error {
+        println_if_not_silent!(
+            "\n{}",
+            format!("❌ {} failed: {}", failed_step, error).red()
+        );
+        println_if_not_silent!(
+            "\n{}",
+            "↩️  Rolling back: restoring original active node to funded identity"
+                .bright_yellow()
+                .bold()
+        );
+
+        // If the standby might already be running the funded identity, restoring
+        // the old active would leave two nodes voting with the same identity.
+        let (safe_to_restore, standby_status) = if !standby_touched {
+            (
+                true,
+                "Not activated - still on unfunded identity".to_string(),
+            )
+        } else {
+            match self.standby_holds_funded_identity().await {
+                Ok(false) => (
+                    true,
+                    "Not activated - still on unfunded identity".to_string(),
+                ),
+                Ok(true) => (
+                    false,
+                    "Reports the funded identity despite the activation error".to_string(),
+                ),
+                Err(e) => (false, format!("Identity could not be verified: {}", e)),
+            }
+        };
+
+        let rollback_result = if safe_to_restore {
+            self.restore_primary_to_funded().await
+        } else {
+            Err(anyhow!(
+                "skipped - standby may hold the funded identity, restoring the old active could cause double voting"
+            ))
+        };
+
+        let report = RollbackReport {
+            failed_step: failed_step.to_string(),
+            error: error.to_string(),
+            standby_status,
+            primary_restored: rollback_result.is_ok(),
+            rollback_error: rollback_result.err().map(|e| e.to_string()),
+        };
+
+        // Keyless standby: a standby that did not take the identity must not keep the key
+        if self.switch_policy.keyless_standby && standby_touched && safe_to_restore {
+            let standby = self.standby_node_with_status.node.clone();
+            if let Err(e) = self.remove_funded_keypair(&standby).await {
+                println_if_not_silent!(
+                    "{}",
+                    format!(
+                        "⚠️  Could not remove the funded keypair from {}: {}",
+                        standby.label, e
+                    )
+                    .yellow()
+                );
+            }
+        }
+
+        if report.primary_restored {
+            self.save_phase(SwitchPhase::RolledBack);
+            println_if_not_silent!(
+                "{}",
+                format!(
+                    "✅ {} restored to funded identity",
+                    self.active_node_with_status.node.label
+                )
+                .bright_green()
+            );
+        }
+
+        let switch_error = report.to_switch_error();
+        self.rollback_report = Some(report);
+        anyhow!(switch_error.to_user_message())
+    }
+
+    /// Take the switch lock on both nodes, alerting if another operator's lock was broken
+    async fn acquire_switch_lock(&mut self) -> Result<()> {
+        let lock = SwitchLock::acquire(
+            &self.ssh_pool,
+            &[
+                &self.active_node_with_status.node,
+                &self.standby_node_with_status.node,
+            ],
+            &self.detected_ssh_keys,
+            &self.validator_pair.identity_pubkey,
+            "switch",
+            self.switch_policy.lock_ttl_seconds,
+            self.break_lock,
+            false,
+        )
+        .await?;
+
+        if !lock.broken.is_empty() {
+            for (label, holder) in &lock.broken {
+                println_if_not_silent!(
+                    "{}",
+                    format!("🔓 Broke switch lock on {} held by {}", label, holder).yellow()
+                );
+            }
+            if let Some(alert_config) = &self.alert_config {
+                let _ = crate::alert::AlertManager::new(alert_config.clone())
+                    .send_switch_lock_broken_alert(
+                        &self.validator_pair.identity_pubkey,
+                        &lock.broken,
+                        &lock.owner.owner,
+                    )
+                    .await;
+            }
+        }
+        self.switch_lock = Some(lock);
+        Ok(())
+    }
+
+    async fn release_switch_lock(&mut self) {
+        if let Some(lock) = self.switch_lock.take() {
+            lock.release(&self.ssh_pool, &self.detected_ssh_keys).await;
+        }
+    }
+
+    /// Run one step with its `switch_policy.steps` timeout (`default_timeout` if unset),
+    /// retrying with backoff after transient SSH errors
+    pub(crate) async fn run_step(
+        &mut self,
+        step: SwitchStep,
+        dry_run: bool,
+        default_timeout: Option<Duration>,
+    ) -> Result<()> {
+        let policy = step.policy(&self.switch_policy.steps).clone();
+        let step_timeout = policy.timeout(default_timeout);
+        let mut retry = 0;
+        loop {
+            let attempt = async {
+                match step {
+                    SwitchStep::DemotePrimary => self.switch_primary_to_unfunded(dry_run).await,
+                    SwitchStep::TransferTower => self.transfer_tower_file(dry_run).await,
+                    SwitchStep::PromoteStandby => self.switch_backup_to_funded(dry_run).await,
+                    SwitchStep::Verify => self.verify_backup_catchup(dry_run).await,
+                }
+            };
+            let result = match step_timeout {
+                Some(limit) if !dry_run => tokio::time::timeout(limit, attempt)
+                    .await
+                    .unwrap_or_else(|_| {
+                        Err(anyhow!(
+                            "{} timed out after {}s",
+                            step.label(),
+                            limit.as_secs()
+                        ))
+                    }),
+                _ => attempt.await,
+            };
+
+            match result {
+                Err(e) if !dry_run && retry < policy.retries && is_transient_error(&e) => {
+                    retry += 1;
+                    let backoff = policy.backoff(retry);
+                    println_if_not_silent!(
+                        "   {}",
+                        format!(
+                            "⚠️  {} failed ({}), retry {}/{} in {}ms",
+                            step.label(),
+                            e,
+                            retry,
+                            policy.retries,
+                            backoff.as_millis()
+                        )
+                        .yellow()
+                    );
+                    tokio::time::sleep(backoff).await;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Run the `switch_policy.hooks` of `event`. Failed hooks are reported and journaled;
+    /// only a failing pre_switch hook with `veto` set is an error.
+    async fn run_hooks(
+        &mut self,
+        event: HookEvent,
+        dry_run: bool,
+        error: Option<&anyhow::Error>,
+    ) -> Result<()> {
+        let hooks = crate::switch_hooks::hooks_for(&self.switch_policy.hooks, event);
+        if hooks.is_empty() {
+            return Ok(());
+        }
+        if dry_run {
+            for hook in hooks {
+                println_if_not_silent!("🪝 Would run {} hook '{}'", event.as_str(), hook.name);
+            }
+            return Ok(());
+        }
+
+        println_if_not_silent!("\n🪝 Running {} {} hook(s)...", hooks.len(), event.as_str());
+        let context = HookContext::new(
+            event,
+            &self.journal_record(self.trigger, false, error),
+            error.map(|e| e.to_string()),
+        );
+        let failures = crate::switch_hooks::run_hooks(
+            &self.switch_policy.hooks,
+            &context,
+            &self.validator_pair,
+            &self.ssh_pool,
+            &self.detected_ssh_keys,
+        )
+        .await;
+
+        for failure in &failures {
+            println_if_not_silent!("   {}", format!("⚠️  {}", failure.describe()).yellow());
+            self.hook_failures
+                .push(format!("{} {}", event.as_str(), failure.describe()));
+        }
+        match failures.iter().find(|failure| failure.vetoes) {
+            Some(failure) => Err(anyhow!("Switch vetoed by {}", failure.describe())),
+            None => Ok(()),
+        }
+    }
+
+    /// Carry on after a best-effort step failed
+    fn skip_failed_step(&mut self, step: SwitchStep, error: anyhow::Error) {
+        println_if_not_silent!(
+            "   {}",
+            format!(
+                "⚠️  {} failed, continuing (best effort): {}",
+                step.label(),
+                error
+            )
+            .yellow()
+        );
+        self.best_effort_failures
+            .push(format!("{} failed (best effort): {}", step.label(), error));
+    }
+
+    /// Persist the step just completed; nothing is written for dry runs
+    fn save_phase(&mut self, phase: SwitchPhase) {
+        if let Some(progress) = &self.progress {
+            progress.set_message(phase.describe());
+        }
+        if let Some(state) = &mut self.switch_state {
+            state.advance(phase);
+            crate::switch_state::save_state(state);
+        }
+    }
+
+    /// Roll back a switch that was interrupted after `phase`, putting the original
+    /// active node back on its funded identity
+    pub(crate) async fn rollback_interrupted(&mut self, state: SwitchState) -> Result<()> {
+        self.acquire_switch_lock().await?;
+        let result = self.rollback_interrupted_steps(state).await;
+        self.release_switch_lock().await;
+        result
+    }
+
+    async fn rollback_interrupted_steps(&mut self, state: SwitchState) -> Result<()> {
+        let phase = state.phase;
+        self.switch_state = Some(state);
+        let error = anyhow!("svs stopped after: {}", phase.describe());
+        // From the tower copy on, the standby may have taken the funded identity
+        let message = self
+            .rollback_partial_switch(
+                "Interrupted switch",
+                phase >= SwitchPhase::TowerCopied,
+                error,
+            )
+            .await;
+        match &self.rollback_report {
+            Some(report) if report.primary_restored => Ok(()),
+            _ => Err(message),
+        }
+    }
+
+    /// Check via RPC whether the standby node currently reports the funded identity
+    pub(crate) async fn standby_holds_funded_identity(&self) -> Result<bool> {
+        let ssh_key = self.get_ssh_key_for_node(&self.standby_node_with_status.node.host)?;
+        let rpc_port = crate::validator_rpc::get_rpc_port(
+            self.standby_node_with_status.validator_type.clone(),
+            None,
+        );
+
+        let identity = crate::validator_rpc::get_identity(
+            &self.ssh_pool,
+            &self.standby_node_with_status.node,
+            &ssh_key,
+            rpc_port,
+        )
+        .await?;
+
+        Ok(identity == self.validator_pair.identity_pubkey)
+    }
+
+    /// Switch the original active node back to its funded identity
+    async fn restore_primary_to_funded(&mut self) -> Result<()> {
+        let ssh_key = self.get_ssh_key_for_node(&self.active_node_with_status.node.host)?;
+
+        let process_info = self
+            .ssh_pool
+            .execute_command(
+                &self.active_node_with_status.node,
+                &ssh_key,
+                VALIDATOR_PROCESS_QUERY,
+            )
+            .await?;
 
-        // Summary
-        self.print_summary(dry_run);
+        let spinner = ConditionalSpinner::new("Restoring active validator to funded identity...");
 
-        Ok(!dry_run)
+        let identity_keypair = self
+            .active_node_with_status
+            .node
+            .paths
+            .funded_identity
+            .clone();
+        let result = match set_identity_command(
+            &mut self.active_node_with_status,
+            &process_info,
+            &identity_keypair,
+            false,
+        ) {
+            Ok((_, command)) => command
+                .run(&self.ssh_pool, &self.active_node_with_status.node, &ssh_key)
+                .await
+                .map(|_| ()),
+            Err(e) => Err(e),
+        };
+
+        match result {
+            Ok(_) => {
+                spinner.stop_with_message("");
+                Ok(())
+            }
+            Err(e) => {
+                spinner.stop_with_message(&format!("❌ Rollback failed: {}", e));
+                Err(e)
+            }
+        }
     }
 
-    pub(crate) async fn switch_primary_to_unfunded(&mut self, dry_run: bool) -> Result<()> {
-        // Detect validator type to use appropriate command
-        let process_info = {
+    pub(crate) async fn transfer_tower_file(&mut self, dry_run: bool) -> Result<()> {
+        // Use the derived tower path from active node
+        let tower = self.tower_paths()?;
+        let (tower_path, dest_path, temp_path) = (tower.source, tower.destination, tower.staging);
+
+        // Verify the tower file exists
+        let check_tower_cmd = format!("test -f {} && echo 'exists' || echo 'missing'", tower_path);
+        let tower_exists = {
             let ssh_key = self.get_ssh_key_for_node(&self.active_node_with_status.node.host)?;
             let pool = self.ssh_pool.clone();
             pool.execute_command(
                 &self.active_node_with_status.node,
                 &ssh_key,
-                "ps aux | grep -E 'solana-validator|agave|fdctl|firedancer' | grep -v grep",
+                &check_tower_cmd,
             )
             .await?
         };
 
-        let (subtitle, switch_command) = if process_info.contains("fdctl")
-            || process_info.contains("firedancer")
-        {
-            // Use detected fdctl executable path, or extract from running process as fallback
-            let fdctl_path = crate::executable_utils::extract_and_save_fdctl_path(&mut self.active_node_with_status, &process_info)?;
-
-            // Extract config path from the process info
-            let config_path = crate::executable_utils::extract_firedancer_config_path(&process_info)?;
-
-            (
-                "Using Firedancer fdctl set-identity",
-                format!(
-                    "{} set-identity --config \"{}\" \"{}\"",
-                    fdctl_path,
-                    config_path,
-                    self.active_node_with_status.node.paths.unfunded_identity
-                ),
-            )
-        } else if process_info.contains("agave-validator") {
-            // Use detected agave executable path if available
-            let agave_path = self
-                .active_node_with_status
-                .agave_validator_executable
-                .as_ref()
-                .ok_or_else(|| anyhow!("Agave validator executable path not found"))?;
-
-            // Use detected ledger path if available, otherwise error
-            let ledger_path = self
-                .active_node_with_status
-                .ledger_path
-                .as_ref()
-                .ok_or_else(|| anyhow!("Ledger path not detected for active node"))?;
-
-            (
-                "Using Agave validator set-identity",
-                format!(
-                    "{} -l \"{}\" set-identity \"{}\"",
-                    agave_path,
-                    ledger_path,
-                    self.active_node_with_status.node.paths.unfunded_identity
-                ),
-            )
-        } else {
-            // Use detected ledger path if available, otherwise error
-            let ledger_path = self
-                .active_node_with_status
-                .ledger_path
-                .as_ref()
-                .ok_or_else(|| anyhow!("Ledger path not detected for active node"))?;
-
-            (
-                "Using Solana validator restart",
-                format!("{} exit && solana-validator --identity {} --vote-account {} --ledger {} --limit-ledger-size 100000000 --log - &", 
-                    "solana-validator",  // Using validator binary directly instead of solana CLI
-                    self.active_node_with_status.node.paths.unfunded_identity,
-                    self.active_node_with_status.node.paths.vote_keypair,
-                    ledger_path)
-            )
-        };
+        if tower_exists.trim() != "exists" {
+            return Err(anyhow!(
+                "Tower file not found on active node: {}",
+                tower_path
+            ));
+        }
 
-        println_if_not_silent!("{}", subtitle.dimmed());
         println_if_not_silent!(
-            "ssh {}@{} '{}'",
+            "  📤 {}@{} → {}@{}",
             self.active_node_with_status.node.user,
             self.active_node_with_status.node.host,
-            switch_command
+            self.standby_node_with_status.node.user,
+            self.standby_node_with_status.node.host
         );
 
-        if !dry_run {
-            let spinner =
-                ConditionalSpinner::new("Switching active validator to unfunded identity...");
+        let start_time = Instant::now();
+
+        // Execute the streaming transfer using base64 encoding
+        let file_size = if !dry_run {
+            let spinner = ConditionalSpinner::new("Reading tower file...");
+            let ssh_key_active =
+                self.get_ssh_key_for_node(&self.active_node_with_status.node.host)?;
+            let source_digest = match self
+                .remote_file_digest(
+                    &self.active_node_with_status.node,
+                    &ssh_key_active,
+                    &tower_path,
+                )
+                .await
             {
-                let ssh_key = self.get_ssh_key_for_node(&self.active_node_with_status.node.host)?;
+                Ok(digest) => digest,
+                Err(e) => {
+                    spinner.stop_with_message(&format!("❌ Failed to hash tower file: {}", e));
+                    return Err(anyhow!("Failed to hash tower file on active node: {}", e));
+                }
+            };
+            let data = {
                 let pool = self.ssh_pool.clone();
-
-                // Execute the switch command based on validator type
-                if process_info.contains("fdctl") || process_info.contains("firedancer") {
-                    // Firedancer: fdctl set-identity --config <config> <identity>
-                    let fdctl_path = crate::executable_utils::extract_and_save_fdctl_path(
-                        &mut self.active_node_with_status,
-                        &process_info,
-                    )?;
-                    let config_path = crate::executable_utils::extract_firedancer_config_path(&process_info)?;
-
-                    let args = vec![
-                        "set-identity",
-                        "--config",
-                        &config_path,
-                        &self.active_node_with_status.node.paths.unfunded_identity,
-                    ];
-
-                    pool.execute_command_with_args(
+                let base64_args = vec![tower_path.as_str()];
+                match pool
+                    .execute_command_with_args(
                         &self.active_node_with_status.node,
-                        &ssh_key,
-                        &fdctl_path,
-                        &args,
+                        &ssh_key_active,
+                        "base64",
+                        &base64_args,
                     )
-                    .await?;
-                } else if process_info.contains("agave-validator") {
-                    // Agave: agave-validator -l <ledger> set-identity <identity>
-                    let agave_path = self
-                        .active_node_with_status
-                        .agave_validator_executable
-                        .as_ref()
-                        .unwrap();
-                    let ledger_path = self.active_node_with_status.ledger_path.as_ref().unwrap();
+                    .await
+                {
+                    Ok(data) => data,
+                    Err(e) => {
+                        spinner.stop_with_message(&format!("❌ Failed to read tower file: {}", e));
+                        return Err(anyhow!("Failed to read tower file: {}", e));
+                    }
+                }
+            };
+
+            // Make sure what we read is exactly what is on disk before sending it anywhere
+            let tower_bytes = match decode_base64(&data) {
+                Ok(bytes) => bytes,
+                Err(e) => {
+                    spinner.stop_with_message(&format!("❌ Failed to decode tower file: {}", e));
+                    return Err(e);
+                }
+            };
+            let read_digest = digest_bytes(&tower_bytes);
+            if read_digest != source_digest {
+                spinner.stop_with_message("❌ Tower file changed or was corrupted while reading");
+                return Err(anyhow!(
+                    "Tower integrity check failed on read: active has {} ({} bytes), read {} ({} bytes)",
+                    source_digest.sha256,
+                    source_digest.size,
+                    read_digest.sha256,
+                    read_digest.size
+                ));
+            }
+            spinner.stop_with_message("");
 
-                    let args = vec![
-                        "-l",
-                        ledger_path,
-                        "set-identity",
-                        &self.active_node_with_status.node.paths.unfunded_identity,
-                    ];
+            // Never hand the standby a tower that is behind what the cluster has already seen
+            self.verify_tower_freshness(&tower_bytes).await?;
 
-                    pool.execute_command_with_args(
-                        &self.active_node_with_status.node,
-                        &ssh_key,
-                        agave_path,
-                        &args,
+            let spinner = ConditionalSpinner::new("Transferring tower file...");
+            let ssh_key_standby =
+                self.get_ssh_key_for_node(&self.standby_node_with_status.node.host)?;
+            {
+                let pool = self.ssh_pool.clone();
+                match pool
+                    .transfer_base64_to_file(
+                        &self.standby_node_with_status.node,
+                        &ssh_key_standby,
+                        &temp_path,
+                        &data,
                     )
-                    .await?;
-                } else {
-                    return Err(anyhow!("Unsupported validator type for set-identity"));
+                    .await
+                {
+                    Ok(_) => {}
+                    Err(e) => {
+                        spinner.stop_with_message(&format!("❌ Failed to write tower file: {}", e));
+                        self.remove_standby_temp_file(&temp_path).await;
+                        return Err(anyhow!("Failed to write tower file: {}", e));
+                    }
                 }
             }
-            // No sleep - move immediately to next step!
-            spinner.stop_with_message("✅ Active validator switched to unfunded identity");
+
+            // Verify the written copy before it replaces anything in the ledger
+            let written_digest = self
+                .remote_file_digest(
+                    &self.standby_node_with_status.node,
+                    &ssh_key_standby,
+                    &temp_path,
+                )
+                .await;
+            match written_digest {
+                Ok(digest) if digest == source_digest => {}
+                Ok(digest) => {
+                    spinner.stop_with_message("❌ Tower file mismatch on standby");
+                    self.remove_standby_temp_file(&temp_path).await;
+                    return Err(anyhow!(
+                        "Tower integrity check failed on standby: expected {} ({} bytes), found {} ({} bytes)",
+                        source_digest.sha256,
+                        source_digest.size,
+                        digest.sha256,
+                        digest.size
+                    ));
+                }
+                Err(e) => {
+                    spinner.stop_with_message(&format!("❌ Failed to verify tower file: {}", e));
+                    self.remove_standby_temp_file(&temp_path).await;
+                    return Err(anyhow!("Failed to verify tower file on standby: {}", e));
+                }
+            }
+
+            // Atomically move the verified copy into place
+            let rename_cmd = tower_rename_command(&temp_path, &dest_path);
+            let renamed = self
+                .ssh_pool
+                .execute_command(
+                    &self.standby_node_with_status.node,
+                    &ssh_key_standby,
+                    &rename_cmd,
+                )
+                .await;
+            match renamed {
+                Ok(output) if output.trim() == "renamed" => {}
+                Ok(output) => {
+                    spinner.stop_with_message("❌ Failed to move tower file into place");
+                    self.remove_standby_temp_file(&temp_path).await;
+                    return Err(anyhow!(
+                        "Failed to move tower file into place on standby: {}",
+                        output.trim()
+                    ));
+                }
+                Err(e) => {
+                    spinner.stop_with_message("❌ Failed to move tower file into place");
+                    self.remove_standby_temp_file(&temp_path).await;
+                    return Err(anyhow!(
+                        "Failed to move tower file into place on standby: {}",
+                        e
+                    ));
+                }
+            }
+            spinner.stop_with_message("");
+            source_digest.size
+        } else {
+            // For dry run, nothing is transferred
+            0
+        };
+
+        let transfer_duration = start_time.elapsed();
+        self.tower_transfer_time = Some(transfer_duration);
+
+        // Calculate transfer speed
+        let speed_mbps = (file_size as f64 / 1024.0 / 1024.0) / transfer_duration.as_secs_f64();
+
+        println_if_not_silent!(
+            "  ✅ Transferred in {} ({:.2} MB/s)",
+            format!("{}ms", transfer_duration.as_millis())
+                .bright_green()
+                .bold(),
+            speed_mbps
+        );
+
+        if !dry_run {
+            println_if_not_silent!(
+                "  🔒 Verified {} bytes, sha256 match on both nodes",
+                file_size
+            );
         }
 
         Ok(())
     }
 
-    pub(crate) async fn transfer_tower_file(&mut self, dry_run: bool) -> Result<()> {
-        // Use the derived tower path from active node
-        let tower_path = self
-            .active_node_with_status
-            .tower_path
-            .as_ref()
-            .ok_or_else(|| anyhow!("Tower path not available for active node"))?;
+    /// Decode the tower and compare its last vote with the vote account on-chain.
+    /// A tower that is stale or belongs to another identity fails with `StaleTower`;
+    /// so does one that can't be checked, unless `allow_unverified_tower` is set.
+    async fn verify_tower_freshness(&mut self, tower_bytes: &[u8]) -> Result<()> {
+        let tower = match crate::tower_file::decode_tower(tower_bytes) {
+            Ok(tower) => tower,
+            Err(e) => return self.unverified_tower(format!("tower could not be decoded: {}", e)),
+        };
 
-        // Verify the tower file exists
-        let check_tower_cmd = format!("test -f {} && echo 'exists' || echo 'missing'", tower_path);
-        let tower_exists = {
-            let ssh_key = self.get_ssh_key_for_node(&self.active_node_with_status.node.host)?;
-            let pool = self.ssh_pool.clone();
-            pool.execute_command(
+        let on_chain_last_vote = match crate::solana_rpc::fetch_vote_account_data(
+            &self.validator_pair.rpc,
+            &self.validator_pair.vote_pubkey,
+        )
+        .await
+        {
+            Ok(data) => data.vote_account_info.last_vote,
+            Err(e) => {
+                return self
+                    .unverified_tower(format!("on-chain last vote could not be fetched: {}", e))
+            }
+        };
+
+        println_if_not_silent!(
+            "  🗳️  Tower last vote: slot {} (root {}), on-chain last vote: slot {}",
+            tower
+                .last_voted_slot
+                .map(|slot| slot.to_string())
+                .unwrap_or_else(|| "none".to_string()),
+            tower
+                .root_slot
+                .map(|slot| slot.to_string())
+                .unwrap_or_else(|| "none".to_string()),
+            on_chain_last_vote
+        );
+
+        crate::tower_file::check_tower_freshness(
+            &tower,
+            &self.validator_pair.identity_pubkey,
+            on_chain_last_vote,
+            self.switch_policy.max_tower_lag_slots,
+        )
+        .map_err(|e| StaleTower(e.to_string()).into())
+    }
+
+    /// Tower freshness could not be checked: refuse, or carry on with a journaled
+    /// warning if `switch_policy.allow_unverified_tower` is set
+    fn unverified_tower(&mut self, reason: String) -> Result<()> {
+        if !self.switch_policy.allow_unverified_tower {
+            return Err(StaleTower(format!(
+                "{} (set switch_policy.allow_unverified_tower to activate anyway)",
+                reason
+            ))
+            .into());
+        }
+        println_if_not_silent!(
+            "  {}",
+            format!("⚠️  Tower freshness not verified: {}", reason).yellow()
+        );
+        self.tower_unverified = Some(reason);
+        Ok(())
+    }
+
+    /// Describe this switch as a `SwitchPlan`: nodes, target identities, tower paths
+    /// and the exact remote commands of steps 1-3
+    pub(crate) async fn build_plan(&mut self) -> Result<SwitchPlan> {
+        let active_key = self.get_ssh_key_for_node(&self.active_node_with_status.node.host)?;
+        let standby_key = self.get_ssh_key_for_node(&self.standby_node_with_status.node.host)?;
+
+        let active_processes = self
+            .ssh_pool
+            .execute_command(
                 &self.active_node_with_status.node,
-                &ssh_key,
-                &check_tower_cmd,
+                &active_key,
+                VALIDATOR_PROCESS_QUERY,
             )
-            .await?
+            .await?;
+        let standby_processes = self
+            .ssh_pool
+            .execute_command(
+                &self.standby_node_with_status.node,
+                &standby_key,
+                VALIDATOR_PROCESS_QUERY,
+            )
+            .await?;
+
+        let unfunded = self
+            .active_node_with_status
+            .node
+            .paths
+            .unfunded_identity
+            .clone();
+        let (_, deactivate) = set_identity_command(
+            &mut self.active_node_with_status,
+            &active_processes,
+            &unfunded,
+            false,
+        )?;
+        let funded = self
+            .standby_node_with_status
+            .node
+            .paths
+            .funded_identity
+            .clone();
+        let (_, activate, tower_check) = self.activation_commands(&standby_processes)?;
+        let tower = self.tower_paths()?;
+
+        let plan_node = |node: &crate::types::NodeWithStatus, target: &str| PlanNode {
+            label: node.node.label.clone(),
+            host: node.node.host.clone(),
+            user: node.node.user.clone(),
+            validator_type: format!("{:?}", node.validator_type),
+            current_identity: node.current_identity.clone(),
+            target_identity_keypair: target.to_string(),
         };
+        let active_label = self.active_node_with_status.node.label.clone();
+        let standby_label = self.standby_node_with_status.node.label.clone();
+
+        Ok(SwitchPlan {
+            format_version: PLAN_FORMAT_VERSION,
+            created_at: chrono::Utc::now().to_rfc3339(),
+            created_by: crate::switch_journal::operator_host(),
+            validator_identity: self.validator_pair.identity_pubkey.clone(),
+            vote_pubkey: self.validator_pair.vote_pubkey.clone(),
+            active: plan_node(&self.active_node_with_status, &unfunded),
+            standby: plan_node(&self.standby_node_with_status, &funded),
+            steps: vec![
+                PlanStep {
+                    step: 1,
+                    description: "Switch active node to unfunded identity".to_string(),
+                    node: active_label.clone(),
+                    commands: vec![deactivate.display()],
+                },
+                PlanStep {
+                    step: 2,
+                    description: "Read and hash the tower file".to_string(),
+                    node: active_label,
+                    commands: vec![
+                        digest_command(&tower.source),
+                        format!("base64 {}", shell_quote(&tower.source)),
+                    ],
+                },
+                PlanStep {
+                    step: 2,
+                    description: "Write, verify and move the tower file into place".to_string(),
+                    node: standby_label.clone(),
+                    commands: vec![
+                        "base64 -d".to_string(),
+                        format!("dd of={}", tower.staging),
+                        digest_command(&tower.staging),
+                        tower_rename_command(&tower.staging, &tower.destination),
+                    ],
+                },
+                PlanStep {
+                    step: 3,
+                    description: "Switch standby node to funded identity".to_string(),
+                    node: standby_label,
+                    commands: tower_check
+                        .into_iter()
+                        .chain(std::iter::once(activate.display()))
+                        .collect(),
+                },
+            ],
+            tower,
+        })
+    }
 
-        if tower_exists.trim() != "exists" {
+    /// Rebuild the plan from the live cluster and refuse to go on if it differs
+    /// from the reviewed one
+    async fn verify_plan(&mut self, plan: &SwitchPlan) -> Result<()> {
+        println_if_not_silent!("🔍 Re-validating cluster state against the switch plan...");
+
+        let current = self.build_plan().await?;
+        let mismatches = plan.mismatches(&current);
+        if !mismatches.is_empty() {
+            println_if_not_silent!(
+                "\n{}",
+                "❌ Cluster state no longer matches the switch plan"
+                    .red()
+                    .bold()
+            );
+            for mismatch in &mismatches {
+                println_if_not_silent!("  • {}", mismatch.red());
+            }
             return Err(anyhow!(
-                "Tower file not found on active node: {}",
-                tower_path
+                "Cluster state no longer matches the switch plan ({} difference(s))",
+                mismatches.len()
             ));
         }
 
+        println_if_not_silent!("✅ Cluster state matches the plan");
+        Ok(())
+    }
+
+    /// Where the tower is read from on the active node, staged and finally
+    /// placed on the standby
+    fn tower_paths(&mut self) -> Result<PlanTower> {
+        let tower_path = self
+            .active_node_with_status
+            .tower_path
+            .as_ref()
+            .ok_or_else(|| anyhow!("Tower path not available for active node"))?;
+
         let tower_filename = tower_path.split('/').last().unwrap_or("tower.bin");
         self.tower_file_name = Some(tower_filename.to_string());
 
@@ -788,249 +2431,367 @@ impl SwitchManager {
             .as_ref()
             .ok_or_else(|| anyhow!("Ledger path not detected for standby node"))?;
 
-        let dest_path = format!("{}/{}", standby_ledger_path, tower_filename);
+        Ok(PlanTower {
+            source: tower_path.clone(),
+            // Written next to the final file so the rename stays on one filesystem and is atomic
+            staging: format!("{}/.{}.svs-tmp", standby_ledger_path, tower_filename),
+            destination: format!("{}/{}", standby_ledger_path, tower_filename),
+        })
+    }
+
+    /// Size and SHA-256 of a file on a remote node
+    async fn remote_file_digest(
+        &self,
+        node: &crate::types::NodeConfig,
+        ssh_key: &str,
+        path: &str,
+    ) -> Result<FileDigest> {
+        let output = self
+            .ssh_pool
+            .execute_command(node, ssh_key, &digest_command(path))
+            .await?;
+        parse_digest_output(&output)
+    }
 
-        println_if_not_silent!(
-            "  📤 {}@{} → {}@{}",
-            self.active_node_with_status.node.user,
-            self.active_node_with_status.node.host,
-            self.standby_node_with_status.node.user,
-            self.standby_node_with_status.node.host
+    /// Best-effort cleanup of a partially written tower copy
+    async fn remove_standby_temp_file(&self, temp_path: &str) {
+        if let Ok(ssh_key) = self.get_ssh_key_for_node(&self.standby_node_with_status.node.host) {
+            let _ = self
+                .ssh_pool
+                .execute_command_with_args(
+                    &self.standby_node_with_status.node,
+                    &ssh_key,
+                    "rm",
+                    &["-f", temp_path],
+                )
+                .await;
+        }
+    }
+
+    /// Whether the standby must already hold a tower for the funded identity
+    fn require_tower(&self) -> bool {
+        self.switch_policy.require_tower && self.activated_without_tower.is_none()
+    }
+
+    /// Set-identity command activating the standby, and the tower check to run
+    /// first when a tower is required but the client cannot enforce it itself
+    fn activation_commands(
+        &mut self,
+        process_info: &str,
+    ) -> Result<(&'static str, RemoteCommand, Option<String>)> {
+        let require_tower = self.require_tower();
+        let identity_keypair = self
+            .standby_node_with_status
+            .node
+            .paths
+            .funded_identity
+            .clone();
+        let (subtitle, command) = set_identity_command(
+            &mut self.standby_node_with_status,
+            process_info,
+            &identity_keypair,
+            require_tower,
+        )?;
+
+        let client_checks_tower = crate::validator_backend::backend_for_process(
+            &self.standby_node_with_status.validator_type,
+            process_info,
+        )
+        .is_some_and(|backend| backend.supports_require_tower());
+        let tower_check = if require_tower && !client_checks_tower {
+            Some(tower_check_command(&self.tower_paths()?.destination))
+        } else {
+            None
+        };
+
+        Ok((subtitle, command, tower_check))
+    }
+
+    /// Ask the operator whether to activate the standby although the tower could
+    /// not be copied. Anything but an explicit yes, including silent mode, means
+    /// roll back.
+    fn confirm_activation_without_tower(&self, error: &anyhow::Error) -> bool {
+        if is_silent_mode() {
+            return false;
+        }
+
+        println!(
+            "\n{}",
+            format!("⚠️  Tower transfer failed: {}", error)
+                .bright_yellow()
+                .bold()
+        );
+        println!(
+            "   switch_policy.require_tower is set, so {} will not take the funded identity without a tower.",
+            self.standby_node_with_status.node.label
+        );
+        println!(
+            "   Activating anyway lets it vote without lockouts, which can produce conflicting votes."
+        );
+
+        inquire::Confirm::new(&format!(
+            "Activate {} without a tower? (No rolls back)",
+            self.standby_node_with_status.node.label
+        ))
+        .with_default(false)
+        .prompt()
+        .unwrap_or(false)
+    }
+
+    /// Public key of the old active's unfunded keypair, read from its keypair file
+    pub(crate) async fn read_unfunded_pubkey(&self) -> Option<String> {
+        let node = &self.active_node_with_status.node;
+        let ssh_key = self.get_ssh_key_for_node(&node.host).ok()?;
+        let output = self
+            .ssh_pool
+            .execute_command(
+                node,
+                &ssh_key,
+                &keypair_pubkey_command(&node.paths.unfunded_identity),
+            )
+            .await
+            .ok()?;
+        parse_keypair_pubkey_output(&output).ok()
+    }
+
+    /// Ask the old active over RPC which identity it runs. Without the unfunded pubkey
+    /// any identity other than the funded one counts as confirmed.
+    pub(crate) async fn confirm_active_unfunded(
+        &self,
+        unfunded_pubkey: Option<&str>,
+    ) -> UnfundedCheck {
+        let node = &self.active_node_with_status;
+        let ssh_key = match self.get_ssh_key_for_node(&node.node.host) {
+            Ok(ssh_key) => ssh_key,
+            Err(e) => return UnfundedCheck::Unconfirmed(e.to_string()),
+        };
+        let rpc_port = crate::validator_rpc::get_rpc_port(
+            node.validator_type.clone(),
+            self.active_process_info.as_deref(),
+        );
+
+        let mut result = UnfundedCheck::Unconfirmed("no answer".to_string());
+        for attempt in 0..UNFUNDED_CONFIRMATION_ATTEMPTS {
+            if attempt > 0 {
+                tokio::time::sleep(UNFUNDED_CONFIRMATION_RETRY).await;
+            }
+            result = match crate::validator_rpc::get_identity(
+                &self.ssh_pool,
+                &node.node,
+                &ssh_key,
+                rpc_port,
+            )
+            .await
+            {
+                Ok(reported) => check_reported_identity(
+                    &reported,
+                    &self.validator_pair.identity_pubkey,
+                    unfunded_pubkey,
+                ),
+                Err(e) => UnfundedCheck::Unconfirmed(format!("getIdentity failed: {}", e)),
+            };
+            if result == UnfundedCheck::Confirmed {
+                break;
+            }
+        }
+        result
+    }
+
+    /// Ask the operator whether to activate the standby although the old active could
+    /// not be confirmed on its unfunded identity. Never in silent mode.
+    fn confirm_activation_unconfirmed(&self, error: &anyhow::Error) -> bool {
+        if is_silent_mode() {
+            return false;
+        }
+
+        println!("\n{}", format!("⚠️  {}", error).bright_yellow().bold());
+        println!(
+            "   If {} still votes with the funded identity, activating {} can double-sign.",
+            self.active_node_with_status.node.label, self.standby_node_with_status.node.label
         );
 
-        let start_time = Instant::now();
+        inquire::Confirm::new(&format!(
+            "Activate {} anyway? Only if {} is known to be down (No rolls back)",
+            self.standby_node_with_status.node.label, self.active_node_with_status.node.label
+        ))
+        .with_default(false)
+        .prompt()
+        .unwrap_or(false)
+    }
 
-        // Execute the streaming transfer using base64 encoding
-        let encoded_data = if !dry_run {
-            let spinner = ConditionalSpinner::new("Reading tower file...");
-            let ssh_key_active =
-                self.get_ssh_key_for_node(&self.active_node_with_status.node.host)?;
-            let data = {
-                let pool = self.ssh_pool.clone();
-                let base64_args = vec![tower_path.as_str()];
-                match pool
+    /// Funded keypair for keyless standby mode, base64 encoded. Read from
+    /// `switch_policy.funded_keypair_source` on this machine, or else from the old
+    /// active, and checked against the validator identity before it is sent anywhere.
+    pub(crate) async fn load_funded_keypair(&mut self) -> Result<String> {
+        use base64::Engine;
+
+        if let Some(data) = &self.funded_keypair {
+            return Ok(data.clone());
+        }
+
+        let bytes = match &self.switch_policy.funded_keypair_source {
+            Some(path) => {
+                let expanded_path = match path.strip_prefix("~/") {
+                    Some(rest) => dirs::home_dir()
+                        .ok_or_else(|| anyhow!("Could not find home directory"))?
+                        .join(rest),
+                    None => std::path::PathBuf::from(path),
+                };
+                std::fs::read(&expanded_path)
+                    .map_err(|e| anyhow!("Failed to read funded keypair {}: {}", path, e))?
+            }
+            None => {
+                let node = &self.active_node_with_status.node;
+                let ssh_key = self.get_ssh_key_for_node(&node.host)?;
+                let data = self
+                    .ssh_pool
                     .execute_command_with_args(
-                        &self.active_node_with_status.node,
-                        &ssh_key_active,
+                        node,
+                        &ssh_key,
                         "base64",
-                        &base64_args,
-                    )
-                    .await
-                {
-                    Ok(data) => data,
-                    Err(e) => {
-                        spinner.stop_with_message(&format!("❌ Failed to read tower file: {}", e));
-                        return Err(anyhow!("Failed to read tower file: {}", e));
-                    }
-                }
-            };
-            spinner.stop_with_message("");
-
-            let spinner = ConditionalSpinner::new("Transferring tower file...");
-            let ssh_key_standby =
-                self.get_ssh_key_for_node(&self.standby_node_with_status.node.host)?;
-            {
-                let pool = self.ssh_pool.clone();
-                match pool
-                    .transfer_base64_to_file(
-                        &self.standby_node_with_status.node,
-                        &ssh_key_standby,
-                        &dest_path,
-                        &data,
+                        &[node.paths.funded_identity.as_str()],
                     )
                     .await
-                {
-                    Ok(_) => {}
-                    Err(e) => {
-                        spinner.stop_with_message(&format!("❌ Failed to write tower file: {}", e));
-                        return Err(anyhow!("Failed to write tower file: {}", e));
-                    }
-                }
+                    .map_err(|e| {
+                        anyhow!("Failed to read funded keypair from {}: {}", node.label, e)
+                    })?;
+                let data: String = data.split_whitespace().collect();
+                base64::engine::general_purpose::STANDARD
+                    .decode(data)
+                    .map_err(|e| anyhow!("Funded keypair data is not valid base64: {}", e))?
             }
-            spinner.stop_with_message("");
-            data
-        } else {
-            // For dry run, just use a dummy value
-            String::from("dummy")
         };
 
-        let transfer_duration = start_time.elapsed();
-        self.tower_transfer_time = Some(transfer_duration);
+        let pubkey = keypair_file_pubkey(&bytes)?;
+        if pubkey != self.validator_pair.identity_pubkey {
+            return Err(anyhow!(
+                "Funded keypair is for {}, expected the validator identity {}",
+                pubkey,
+                self.validator_pair.identity_pubkey
+            ));
+        }
 
-        // Calculate transfer speed
-        let file_size = encoded_data.len() as u64 * 3 / 4; // approximate original size from base64
-        let speed_mbps = (file_size as f64 / 1024.0 / 1024.0) / transfer_duration.as_secs_f64();
+        let data = base64::engine::general_purpose::STANDARD.encode(&bytes);
+        self.funded_keypair = Some(data.clone());
+        Ok(data)
+    }
 
-        println_if_not_silent!(
-            "  ✅ Transferred in {} ({:.2} MB/s)",
-            format!("{}ms", transfer_duration.as_millis())
-                .bright_green()
-                .bold(),
-            speed_mbps
-        );
+    /// Write the funded keypair to the standby's `paths.funded_identity`, readable by its owner only
+    async fn push_funded_keypair(&mut self, ssh_key: &str) -> Result<()> {
+        let data = self.load_funded_keypair().await?;
+        let node = &self.standby_node_with_status.node;
+        self.ssh_pool
+            .transfer_base64_to_file_with_mode(
+                node,
+                ssh_key,
+                &node.paths.funded_identity,
+                &data,
+                Some(FUNDED_KEYPAIR_MODE),
+            )
+            .await
+            .map_err(|e| anyhow!("Failed to push funded keypair to {}: {}", node.label, e))
+    }
 
-        if !dry_run {
-            // Verify the file on standby
-            let verify_result = {
-                let ssh_key =
-                    self.get_ssh_key_for_node(&self.standby_node_with_status.node.host)?;
-                let pool = self.ssh_pool.clone();
-                let ls_args = vec!["-la", &dest_path];
-                pool.execute_command_with_args(
-                    &self.standby_node_with_status.node,
-                    &ssh_key,
-                    "ls",
-                    &ls_args,
-                )
-                .await?
-            };
-            if verify_result.trim().is_empty() {
-                return Err(anyhow!("Failed to verify tower file on standby"));
-            }
+    /// Securely delete the funded keypair from `node`
+    async fn remove_funded_keypair(&self, node: &crate::types::NodeConfig) -> Result<()> {
+        let ssh_key = self.get_ssh_key_for_node(&node.host)?;
+        let output = self
+            .ssh_pool
+            .execute_command(
+                node,
+                &ssh_key,
+                &secure_delete_command(&node.paths.funded_identity),
+            )
+            .await?;
+        if !output.contains("removed") {
+            return Err(anyhow!(
+                "{} still exists on {}",
+                node.paths.funded_identity,
+                node.label
+            ));
         }
-
         Ok(())
     }
 
+    /// Keyless standby: delete the funded keypair from the old active once the new one runs it
+    pub(crate) async fn remove_old_active_funded_keypair(&self) -> Result<()> {
+        self.remove_funded_keypair(&self.active_node_with_status.node)
+            .await
+    }
+
+    /// Keyless standby: delete the funded keypair from a standby that failed to take the identity
+    pub(crate) async fn remove_standby_funded_keypair(&self) -> Result<()> {
+        self.remove_funded_keypair(&self.standby_node_with_status.node)
+            .await
+    }
+
     pub(crate) async fn switch_backup_to_funded(&mut self, dry_run: bool) -> Result<()> {
         // Detect validator type to use appropriate command
-        let process_info = {
-            let ssh_key = self.get_ssh_key_for_node(&self.standby_node_with_status.node.host)?;
-            let pool = self.ssh_pool.clone();
-            pool.execute_command(
+        let ssh_key = self.get_ssh_key_for_node(&self.standby_node_with_status.node.host)?;
+        let process_info = self
+            .ssh_pool
+            .execute_command(
                 &self.standby_node_with_status.node,
                 &ssh_key,
-                "ps aux | grep -E 'solana-validator|agave|fdctl|firedancer' | grep -v grep",
-            )
-            .await?
-        };
-
-        let (subtitle, switch_command) = if process_info.contains("fdctl")
-            || process_info.contains("firedancer")
-        {
-            // Use detected fdctl executable path, or extract from running process as fallback
-            let fdctl_path = crate::executable_utils::extract_and_save_fdctl_path(&mut self.standby_node_with_status, &process_info)?;
-
-            // Extract config path from the process info
-            let config_path = crate::executable_utils::extract_firedancer_config_path(&process_info)?;
-
-            (
-                "Using Firedancer fdctl set-identity",
-                format!(
-                    "{} set-identity --config \"{}\" \"{}\"",
-                    fdctl_path,
-                    config_path,
-                    self.standby_node_with_status.node.paths.funded_identity
-                ),
-            )
-        } else if process_info.contains("agave-validator") {
-            // Use detected agave executable path if available
-            let agave_path = self
-                .standby_node_with_status
-                .agave_validator_executable
-                .as_ref()
-                .ok_or_else(|| anyhow!("Agave validator executable path not found"))?;
-
-            // Use detected ledger path if available, otherwise error
-            let ledger_path = self
-                .standby_node_with_status
-                .ledger_path
-                .as_ref()
-                .ok_or_else(|| anyhow!("Ledger path not detected for standby node"))?;
-
-            (
-                "Using Agave validator set-identity",
-                format!(
-                    "{} -l \"{}\" set-identity \"{}\"",
-                    agave_path,
-                    ledger_path,
-                    self.standby_node_with_status.node.paths.funded_identity
-                ),
+                VALIDATOR_PROCESS_QUERY,
             )
-        } else {
-            // Use detected ledger path if available, otherwise error
-            let ledger_path = self
-                .standby_node_with_status
-                .ledger_path
-                .as_ref()
-                .ok_or_else(|| anyhow!("Ledger path not detected for standby node"))?;
+            .await?;
 
-            (
-                "Using Solana validator restart",
-                format!("{} exit && solana-validator --identity {} --vote-account {} --ledger {} --limit-ledger-size 100000000 --log - &", 
-                    "solana-validator",  // Using validator binary directly instead of solana CLI
-                    self.standby_node_with_status.node.paths.funded_identity,
-                    self.standby_node_with_status.node.paths.vote_keypair,
-                    ledger_path)
-            )
-        };
+        let (subtitle, command, tower_check) = self.activation_commands(&process_info)?;
 
         println_if_not_silent!("{}", subtitle.dimmed());
+        if self.switch_policy.keyless_standby {
+            println_if_not_silent!(
+                "push funded keypair → {}@{}:{} (mode {})",
+                self.standby_node_with_status.node.user,
+                self.standby_node_with_status.node.host,
+                self.standby_node_with_status.node.paths.funded_identity,
+                FUNDED_KEYPAIR_MODE
+            );
+        }
+        if let Some(tower_check) = &tower_check {
+            println_if_not_silent!(
+                "ssh {}@{} '{}'",
+                self.standby_node_with_status.node.user,
+                self.standby_node_with_status.node.host,
+                tower_check
+            );
+        }
         println_if_not_silent!(
             "ssh {}@{} '{}'",
             self.standby_node_with_status.node.user,
             self.standby_node_with_status.node.host,
-            switch_command
+            command.display()
         );
 
         if !dry_run {
-            let spinner =
-                ConditionalSpinner::new("Switching standby validator to funded identity...");
-            {
-                let ssh_key =
-                    self.get_ssh_key_for_node(&self.standby_node_with_status.node.host)?;
-                let pool = self.ssh_pool.clone();
-
-                // Execute the switch command based on validator type
-                if process_info.contains("fdctl") || process_info.contains("firedancer") {
-                    // Firedancer: fdctl set-identity --config <config> <identity>
-                    let fdctl_path = crate::executable_utils::extract_and_save_fdctl_path(
-                        &mut self.standby_node_with_status,
-                        &process_info,
-                    )?;
-                    let config_path = crate::executable_utils::extract_firedancer_config_path(&process_info)?;
-
-                    let args = vec![
-                        "set-identity",
-                        "--config",
-                        &config_path,
-                        &self.standby_node_with_status.node.paths.funded_identity,
-                    ];
-
-                    pool.execute_command_with_args(
-                        &self.standby_node_with_status.node,
-                        &ssh_key,
-                        &fdctl_path,
-                        &args,
-                    )
-                    .await?;
-                } else if process_info.contains("agave-validator") {
-                    // Agave: agave-validator -l <ledger> set-identity --require-tower <identity>
-                    let agave_path = self
-                        .standby_node_with_status
-                        .agave_validator_executable
-                        .as_ref()
-                        .unwrap();
-                    let ledger_path = self.standby_node_with_status.ledger_path.as_ref().unwrap();
-
-                    let args = vec![
-                        "-l",
-                        ledger_path,
-                        "set-identity",
-                        &self.standby_node_with_status.node.paths.funded_identity,
-                    ];
-
-                    pool.execute_command_with_args(
-                        &self.standby_node_with_status.node,
-                        &ssh_key,
-                        agave_path,
-                        &args,
-                    )
+            if let Some(tower_check) = &tower_check {
+                let output = self
+                    .ssh_pool
+                    .execute_command(&self.standby_node_with_status.node, &ssh_key, tower_check)
                     .await?;
-                } else {
-                    return Err(anyhow!("Unsupported validator type for set-identity"));
+                if !output.contains("present") {
+                    return Err(anyhow!(
+                        "No tower file on {} for the funded identity; refusing to activate (switch_policy.require_tower)",
+                        self.standby_node_with_status.node.label
+                    ));
                 }
             }
+
+            // Keyless standby: the key only reaches the standby right before set-identity
+            if self.switch_policy.keyless_standby {
+                self.push_funded_keypair(&ssh_key).await?;
+            }
+
+            let spinner =
+                ConditionalSpinner::new("Switching standby validator to funded identity...");
+            command
+                .run(
+                    &self.ssh_pool,
+                    &self.standby_node_with_status.node,
+                    &ssh_key,
+                )
+                .await?;
             // No sleep - switch is complete!
             spinner.stop_with_message("✅ Standby validator switched to funded identity");
         }
@@ -1087,6 +2848,300 @@ impl SwitchManager {
         Ok(())
     }
 
+    /// Count down to a scheduled switch, keeping both SSH connections warm and
+    /// re-checking standby readiness shortly before the deadline.
+    /// Returns `Ok(false)` if the operator pressed Ctrl-C.
+    async fn wait_for_scheduled_start(
+        &self,
+        schedule: &crate::switch_schedule::SwitchSchedule,
+    ) -> Result<bool> {
+        use crate::switch_schedule::{fetch_next_epoch_start, format_countdown, SwitchSchedule};
+
+        // For the epoch boundary, (current slot, first slot of the next epoch)
+        let mut epoch_slots = match schedule {
+            SwitchSchedule::EpochBoundary => {
+                Some(fetch_next_epoch_start(&self.validator_pair.rpc).await?)
+            }
+            SwitchSchedule::At(_) => None,
+        };
+
+        println_if_not_silent!(
+            "\n{}",
+            format!("🗓️  Switch scheduled for {}", schedule.describe())
+                .bright_cyan()
+                .bold()
+        );
+        if let Some((_, boundary)) = epoch_slots {
+            println_if_not_silent!("   Epoch boundary at slot {}", boundary);
+        }
+        println_if_not_silent!("   {}", "Press Ctrl-C to abort".dimmed());
+
+        let active_key = self.get_ssh_key_for_node(&self.active_node_with_status.node.host)?;
+        let standby_key = self.get_ssh_key_for_node(&self.standby_node_with_status.node.host)?;
+
+        let ctrl_c = tokio::signal::ctrl_c();
+        tokio::pin!(ctrl_c);
+
+        let mut last_keepalive = Instant::now();
+        let mut last_slot_poll = Instant::now();
+        let mut readiness_checked = false;
+
+        loop {
+            let remaining = match (schedule, epoch_slots.as_mut()) {
+                (SwitchSchedule::At(time), _) => (*time - chrono::Utc::now())
+                    .to_std()
+                    .unwrap_or(Duration::ZERO),
+                (SwitchSchedule::EpochBoundary, Some((current_slot, boundary))) => {
+                    let estimate = |slot: u64, since_poll: Duration| {
+                        crate::leader_window::slots_to_duration(boundary.saturating_sub(slot))
+                            .saturating_sub(since_poll)
+                    };
+                    if last_slot_poll.elapsed() >= SCHEDULE_SLOT_POLL_INTERVAL
+                        || estimate(*current_slot, last_slot_poll.elapsed()).is_zero()
+                    {
+                        match crate::leader_window::fetch_current_slot(&self.validator_pair.rpc)
+                            .await
+                        {
+                            Ok(slot) => *current_slot = slot,
+                            Err(e) => println_if_not_silent!(
+                                "\n{}",
+                                format!("⚠️  Failed to fetch current slot: {}", e).yellow()
+                            ),
+                        }
+                        last_slot_poll = Instant::now();
+                    }
+                    if *current_slot >= *boundary {
+                        Duration::ZERO
+                    } else {
+                        // Between polls the countdown runs on the estimated slot time
+                        estimate(*current_slot, last_slot_poll.elapsed())
+                            .max(crate::leader_window::SLOT_DURATION)
+                    }
+                }
+                (SwitchSchedule::EpochBoundary, None) => Duration::ZERO,
+            };
+
+            if remaining.is_zero() {
+                if !is_silent_mode() {
+                    println!("\r⏳ Switching now{}", " ".repeat(30));
+                }
+                return Ok(true);
+            }
+
+            if !is_silent_mode() {
+                print!("\r⏳ Switch in {}   ", format_countdown(remaining));
+                let _ = std::io::stdout().flush();
+            }
+
+            // Keep the pooled connections alive so the switch starts without a handshake
+            if last_keepalive.elapsed() >= SCHEDULE_KEEPALIVE_INTERVAL {
+                let _ = self
+                    .ssh_pool
+                    .execute_command(&self.active_node_with_status.node, &active_key, "true")
+                    .await;
+                let _ = self
+                    .ssh_pool
+                    .execute_command(&self.standby_node_with_status.node, &standby_key, "true")
+                    .await;
+                last_keepalive = Instant::now();
+            }
+
+            if !readiness_checked && remaining <= SCHEDULE_READINESS_RECHECK {
+                readiness_checked = true;
+                println_if_not_silent!("\n🔍 Re-checking target node swap readiness...");
+                let (is_ready, issues) = crate::startup::check_node_swap_readiness(
+                    &self.ssh_pool,
+                    &self.standby_node_with_status.node,
+                    &standby_key,
+                    self.standby_node_with_status.ledger_path.as_ref(),
+                    Some(true),
+                    !self.switch_policy.keyless_standby,
+                )
+                .await;
+                if !is_ready {
+                    return Err(anyhow!(
+                        "Target node {} is no longer swap-ready: {}",
+                        self.standby_node_with_status.node.label,
+                        issues.join(", ")
+                    ));
+                }
+                println_if_not_silent!("✅ Target node still swap-ready");
+            }
+
+            tokio::select! {
+                _ = tokio::time::sleep(remaining.min(Duration::from_secs(1))) => {}
+                _ = &mut ctrl_c => {
+                    println_if_not_silent!();
+                    return Ok(false);
+                }
+            }
+        }
+    }
+
+    /// Wait until a run of `switch_policy.leader_window_slots` slots without our leader
+    /// slots begins, so the switch does not cost blocks. In dry run only the time until
+    /// that window is shown. RPC problems are reported and never block the switch.
+    pub(crate) async fn wait_for_safe_leader_window(
+        &self,
+        dry_run: bool,
+        max_wait: Option<Duration>,
+    ) {
+        use crate::leader_window::{next_leader_slot, slots_to_duration, slots_until_safe_window};
+
+        let window_slots = self.switch_policy.leader_window_slots;
+        let schedule = match crate::leader_window::fetch_leader_schedule(
+            &self.validator_pair.rpc,
+            &self.validator_pair.identity_pubkey,
+        )
+        .await
+        {
+            Ok(schedule) => schedule,
+            Err(e) => {
+                println_if_not_silent!(
+                    "{}",
+                    format!(
+                        "⚠️  Could not fetch leader schedule ({}), not waiting for a safe window",
+                        e
+                    )
+                    .yellow()
+                );
+                return;
+            }
+        };
+
+        let wait_slots =
+            slots_until_safe_window(schedule.current_slot, &schedule.leader_slots, window_slots);
+
+        if dry_run {
+            if wait_slots == 0 {
+                println_if_not_silent!(
+                    "⏱️  Leader schedule: safe window open now (no leader slots in the next {} slots)",
+                    window_slots
+                );
+            } else {
+                println_if_not_silent!(
+                    "⏱️  Leader schedule: next safe window in {} slots (~{}s)",
+                    wait_slots,
+                    slots_to_duration(wait_slots).as_secs()
+                );
+            }
+            return;
+        }
+
+        if wait_slots > 0 {
+            println_if_not_silent!(
+                "⏱️  Waiting ~{}s for a window without our leader slots (use --no-wait to skip)",
+                slots_to_duration(wait_slots).as_secs()
+            );
+        }
+
+        let spinner = ConditionalSpinner::new("Waiting for safe leader window...");
+        let start = Instant::now();
+        let mut current_slot = schedule.current_slot;
+        loop {
+            let wait_slots =
+                slots_until_safe_window(current_slot, &schedule.leader_slots, window_slots);
+            if wait_slots == 0 {
+                let next = next_leader_slot(current_slot, &schedule.leader_slots)
+                    .map(|slot| format!("next leader slot in {} slots", slot - current_slot))
+                    .unwrap_or_else(|| "no upcoming leader slots".to_string());
+                spinner.stop_with_message(&format!("✅ Safe leader window ({})", next));
+                return;
+            }
+
+            if let Some(max_wait) = max_wait {
+                if start.elapsed() >= max_wait {
+                    spinner.stop_with_message(&format!(
+                        "⚠️  No safe leader window within {}s, switching now",
+                        max_wait.as_secs()
+                    ));
+                    return;
+                }
+            }
+
+            tokio::time::sleep(slots_to_duration(wait_slots).min(Duration::from_secs(2))).await;
+
+            current_slot =
+                match crate::leader_window::fetch_current_slot(&self.validator_pair.rpc).await {
+                    Ok(slot) => slot,
+                    Err(e) => {
+                        spinner.stop_with_message(&format!(
+                            "⚠️  Lost track of the current slot ({}), switching now",
+                            e
+                        ));
+                        return;
+                    }
+                };
+        }
+    }
+
+    /// Poll the vote account until a vote made under the funded identity lands
+    /// on-chain after activation. A health check alone doesn't prove the new
+    /// active node is voting, so this measures time to first landed vote.
+    async fn confirm_first_landed_vote(&mut self, dry_run: bool) {
+        let timeout = Duration::from_secs(self.switch_policy.vote_confirmation_timeout_seconds);
+
+        println_if_not_silent!(
+            "Waiting for first landed vote on-chain (timeout {}s)...",
+            timeout.as_secs()
+        );
+
+        if dry_run {
+            return;
+        }
+
+        let spinner = ConditionalSpinner::new("Waiting for vote account to advance...");
+        // Both nodes vote as the funded identity, so only a vote past the last one
+        // seen before activation and the slot set-identity returned at proves the new
+        // active is voting. A resumed switch has neither and uses the current slot.
+        let activated_at = self.activated_at.unwrap_or_else(Instant::now);
+        let activation_slot = match self.pre_activation_vote.max(self.set_identity_slot) {
+            Some(slot) => Ok(slot),
+            None => crate::leader_window::fetch_current_slot(&self.validator_pair.rpc)
+                .await
+                .map_err(|e| format!("no activation slot: {}", e)),
+        };
+
+        let rpc = self.validator_pair.rpc.clone();
+        let vote_pubkey = self.validator_pair.vote_pubkey.clone();
+        let result = match activation_slot {
+            Ok(activation_slot) => {
+                wait_for_vote_past(
+                    &self.validator_pair.identity_pubkey,
+                    activation_slot + VOTE_CONFIRMATION_SLOT_MARGIN,
+                    timeout.saturating_sub(activated_at.elapsed()),
+                    VOTE_CONFIRMATION_POLL_INTERVAL,
+                    || async {
+                        crate::solana_rpc::fetch_vote_account_data(&rpc, &vote_pubkey)
+                            .await
+                            .map(|data| {
+                                let info = data.vote_account_info;
+                                (info.validator_identity, info.last_vote)
+                            })
+                    },
+                )
+                .await
+            }
+            Err(issue) => Err(issue),
+        };
+
+        match result {
+            Ok(_) => {
+                let vote_time = activated_at.elapsed();
+                self.first_vote_time = Some(vote_time);
+                spinner.stop_with_message(&format!(
+                    "✅ First vote landed on-chain {}ms after activation",
+                    vote_time.as_millis()
+                ));
+            }
+            Err(last_issue) => spinner.stop_with_message(&format!(
+                "⚠️  No new vote landed within {}s ({}) - check the new active node",
+                timeout.as_secs(),
+                last_issue
+            )),
+        }
+    }
+
     fn print_summary(&self, dry_run: bool) {
         println_if_not_silent!();
         if dry_run {
@@ -1106,7 +3161,122 @@ impl SwitchManager {
             }
         } else {
             println_if_not_silent!("✅ Validator identity switch completed successfully");
+            match self.first_vote_time {
+                Some(vote_time) => println_if_not_silent!(
+                    "   Time to first landed vote: {}",
+                    format!("{}ms", vote_time.as_millis()).bright_yellow()
+                ),
+                None => println_if_not_silent!(
+                    "   Time to first landed vote: {}",
+                    "not confirmed".yellow()
+                ),
+            }
+            if self.activated_without_tower.is_some() {
+                println_if_not_silent!(
+                    "   {}",
+                    "⚠️  Activated without a tower (operator decision)".yellow()
+                );
+            }
+            if self.tower_unverified.is_some() {
+                println_if_not_silent!(
+                    "   {}",
+                    "⚠️  Tower freshness was not verified - check the new active's lockouts"
+                        .yellow()
+                );
+            }
+            if self.unfunded_unconfirmed.is_some() {
+                println_if_not_silent!(
+                    "   {}",
+                    "⚠️  Old active was not confirmed on its unfunded identity - check it is not voting"
+                        .yellow()
+                );
+            }
+            if self.keypair_cleanup_error.is_some() {
+                println_if_not_silent!(
+                    "   {}",
+                    "⚠️  Funded keypair is still on the old active - delete it manually".yellow()
+                );
+            }
+        }
+    }
+}
+
+/// Drive `wait` to completion while renewing `lock` every third of its TTL, so a
+/// schedule or leader window wait can't outlast it. Fails if the lock is taken over,
+/// or can't be renewed before it expires.
+async fn keep_lock_alive<T>(
+    lock: &mut Option<SwitchLock>,
+    ssh_pool: &crate::ssh::AsyncSshPool,
+    detected_ssh_keys: &std::collections::HashMap<String, String>,
+    ttl_seconds: u64,
+    wait: impl std::future::Future<Output = T>,
+) -> Result<T> {
+    let Some(lock) = lock else {
+        return Ok(wait.await);
+    };
+    let renew_every = Duration::from_secs((ttl_seconds / 3).max(1));
+    let mut next_renewal = renew_every;
+    tokio::pin!(wait);
+    loop {
+        tokio::select! {
+            result = &mut wait => return Ok(result),
+            _ = tokio::time::sleep(next_renewal) => {
+                match lock.extend(ssh_pool, detected_ssh_keys, ttl_seconds).await {
+                    Ok(true) => next_renewal = renew_every,
+                    Ok(false) => return Err(anyhow!("Switch lock was taken over by another svs")),
+                    Err(e) if lock.owner.expires_at
+                        > chrono::Utc::now().timestamp() as u64 + LOCK_RENEW_RETRY.as_secs() =>
+                    {
+                        println_if_not_silent!(
+                            "\n{}",
+                            format!("⚠️  Could not renew the switch lock ({}), retrying", e)
+                                .yellow()
+                        );
+                        next_renewal = LOCK_RENEW_RETRY;
+                    }
+                    Err(e) => {
+                        return Err(anyhow!(
+                            "Could not renew the switch lock before it expired: {}",
+                            e
+                        ))
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Poll the vote account until it reports `identity` with a last vote past
+/// `activation_slot`, returning that slot or the last issue seen once `timeout` runs out
+pub(crate) async fn wait_for_vote_past<F, Fut>(
+    identity: &str,
+    activation_slot: u64,
+    timeout: Duration,
+    poll_interval: Duration,
+    mut fetch_vote: F,
+) -> std::result::Result<u64, String>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<(String, u64)>>,
+{
+    let start = Instant::now();
+    loop {
+        let last_issue = match fetch_vote().await {
+            Ok((vote_identity, _)) if vote_identity != identity => format!(
+                "vote account reports identity {} (expected {})",
+                vote_identity, identity
+            ),
+            Ok((_, last_vote)) if last_vote > activation_slot => return Ok(last_vote),
+            Ok((_, last_vote)) => format!(
+                "last vote at slot {}, activation at slot {}",
+                last_vote, activation_slot
+            ),
+            Err(e) => format!("RPC error: {}", e),
+        };
+        if start.elapsed() + poll_interval > timeout {
+            return Err(last_issue);
         }
+        tokio::time::sleep(poll_interval).await;
     }
 }
 
@@ -193,6 +193,227 @@ mod scenario_tests {
         }
     }
 
+    #[test]
+    fn test_rollback_report_describes_partial_switch() {
+        use crate::commands::error_handler::SwitchError;
+        use crate::commands::switch::RollbackReport;
+
+        // Rollback succeeded - old active is back on its funded identity
+        let report = RollbackReport {
+            failed_step: "Tower transfer".to_string(),
+            error: "Tower file not found".to_string(),
+            standby_status: "Not activated - still on unfunded identity".to_string(),
+            primary_restored: true,
+            rollback_error: None,
+        };
+        let error = report.to_switch_error();
+        assert_eq!(error.exit_code(), 15);
+        let message = error.to_user_message();
+        assert!(message.contains("Rolled back - restored to funded identity"));
+        assert!(message.contains("Tower transfer failed: Tower file not found"));
+
+        // Rollback failed - old active was left unfunded
+        let report = RollbackReport {
+            failed_step: "Standby activation".to_string(),
+            error: "permission denied".to_string(),
+            standby_status: "Identity could not be verified: connection refused".to_string(),
+            primary_restored: false,
+            rollback_error: Some("skipped".to_string()),
+        };
+        match report.to_switch_error() {
+            SwitchError::PartialSwitch {
+                active_status,
+                standby_status,
+            } => {
+                assert!(active_status.contains("rollback failed: skipped"));
+                assert!(standby_status.contains("Identity could not be verified"));
+            }
+            other => panic!("expected PartialSwitch, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_tower_digest_verification() {
+        use crate::commands::switch::{
+            decode_base64, digest_bytes, parse_digest_output, FileDigest,
+        };
+
+        // sha256 of the bytes "tower" and their base64 encoding, wrapped like `base64` output
+        let expected = FileDigest {
+            sha256: "ad2015263f339bff96818238148b790bd02be561472f4ea7db44498caa0b6558".to_string(),
+            size: 5,
+        };
+        let remote = format!("{}  /mnt/ledger/tower-1_9-abc.bin\n5\n", expected.sha256);
+        assert_eq!(parse_digest_output(&remote).unwrap(), expected);
+
+        let read = digest_bytes(&decode_base64("dG93\nZXI=\n").unwrap());
+        assert_eq!(read, expected);
+
+        // A truncated or corrupted read must not match the source
+        assert_ne!(digest_bytes(&decode_base64("dG93").unwrap()), read);
+        assert!(decode_base64("not base64!").is_err());
+        assert!(parse_digest_output("sha256sum: missing file\n").is_err());
+        assert!(parse_digest_output(&format!("{}  f\n", expected.sha256)).is_err());
+    }
+
+    #[test]
+    fn test_old_active_identity_check() {
+        use crate::commands::switch::{
+            check_reported_identity, parse_keypair_pubkey_output, UnfundedCheck,
+        };
+
+        // Public half of a keypair file: 32 comma-separated bytes
+        let bytes = vec!["1"; 32].join(",");
+        let unfunded = parse_keypair_pubkey_output(&format!("{}\n", bytes)).unwrap();
+        assert_eq!(unfunded, "4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi");
+        assert!(parse_keypair_pubkey_output("1,2,3").is_err());
+        assert!(parse_keypair_pubkey_output("").is_err());
+
+        let funded = "Funded1111111111111111111111111111111111111";
+        assert_eq!(
+            check_reported_identity(&unfunded, funded, Some(&unfunded)),
+            UnfundedCheck::Confirmed
+        );
+        assert_eq!(
+            check_reported_identity(funded, funded, Some(&unfunded)),
+            UnfundedCheck::StillFunded
+        );
+        // Some third identity is not a confirmation when the unfunded pubkey is known
+        assert!(matches!(
+            check_reported_identity("Other111", funded, Some(&unfunded)),
+            UnfundedCheck::Unconfirmed(_)
+        ));
+        assert_eq!(
+            check_reported_identity("Other111", funded, None),
+            UnfundedCheck::Confirmed
+        );
+    }
+
+    #[tokio::test]
+    async fn test_first_landed_vote_waits_past_activation_slot() {
+        use crate::commands::switch::wait_for_vote_past;
+        use std::collections::VecDeque;
+        use std::sync::Mutex;
+        use std::time::Duration;
+
+        let funded = "Funded1111111111111111111111111111111111111";
+        let poll = Duration::from_millis(1);
+        let vote = |identity: &str, slot: u64| Ok((identity.to_string(), slot));
+        let samples = |votes: Vec<anyhow::Result<(String, u64)>>| Mutex::new(VecDeque::from(votes));
+
+        // The old active's last vote, an RPC error and a vote under another identity
+        // don't count, the first funded vote past the activation slot does
+        let votes = samples(vec![
+            vote(funded, 100),
+            Err(anyhow::anyhow!("connection reset")),
+            vote("Other111", 105),
+            vote(funded, 100),
+            vote(funded, 103),
+            vote(funded, 104),
+        ]);
+        let landed = wait_for_vote_past(funded, 100, Duration::from_secs(5), poll, || async {
+            votes.lock().unwrap().pop_front().unwrap()
+        })
+        .await;
+        assert_eq!(landed, Ok(103));
+        assert_eq!(votes.lock().unwrap().len(), 1);
+
+        let votes = samples(vec![vote(funded, 101)]);
+        let landed = wait_for_vote_past(funded, 100, Duration::from_secs(5), poll, || async {
+            votes.lock().unwrap().pop_front().unwrap()
+        })
+        .await;
+        assert_eq!(landed, Ok(101));
+
+        // Times out with the last issue, after fetching at least once
+        let landed = wait_for_vote_past(funded, 100, Duration::from_millis(20), poll, || async {
+            vote(funded, 100)
+        })
+        .await;
+        assert_eq!(
+            landed,
+            Err("last vote at slot 100, activation at slot 100".to_string())
+        );
+        let landed = wait_for_vote_past(funded, 100, Duration::from_millis(20), poll, || async {
+            vote("Other111", 200)
+        })
+        .await;
+        assert_eq!(
+            landed,
+            Err(format!(
+                "vote account reports identity Other111 (expected {})",
+                funded
+            ))
+        );
+        let landed = wait_for_vote_past(funded, 100, Duration::ZERO, poll, || async {
+            Err(anyhow::anyhow!("timed out"))
+        })
+        .await;
+        assert_eq!(landed, Err("RPC error: timed out".to_string()));
+    }
+
+    #[test]
+    fn test_keyless_funded_keypair_check() {
+        use crate::commands::switch::keypair_file_pubkey;
+
+        // Secret half zeros, public half all ones
+        let mut bytes = vec![0u8; 32];
+        bytes.extend(vec![1u8; 32]);
+        let keypair_file = serde_json::to_vec(&bytes).unwrap();
+        assert_eq!(
+            keypair_file_pubkey(&keypair_file).unwrap(),
+            "4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi"
+        );
+
+        assert!(keypair_file_pubkey(b"[1,2,3]").is_err());
+        assert!(keypair_file_pubkey(b"not a keypair").is_err());
+    }
+
+    #[test]
+    fn test_step_policies_and_transient_errors() {
+        use crate::commands::switch::{is_transient_error, SwitchStep};
+        use crate::types::SwitchPolicy;
+        use std::time::Duration;
+
+        let policy: SwitchPolicy = serde_yaml::from_str(
+            "steps:\n  demote_primary:\n    timeout_seconds: 5\n    retries: 2\n    requirement: best_effort\n  verify:\n    requirement: required\n",
+        )
+        .unwrap();
+        let demote = SwitchStep::DemotePrimary.policy(&policy.steps);
+        assert_eq!(demote.timeout(None), Some(Duration::from_secs(5)));
+        assert!(!demote.is_required(true));
+        assert_eq!(demote.backoff(1), Duration::from_millis(500));
+        assert_eq!(demote.backoff(2), Duration::from_millis(1000));
+        assert!(SwitchStep::Verify.policy(&policy.steps).is_required(false));
+
+        // Unset values keep the caller's built-in behaviour
+        let tower = SwitchStep::TransferTower.policy(&policy.steps);
+        assert_eq!(
+            tower.timeout(Some(Duration::from_secs(10))),
+            Some(Duration::from_secs(10))
+        );
+        assert_eq!(tower.timeout(None), None);
+        assert!(!tower.is_required(false));
+        assert_eq!(tower.retries, 0);
+
+        assert!(is_transient_error(&anyhow::anyhow!(
+            "Failed to connect to solana@10.0.0.1: Connection refused"
+        )));
+        assert!(is_transient_error(&anyhow::anyhow!(
+            "Tower transfer timed out after 10s"
+        )));
+        assert!(!is_transient_error(&anyhow::anyhow!(
+            "Command failed: fdctl: unknown identity"
+        )));
+        // A stale or unverifiable tower is never retried, even after an RPC error
+        let stale: anyhow::Error = crate::tower_file::StaleTower(
+            "on-chain last vote could not be fetched: connection refused".to_string(),
+        )
+        .into();
+        assert!(stale.is::<crate::tower_file::StaleTower>());
+        assert!(!is_transient_error(&stale));
+    }
+
     #[test]
     fn test_progress_spinner_lifecycle() {
         use crate::commands::error_handler::ProgressSpinner;
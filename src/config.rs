@@ -67,6 +67,7 @@ impl ConfigManager {
             version: "1.0.0".to_string(),
             validators: Vec::new(),
             alert_config: None,
+            switch_policy: None,
         }
     }
 }
@@ -4,48 +4,147 @@ use std::time::{Duration, Instant};
 use tokio::time::timeout;
 
 use crate::alert::AlertManager;
-use crate::commands::switch::SwitchManager;
+use crate::commands::switch::{SwitchManager, SwitchStep, UnfundedCheck};
 use crate::ssh::AsyncSshPool;
-use crate::types::{NodeWithStatus, ValidatorPair};
+use crate::switch_hooks::{hooks_for, run_hooks, HookContext};
+use crate::switch_journal::{duration_ms, JournalNode, SwitchRecord, SwitchTrigger};
+use crate::switch_lock::SwitchLock;
+use crate::tower_file::StaleTower;
+use crate::types::{HookEvent, NodeConfig, NodeWithStatus, SwitchPolicy, ValidatorPair};
+
+/// Timeout of the best-effort steps (primary demotion, tower copy) unless
+/// `switch_policy.steps` sets one
+const BEST_EFFORT_STEP_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Limit on the standby readiness checks, which run concurrently
+const READINESS_TIMEOUT: Duration = Duration::from_secs(10);
 
 pub struct EmergencyFailover {
     active_node: NodeWithStatus,
+    /// Standby nodes to try, in priority order
+    standby_candidates: Vec<NodeWithStatus>,
+    /// Candidate currently being (or last) activated
     standby_node: NodeWithStatus,
     validator_pair: ValidatorPair,
     ssh_pool: Arc<AsyncSshPool>,
     detected_ssh_keys: std::collections::HashMap<String, String>,
     alert_manager: AlertManager,
+    switch_policy: SwitchPolicy,
     // Track results
     primary_switch_success: bool,
     tower_copy_success: bool,
     standby_switch_success: bool,
     total_time: Option<Duration>,
+    journal: SwitchRecord,
+    /// Auto-failover policy checks that led to the takeover
+    decision: Vec<String>,
 }
 
 impl EmergencyFailover {
+    /// `standby_candidates` are tried in order until one takes the funded identity; it must not be empty
     pub fn new(
         active_node: NodeWithStatus,
-        standby_node: NodeWithStatus,
+        standby_candidates: Vec<NodeWithStatus>,
         validator_pair: ValidatorPair,
         ssh_pool: Arc<AsyncSshPool>,
         detected_ssh_keys: std::collections::HashMap<String, String>,
         alert_manager: AlertManager,
+        switch_policy: SwitchPolicy,
     ) -> Self {
+        let standby_node = standby_candidates[0].clone();
+        let journal = SwitchRecord::new(
+            SwitchTrigger::AutoFailover,
+            false,
+            &validator_pair,
+            &active_node,
+            &standby_node,
+        );
+
         Self {
             active_node,
+            standby_candidates,
             standby_node,
             validator_pair,
             ssh_pool,
             detected_ssh_keys,
             alert_manager,
+            switch_policy,
             primary_switch_success: false,
             tower_copy_success: false,
             standby_switch_success: false,
             total_time: None,
+            journal,
+            decision: Vec::new(),
         }
     }
 
+    /// Include the auto-failover policy checks in the takeover alerts
+    pub fn with_decision(mut self, decision: Vec<String>) -> Self {
+        self.decision = decision;
+        self
+    }
+
     pub async fn execute_emergency_takeover(&mut self) -> Result<()> {
+        // Another svs switching this validator may be the very reason it stopped voting.
+        // The primary is left out, it is most likely unreachable.
+        let candidates: Vec<&NodeConfig> =
+            self.standby_candidates.iter().map(|n| &n.node).collect();
+        let lock = match timeout(
+            Duration::from_secs(10),
+            SwitchLock::acquire(
+                &self.ssh_pool,
+                &candidates,
+                &self.detected_ssh_keys,
+                &self.validator_pair.identity_pubkey,
+                "auto-failover",
+                self.switch_policy.lock_ttl_seconds,
+                false,
+                true,
+            ),
+        )
+        .await
+        {
+            Ok(Ok(lock)) => Some(lock),
+            Ok(Err(e)) => {
+                eprintln!("❌ EMERGENCY TAKEOVER ABORTED: {}", e);
+                self.journal.errors.push(format!("Switch lock: {}", e));
+                self.record_journal();
+                let _ = self
+                    .alert_manager
+                    .send_emergency_takeover_alert(
+                        &self.validator_pair.identity_pubkey,
+                        &self.active_node.node.label,
+                        &self.standby_node.node.label,
+                        false,
+                        false,
+                        false,
+                        Duration::ZERO,
+                        Some(&format!(
+                            "Aborted, another svs holds the switch lock: {}",
+                            e
+                        )),
+                        &self.decision,
+                    )
+                    .await;
+                return Err(anyhow!("Emergency takeover aborted: {}", e));
+            }
+            Err(_) => {
+                eprintln!("⚠️  Taking the switch lock timed out, continuing without it");
+                self.journal
+                    .errors
+                    .push("Switch lock timed out, failover ran without it".to_string());
+                None
+            }
+        };
+
+        let result = self.run_emergency_takeover().await;
+        if let Some(lock) = lock {
+            lock.release(&self.ssh_pool, &self.detected_ssh_keys).await;
+        }
+        result
+    }
+
+    async fn run_emergency_takeover(&mut self) -> Result<()> {
         let start_time = Instant::now();
 
         // Log the emergency takeover
@@ -63,88 +162,389 @@ impl EmergencyFailover {
             self.ssh_pool.clone(),
             self.detected_ssh_keys.clone(),
         );
+        switch_manager.switch_policy = self.switch_policy.clone();
+
+        // Opt-in: hold off briefly if we are about to produce blocks
+        if self.switch_policy.emergency_leader_window {
+            eprintln!("⏱️  Checking leader schedule...");
+            switch_manager
+                .wait_for_safe_leader_window(
+                    false,
+                    Some(Duration::from_secs(
+                        self.switch_policy.emergency_leader_wait_max_seconds,
+                    )),
+                )
+                .await;
+        }
+
+        // Operator hooks may veto the takeover before anything changes
+        if let Some(veto) = self.run_hooks(HookEvent::PreSwitch, None).await {
+            eprintln!("   ❌ {}", veto);
+            self.finish_failed_takeover(start_time, &veto.to_string())
+                .await;
+            return Err(anyhow!("Emergency takeover failed: {}", veto));
+        }
+
+        // Keyless standby: load the funded keypair once for every candidate, before
+        // anything changes, since no standby can be promoted without it
+        if self.switch_policy.keyless_standby {
+            let loaded = match timeout(
+                BEST_EFFORT_STEP_TIMEOUT,
+                switch_manager.load_funded_keypair(),
+            )
+            .await
+            {
+                Ok(result) => result,
+                Err(_) => Err(anyhow!("timed out")),
+            };
+            if let Err(e) = loaded {
+                let error = format!("funded keypair could not be loaded: {}", e);
+                eprintln!("   ❌ {}, leaving the primary as it is", error);
+                self.finish_failed_takeover(start_time, &error).await;
+                return Err(anyhow!("Emergency takeover failed: {}", error));
+            }
+        }
+
+        // Never hand the funded identity to a node that can't vote. Checked before the
+        // primary is demoted so that no ready standby leaves the primary voting.
+        if self.switch_policy.emergency_readiness_gate {
+            eprintln!("🩺 Checking standby readiness...");
+            let candidates = self.standby_candidates.clone();
+            let results =
+                futures::future::join_all(candidates.iter().map(|c| self.check_standby_ready(c)))
+                    .await;
+            let mut ready = Vec::new();
+            for (candidate, result) in candidates.into_iter().zip(results) {
+                match result {
+                    Ok(()) => ready.push(candidate),
+                    Err(e) => self.journal.errors.push(e.to_string()),
+                }
+            }
+            if ready.is_empty() {
+                eprintln!("   ❌ No standby is ready, leaving the primary as it is");
+                let error = "no standby passed the readiness checks";
+                self.finish_failed_takeover(start_time, error).await;
+                return Err(anyhow!("Emergency takeover failed: {}", error));
+            }
+            self.standby_candidates = ready;
+        }
 
         // Step 1: Try to switch primary to unfunded (optional, best-effort)
         eprintln!("📤 Switching primary to unfunded...");
         std::env::set_var("SVS_SILENT_MODE", "1");
 
-        let primary_result = match timeout(
-            Duration::from_secs(10), // Default 10 second timeout
-            switch_manager.switch_primary_to_unfunded(false),
-        )
-        .await
+        let step_start = Instant::now();
+        let primary_result = match switch_manager
+            .run_step(
+                SwitchStep::DemotePrimary,
+                false,
+                Some(BEST_EFFORT_STEP_TIMEOUT),
+            )
+            .await
         {
-            Ok(Ok(_)) => {
+            Ok(_) => {
                 eprintln!("   ✅ Primary switched to unfunded successfully");
                 Ok(())
             }
-            Ok(Err(e)) => {
+            Err(e) => {
                 eprintln!("   ⚠️  Failed to switch primary: {}", e);
                 Err(e)
             }
-            Err(_) => {
-                eprintln!("   ⚠️  Switch primary timed out");
-                Err(anyhow!("Operation timed out"))
-            }
         };
         self.primary_switch_success = primary_result.is_ok();
+        self.journal.steps.active_to_unfunded_ms = duration_ms(Some(step_start.elapsed()));
+        if let Err(e) = &primary_result {
+            self.journal
+                .errors
+                .push(format!("Primary → Unfunded: {}", e));
+        }
 
-        // Step 2: Try to copy tower file (optional, best-effort)
-        eprintln!("📤 Copying tower file...");
-        let tower_result = match timeout(
-            Duration::from_secs(10), // Default 10 second timeout
-            switch_manager.transfer_tower_file(false),
+        // A standby may not take the funded identity while the primary still reports it
+        eprintln!("🔍 Confirming primary dropped the funded identity...");
+        let unfunded_check = timeout(
+            Duration::from_secs(5),
+            // Any identity other than the funded one is enough here
+            switch_manager.confirm_active_unfunded(None),
         )
         .await
-        {
-            Ok(Ok(_)) => {
-                eprintln!("   ✅ Tower file copied successfully");
-                Ok(())
+        .unwrap_or_else(|_| UnfundedCheck::Unconfirmed("getIdentity timed out".to_string()));
+        let blocked = match unfunded_check {
+            UnfundedCheck::Confirmed => {
+                eprintln!("   ✅ Primary no longer reports the funded identity");
+                None
             }
-            Ok(Err(e)) => {
-                eprintln!("   ⚠️  Failed to copy tower: {}", e);
-                Err(e)
+            UnfundedCheck::StillFunded => {
+                eprintln!("   ❌ Primary still reports the funded identity");
+                // Only fencing makes it safe to take over from a primary that still holds the identity
+                self.fence_primary(false)
+                    .await
+                    .err()
+                    .map(|e| anyhow!("primary still reports the funded identity and {}", e))
             }
-            Err(_) => {
-                eprintln!("   ⚠️  Tower copy timed out");
-                Err(anyhow!("Operation timed out"))
+            UnfundedCheck::Unconfirmed(reason) => {
+                eprintln!("   ⚠️  Could not confirm: {}", reason);
+                self.journal.errors.push(format!(
+                    "Primary not confirmed on unfunded identity: {}",
+                    reason
+                ));
+                if self.switch_policy.fencing.is_some() {
+                    let allow_unfenced = self.switch_policy.proceed_without_fencing
+                        && !self.switch_policy.emergency_require_unfunded_confirmation;
+                    self.fence_primary(allow_unfenced).await.err()
+                } else if self.switch_policy.emergency_require_unfunded_confirmation {
+                    Some(anyhow!(
+                        "could not confirm the primary dropped the funded identity ({}) and switch_policy.emergency_require_unfunded_confirmation is set",
+                        reason
+                    ))
+                } else {
+                    eprintln!("   ⚠️  Continuing without confirmation");
+                    None
+                }
+            }
+        };
+        // Only an operator opt-in makes a failed demotion stop the takeover
+        let blocked = match (&primary_result, blocked) {
+            (Err(e), None) if self.switch_policy.steps.demote_primary.is_required(false) => {
+                Some(anyhow!(
+                    "primary could not be switched to unfunded ({}) and switch_policy.steps.demote_primary is required",
+                    e
+                ))
             }
+            (_, blocked) => blocked,
         };
-        self.tower_copy_success = tower_result.is_ok();
+        if let Some(e) = &blocked {
+            self.journal.errors.push(e.to_string());
+        }
+
+        // Steps 2 and 3 for each standby candidate in priority order, until one is activated
+        let candidates = if blocked.is_some() {
+            Vec::new()
+        } else {
+            self.standby_candidates.clone()
+        };
+        let mut standby_result = Err(blocked.unwrap_or_else(|| anyhow!("No standby candidates")));
+        for (attempt, candidate) in candidates.into_iter().enumerate() {
+            // The switch manager was built for the first configured standby
+            if attempt > 0 || candidate.node.label != self.standby_node.node.label {
+                if attempt > 0 {
+                    eprintln!("↪️  Trying next standby ({})...", candidate.node.label);
+                }
+                let funded_keypair = switch_manager.funded_keypair.take();
+                switch_manager = SwitchManager::new(
+                    self.active_node.clone(),
+                    candidate.clone(),
+                    self.validator_pair.clone(),
+                    self.ssh_pool.clone(),
+                    self.detected_ssh_keys.clone(),
+                );
+                switch_manager.switch_policy = self.switch_policy.clone();
+                switch_manager.funded_keypair = funded_keypair;
+            }
+            self.journal.to_node = JournalNode::from_node(&candidate);
+            self.standby_node = candidate;
+
+            // Step 2: Try to copy tower file (optional, best-effort)
+            eprintln!(
+                "📤 Copying tower file to {}...",
+                self.standby_node.node.label
+            );
+            let step_start = Instant::now();
+            let tower_result = match switch_manager
+                .run_step(
+                    SwitchStep::TransferTower,
+                    false,
+                    Some(BEST_EFFORT_STEP_TIMEOUT),
+                )
+                .await
+            {
+                Ok(_) => {
+                    eprintln!("   ✅ Tower file copied successfully");
+                    Ok(())
+                }
+                Err(e) => {
+                    eprintln!("   ⚠️  Failed to copy tower: {}", e);
+                    Err(e)
+                }
+            };
+            self.tower_copy_success = tower_result.is_ok();
+            self.journal.steps.tower_transfer_ms = duration_ms(Some(step_start.elapsed()));
+            if let Err(e) = &tower_result {
+                self.journal.errors.push(format!(
+                    "Tower copy to {}: {}",
+                    self.standby_node.node.label, e
+                ));
+            }
+            if let Some(reason) = &switch_manager.tower_unverified {
+                self.journal.errors.push(format!(
+                    "Tower freshness on {} not verified (switch_policy.allow_unverified_tower): {}",
+                    self.standby_node.node.label, reason
+                ));
+            }
+            self.run_hooks(HookEvent::AfterTowerTransfer, None).await;
 
-        // Step 3: Switch standby to funded (REQUIRED - must succeed)
-        eprintln!("🚀 Switching standby to funded identity...");
-        match switch_manager.switch_backup_to_funded(false).await {
+            // Step 3: Switch standby to funded (REQUIRED - must succeed)
+            let step_start = Instant::now();
+            let mut promote_attempted = false;
+            standby_result = match &tower_result {
+                // Activating with a stale tower could violate lockouts; no policy overrides that
+                Err(e) if e.is::<StaleTower>() => {
+                    eprintln!("   ❌ {}", e);
+                    Err(anyhow!("{}", e))
+                }
+                // Without a tower only an explicit operator opt-in allows activation
+                Err(_)
+                    if self.switch_policy.require_tower
+                        && !self.switch_policy.emergency_activate_without_tower =>
+                {
+                    eprintln!("   ❌ No tower on standby and switch_policy.require_tower is set");
+                    Err(anyhow!(
+                        "tower copy failed and switch_policy.require_tower is set; activate manually or set switch_policy.emergency_activate_without_tower"
+                    ))
+                }
+                Err(e)
+                    if !self.switch_policy.require_tower
+                        && self.switch_policy.steps.transfer_tower.is_required(false) =>
+                {
+                    eprintln!("   ❌ No tower on standby and switch_policy.steps.transfer_tower is required");
+                    Err(anyhow!(
+                        "tower copy failed ({}) and switch_policy.steps.transfer_tower is required",
+                        e
+                    ))
+                }
+                result => {
+                    if let Err(e) = result {
+                        if self.switch_policy.require_tower {
+                            eprintln!("   ⚠️  Activating without a tower (switch_policy.emergency_activate_without_tower)");
+                            switch_manager.activated_without_tower = Some(e.to_string());
+                            self.journal.errors.push(
+                                "Standby activated without tower (switch_policy.emergency_activate_without_tower)"
+                                    .to_string(),
+                            );
+                        }
+                    }
+                    eprintln!("🚀 Switching standby to funded identity...");
+                    promote_attempted = true;
+                    switch_manager
+                        .run_step(SwitchStep::PromoteStandby, false, None)
+                        .await
+                }
+            };
+            self.journal.steps.standby_to_funded_ms = duration_ms(Some(step_start.elapsed()));
+            let e = match &standby_result {
+                Ok(_) => break,
+                Err(e) => e,
+            };
+            let label = self.standby_node.node.label.clone();
+            eprintln!("   ❌ Failed to switch {} to funded: {}", label, e);
+            self.journal
+                .errors
+                .push(format!("Standby {} → Funded: {}", label, e));
+            if !promote_attempted {
+                continue;
+            }
+
+            // A failed or timed out activation may still have handed the standby the
+            // funded identity; activating another one could then cause double voting
+            let holds_funded = timeout(
+                Duration::from_secs(5),
+                switch_manager.standby_holds_funded_identity(),
+            )
+            .await
+            .unwrap_or_else(|_| Err(anyhow!("getIdentity timed out")));
+            let abort = match holds_funded {
+                Ok(false) => None,
+                Ok(true) => Some(anyhow!(
+                    "standby {} reports the funded identity despite the activation error",
+                    label
+                )),
+                Err(e) => Some(anyhow!(
+                    "identity of standby {} could not be verified after the activation error: {}",
+                    label,
+                    e
+                )),
+            };
+            if let Some(e) = abort {
+                eprintln!("   ❌ {}, not trying other standbys", e);
+                self.journal.errors.push(e.to_string());
+                standby_result = Err(e);
+                break;
+            }
+
+            // Keyless standby: a standby that did not take the identity must not keep the key
+            if self.switch_policy.keyless_standby {
+                let cleanup = match timeout(
+                    Duration::from_secs(5),
+                    switch_manager.remove_standby_funded_keypair(),
+                )
+                .await
+                {
+                    Ok(result) => result,
+                    Err(_) => Err(anyhow!("timed out")),
+                };
+                match cleanup {
+                    Ok(()) => eprintln!("   🔒 Funded keypair removed from {}", label),
+                    Err(e) => {
+                        eprintln!("   ⚠️  Could not remove the funded keypair: {}", e);
+                        self.journal
+                            .errors
+                            .push(format!("Funded keypair not removed from {}: {}", label, e));
+                    }
+                }
+            }
+        }
+        match standby_result {
             Ok(_) => {
                 self.standby_switch_success = true;
                 eprintln!("   ✅ Standby switched to funded identity successfully");
-            }
-            Err(e) => {
-                eprintln!("   ❌ CRITICAL: Failed to switch standby to funded: {}", e);
-                self.total_time = Some(start_time.elapsed());
 
-                // Send failure notification
-                let _ = self
-                    .alert_manager
-                    .send_emergency_takeover_alert(
-                        &self.validator_pair.identity_pubkey,
-                        &self.active_node.node.label,
-                        &self.standby_node.node.label,
-                        self.primary_switch_success,
-                        self.tower_copy_success,
-                        false, // standby switch failed
-                        self.total_time.unwrap(),
-                        Some(&format!("Failed to activate standby: {}", e)),
+                // Keyless standby: best effort, the primary is probably unreachable
+                if self.switch_policy.keyless_standby {
+                    let cleanup = match timeout(
+                        Duration::from_secs(5),
+                        switch_manager.remove_old_active_funded_keypair(),
                     )
-                    .await;
+                    .await
+                    {
+                        Ok(result) => result,
+                        Err(_) => Err(anyhow!("timed out")),
+                    };
+                    match cleanup {
+                        Ok(()) => eprintln!(
+                            "   🔒 Funded keypair removed from {}",
+                            self.active_node.node.label
+                        ),
+                        Err(e) => {
+                            eprintln!("   ⚠️  Could not remove the funded keypair: {}", e);
+                            self.journal.errors.push(format!(
+                                "Funded keypair not removed from {}: {}",
+                                self.active_node.node.label, e
+                            ));
+                        }
+                    }
+                }
+            }
+            Err(e) => {
+                eprintln!(
+                    "   ❌ CRITICAL: No standby could be switched to funded: {}",
+                    e
+                );
+                self.finish_failed_takeover(
+                    start_time,
+                    &format!("Failed to activate standby: {}", e),
+                )
+                .await;
 
                 return Err(anyhow!(
-                    "Emergency takeover failed: could not activate standby node"
+                    "Emergency takeover failed: could not activate any standby node"
                 ));
             }
         }
+        self.run_hooks(HookEvent::AfterActivation, None).await;
 
         self.total_time = Some(start_time.elapsed());
+        self.journal.success = true;
+        self.journal.steps.identity_switch_ms = duration_ms(self.total_time);
+        self.record_journal();
 
         // Send success notification
         let _ = self
@@ -158,6 +558,7 @@ impl EmergencyFailover {
                 self.standby_switch_success,
                 self.total_time.unwrap(),
                 None,
+                &self.decision,
             )
             .await;
 
@@ -185,4 +586,142 @@ impl EmergencyFailover {
 
         Ok(())
     }
+
+    /// Run the configured fencing stage against the primary. Fails if fencing is not
+    /// configured or does not succeed, unless `allow_unfenced` is set.
+    async fn fence_primary(&mut self, allow_unfenced: bool) -> Result<()> {
+        let Some(fencing) = self.switch_policy.fencing.clone() else {
+            return Err(anyhow!("no fencing is configured (switch_policy.fencing)"));
+        };
+
+        eprintln!("🔌 Fencing primary ({:?})...", fencing.method);
+        match crate::fencing::fence_node(&fencing, &self.active_node.node, &self.validator_pair)
+            .await
+        {
+            Ok(description) => {
+                eprintln!("   ✅ Primary fenced: {}", description);
+                self.journal
+                    .errors
+                    .push(format!("Primary fenced: {}", description));
+                Ok(())
+            }
+            Err(e) if allow_unfenced => {
+                eprintln!(
+                    "   ⚠️  Fencing failed ({}), continuing (switch_policy.proceed_without_fencing)",
+                    e
+                );
+                self.journal.errors.push(format!(
+                    "Fencing failed, continued per switch_policy.proceed_without_fencing: {}",
+                    e
+                ));
+                Ok(())
+            }
+            Err(e) => {
+                eprintln!("   ❌ Fencing failed: {}", e);
+                Err(anyhow!("fencing failed: {}", e))
+            }
+        }
+    }
+
+    /// Run the `switch_policy.hooks` of `event` and journal the ones that failed.
+    /// Returns the veto of a failing pre_switch hook.
+    async fn run_hooks(&mut self, event: HookEvent, error: Option<&str>) -> Option<anyhow::Error> {
+        if hooks_for(&self.switch_policy.hooks, event).is_empty() {
+            return None;
+        }
+        eprintln!("🪝 Running {} hooks...", event.as_str());
+        let context = HookContext::new(event, &self.journal, error.map(str::to_string));
+        let failures = run_hooks(
+            &self.switch_policy.hooks,
+            &context,
+            &self.validator_pair,
+            &self.ssh_pool,
+            &self.detected_ssh_keys,
+        )
+        .await;
+        for failure in &failures {
+            eprintln!("   ⚠️  {}", failure.describe());
+            self.journal
+                .errors
+                .push(format!("{} {}", event.as_str(), failure.describe()));
+        }
+        failures
+            .iter()
+            .find(|failure| failure.vetoes)
+            .map(|failure| anyhow!("takeover vetoed by {}", failure.describe()))
+    }
+
+    /// Readiness gate run before step 1; alerts about a standby that fails it
+    async fn check_standby_ready(&self, standby: &NodeWithStatus) -> Result<()> {
+        let label = &standby.node.label;
+        let issues = match self.detected_ssh_keys.get(&standby.node.host) {
+            None => vec!["no SSH key detected".to_string()],
+            Some(ssh_key) => match timeout(
+                READINESS_TIMEOUT,
+                crate::standby_selection::check_standby_readiness(
+                    &self.ssh_pool,
+                    standby,
+                    ssh_key,
+                    &self.validator_pair.rpc,
+                    self.switch_policy.keyless_standby,
+                ),
+            )
+            .await
+            {
+                Ok(readiness) => readiness.issues(
+                    &self.validator_pair.identity_pubkey,
+                    self.switch_policy.emergency_max_slot_lag,
+                ),
+                Err(_) => vec![format!(
+                    "readiness checks timed out after {}s",
+                    READINESS_TIMEOUT.as_secs()
+                )],
+            },
+        };
+        if issues.is_empty() {
+            eprintln!("   ✅ {} is healthy and caught up", label);
+            return Ok(());
+        }
+
+        for issue in &issues {
+            eprintln!("   ❌ {}: {}", label, issue);
+        }
+        let _ = self
+            .alert_manager
+            .send_catchup_failure_alert(&self.validator_pair.identity_pubkey, label, &issues)
+            .await;
+        Err(anyhow!(
+            "standby {} is not ready: {}",
+            label,
+            issues.join(", ")
+        ))
+    }
+
+    /// Journal a takeover that activated no standby, run the on_failure hooks and alert
+    async fn finish_failed_takeover(&mut self, start_time: Instant, error: &str) {
+        self.total_time = Some(start_time.elapsed());
+        self.run_hooks(HookEvent::OnFailure, Some(error)).await;
+        self.record_journal();
+
+        // Send failure notification
+        let _ = self
+            .alert_manager
+            .send_emergency_takeover_alert(
+                &self.validator_pair.identity_pubkey,
+                &self.active_node.node.label,
+                &self.standby_node.node.label,
+                self.primary_switch_success,
+                self.tower_copy_success,
+                false, // standby switch failed
+                self.total_time.unwrap(),
+                Some(error),
+                &self.decision,
+            )
+            .await;
+    }
+
+    fn record_journal(&mut self) {
+        self.journal.steps.total_ms = duration_ms(self.total_time);
+        crate::switch_journal::record_switch(&self.journal);
+    }
 }
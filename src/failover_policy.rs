@@ -0,0 +1,298 @@
+use chrono::{DateTime, Duration, Utc};
+
+use crate::standby_selection::StandbyCandidate;
+use crate::types::AutoFailoverPolicy;
+
+/// What automatic failover does about a delinquent validator
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FailoverVerdict {
+    TakeOver,
+    /// Every check passed, but the policy is in dry-run mode
+    DryRun,
+    Blocked,
+}
+
+/// A verdict and the checks behind it, each prefixed with ✅ or ❌
+#[derive(Debug, Clone)]
+pub struct FailoverDecision {
+    pub verdict: FailoverVerdict,
+    pub reasons: Vec<String>,
+}
+
+impl FailoverDecision {
+    pub fn headline(&self) -> &'static str {
+        match self.verdict {
+            FailoverVerdict::TakeOver => "taking over",
+            FailoverVerdict::DryRun => "dry run, would take over",
+            FailoverVerdict::Blocked => "blocked by the auto-failover policy",
+        }
+    }
+
+    pub fn summary(&self) -> String {
+        format!("{}: {}", self.headline(), self.reasons.join("; "))
+    }
+}
+
+/// What the policy is checked against
+pub struct FailoverSituation<'a> {
+    pub now: DateTime<Utc>,
+    pub seconds_since_vote: u64,
+    pub delinquency_threshold: u64,
+    /// Start times of earlier automatic failovers of this validator
+    pub previous_failovers: &'a [DateTime<Utc>],
+    pub active_label: &'a str,
+    /// Whether the active node reported its identity over SSH at the last check
+    pub active_reachable: bool,
+    /// Best-ranked standby with its slot lag, if the policy needs it
+    pub standby: Option<&'a StandbyCandidate>,
+}
+
+/// Check a delinquent validator against the auto-failover policy
+pub fn decide(policy: &AutoFailoverPolicy, situation: &FailoverSituation) -> FailoverDecision {
+    let mut checks = vec![(
+        true,
+        format!(
+            "no new vote for {}s (threshold {}s)",
+            situation.seconds_since_vote, situation.delinquency_threshold
+        ),
+    )];
+
+    if let Some(max_failovers) = policy.max_failovers {
+        let since = situation.now - Duration::minutes(policy.window_minutes as i64);
+        let recent = situation
+            .previous_failovers
+            .iter()
+            .filter(|started| **started > since)
+            .count();
+        checks.push((
+            recent < max_failovers as usize,
+            format!(
+                "{} of {} failover(s) used in the last {} min",
+                recent, max_failovers, policy.window_minutes
+            ),
+        ));
+    }
+
+    if policy.cooldown_minutes > 0 {
+        if let Some(last) = situation.previous_failovers.iter().max() {
+            let minutes = (situation.now - *last).num_minutes();
+            checks.push((
+                minutes >= policy.cooldown_minutes as i64,
+                format!(
+                    "last failover {} min ago (cooldown {} min)",
+                    minutes, policy.cooldown_minutes
+                ),
+            ));
+        }
+    }
+
+    for window in &policy.blackout_windows {
+        match window.covers(situation.now) {
+            Ok(false) => {}
+            Ok(true) => checks.push((false, format!("blackout window {}", window.describe()))),
+            Err(e) => checks.push((
+                false,
+                format!("blackout window {}: {}", window.describe(), e),
+            )),
+        }
+    }
+
+    if policy.require_standby_healthy {
+        checks.push(match situation.standby {
+            None => (false, "no standby node available".to_string()),
+            Some(standby) => {
+                let caught_up = standby
+                    .slot_lag
+                    .is_some_and(|lag| lag <= policy.max_standby_slot_lag);
+                (
+                    standby.healthy && caught_up,
+                    format!(
+                        "standby {} {} (max lag {} slots)",
+                        standby.label,
+                        standby.describe(),
+                        policy.max_standby_slot_lag
+                    ),
+                )
+            }
+        });
+    }
+
+    if policy.require_active_unreachable {
+        checks.push(if situation.active_reachable {
+            (
+                false,
+                format!("active {} still reachable over SSH", situation.active_label),
+            )
+        } else {
+            (
+                true,
+                format!("active {} unreachable over SSH", situation.active_label),
+            )
+        });
+    }
+
+    let verdict = if checks.iter().any(|(passed, _)| !passed) {
+        FailoverVerdict::Blocked
+    } else if policy.dry_run {
+        FailoverVerdict::DryRun
+    } else {
+        FailoverVerdict::TakeOver
+    };
+    FailoverDecision {
+        verdict,
+        reasons: checks
+            .into_iter()
+            .map(|(passed, reason)| format!("{} {}", if passed { "✅" } else { "❌" }, reason))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::BlackoutWindow;
+    use chrono::TimeZone;
+
+    fn situation<'a>(
+        now: DateTime<Utc>,
+        previous_failovers: &'a [DateTime<Utc>],
+        standby: Option<&'a StandbyCandidate>,
+    ) -> FailoverSituation<'a> {
+        FailoverSituation {
+            now,
+            seconds_since_vote: 45,
+            delinquency_threshold: 30,
+            previous_failovers,
+            active_label: "primary",
+            active_reachable: true,
+            standby,
+        }
+    }
+
+    #[test]
+    fn test_rate_limit_and_cooldown() {
+        // Saturday
+        let now = Utc.with_ymd_and_hms(2026, 10, 17, 12, 0, 0).unwrap();
+        let policy = AutoFailoverPolicy {
+            max_failovers: Some(2),
+            cooldown_minutes: 30,
+            ..Default::default()
+        };
+
+        let decision = decide(&AutoFailoverPolicy::default(), &situation(now, &[], None));
+        assert_eq!(decision.verdict, FailoverVerdict::TakeOver);
+        assert_eq!(
+            decision.reasons,
+            vec!["✅ no new vote for 45s (threshold 30s)"]
+        );
+
+        let earlier = [now - Duration::minutes(90), now - Duration::minutes(40)];
+        let decision = decide(&policy, &situation(now, &earlier, None));
+        assert_eq!(decision.verdict, FailoverVerdict::TakeOver);
+        assert!(decision.reasons[1].contains("1 of 2 failover(s) used in the last 60 min"));
+
+        let earlier = [now - Duration::minutes(50), now - Duration::minutes(10)];
+        let decision = decide(&policy, &situation(now, &earlier, None));
+        assert_eq!(decision.verdict, FailoverVerdict::Blocked);
+        assert!(decision.reasons[1].starts_with("❌ 2 of 2"));
+        assert_eq!(
+            decision.reasons[2],
+            "❌ last failover 10 min ago (cooldown 30 min)"
+        );
+
+        let dry_run = AutoFailoverPolicy {
+            dry_run: true,
+            ..policy
+        };
+        let decision = decide(&dry_run, &situation(now, &[], None));
+        assert_eq!(decision.verdict, FailoverVerdict::DryRun);
+        assert!(decision
+            .summary()
+            .starts_with("dry run, would take over: ✅"));
+    }
+
+    #[test]
+    fn test_blackout_windows() {
+        let window = BlackoutWindow {
+            start: "22:00".to_string(),
+            end: "02:00".to_string(),
+            days: vec!["sat".to_string()],
+        };
+        let at = |day, hour| Utc.with_ymd_and_hms(2026, 10, day, hour, 30, 0).unwrap();
+        // Saturday night into Sunday morning, not Friday night
+        assert!(window.covers(at(17, 23)).unwrap());
+        assert!(window.covers(at(18, 1)).unwrap());
+        assert!(!window.covers(at(18, 2)).unwrap());
+        assert!(!window.covers(at(17, 1)).unwrap());
+        assert!(!window.covers(at(16, 23)).unwrap());
+
+        let policy = AutoFailoverPolicy {
+            blackout_windows: vec![window],
+            ..Default::default()
+        };
+        let decision = decide(&policy, &situation(at(17, 23), &[], None));
+        assert_eq!(decision.verdict, FailoverVerdict::Blocked);
+        assert_eq!(
+            decision.reasons[1],
+            "❌ blackout window 22:00-02:00 UTC (sat)"
+        );
+
+        let invalid = BlackoutWindow {
+            start: "25:00".to_string(),
+            end: "02:00".to_string(),
+            days: Vec::new(),
+        };
+        assert!(invalid.covers(at(17, 23)).is_err());
+    }
+
+    #[test]
+    fn test_standby_and_active_requirements() {
+        let now = Utc.with_ymd_and_hms(2026, 10, 17, 12, 0, 0).unwrap();
+        let policy = AutoFailoverPolicy {
+            require_standby_healthy: true,
+            require_active_unreachable: true,
+            ..Default::default()
+        };
+        let mut standby = StandbyCandidate {
+            index: 1,
+            label: "spare".to_string(),
+            healthy: true,
+            version_matches: true,
+            slot_lag: Some(3),
+            issues: Vec::new(),
+        };
+
+        let decision = decide(&policy, &situation(now, &[], Some(&standby)));
+        assert_eq!(decision.verdict, FailoverVerdict::Blocked);
+        assert!(decision.reasons[1].starts_with("✅ standby spare healthy, 3 slot(s) behind"));
+        assert_eq!(
+            decision.reasons[2],
+            "❌ active primary still reachable over SSH"
+        );
+
+        let unreachable = FailoverSituation {
+            active_reachable: false,
+            ..situation(now, &[], Some(&standby))
+        };
+        assert_eq!(
+            decide(&policy, &unreachable).verdict,
+            FailoverVerdict::TakeOver
+        );
+
+        standby.slot_lag = Some(400);
+        let lagging = FailoverSituation {
+            active_reachable: false,
+            ..situation(now, &[], Some(&standby))
+        };
+        assert_eq!(decide(&policy, &lagging).verdict, FailoverVerdict::Blocked);
+        standby.slot_lag = None;
+        let unknown_lag = FailoverSituation {
+            active_reachable: false,
+            ..situation(now, &[], Some(&standby))
+        };
+        assert_eq!(
+            decide(&policy, &unknown_lag).verdict,
+            FailoverVerdict::Blocked
+        );
+    }
+}
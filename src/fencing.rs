@@ -0,0 +1,179 @@
+use anyhow::{anyhow, Result};
+use std::time::Duration;
+
+use crate::types::{FencingConfig, FencingMethod, NodeConfig, ValidatorPair};
+
+/// Replace the `{host}`, `{label}`, `{identity}` and `{vote}` placeholders
+pub fn expand_placeholders(
+    template: &str,
+    node: &NodeConfig,
+    validator_pair: &ValidatorPair,
+) -> String {
+    template
+        .replace("{host}", &node.host)
+        .replace("{label}", &node.label)
+        .replace("{identity}", &validator_pair.identity_pubkey)
+        .replace("{vote}", &validator_pair.vote_pubkey)
+}
+
+/// Fence `node` with the configured method. Returns a short description of what was done.
+pub async fn fence_node(
+    config: &FencingConfig,
+    node: &NodeConfig,
+    validator_pair: &ValidatorPair,
+) -> Result<String> {
+    let fence = async {
+        match config.method {
+            FencingMethod::Command => run_command(config, node, validator_pair).await,
+            FencingMethod::Http | FencingMethod::Firewall => {
+                call_endpoint(config, node, validator_pair).await
+            }
+        }
+    };
+
+    tokio::time::timeout(Duration::from_secs(config.timeout_seconds), fence)
+        .await
+        .map_err(|_| anyhow!("fencing timed out after {}s", config.timeout_seconds))?
+}
+
+async fn run_command(
+    config: &FencingConfig,
+    node: &NodeConfig,
+    validator_pair: &ValidatorPair,
+) -> Result<String> {
+    let template = config
+        .command
+        .as_deref()
+        .ok_or_else(|| anyhow!("switch_policy.fencing.command is required for method 'command'"))?;
+    let command = expand_placeholders(template, node, validator_pair);
+
+    let output = tokio::process::Command::new("sh")
+        .arg("-c")
+        .arg(&command)
+        .kill_on_drop(true)
+        .output()
+        .await
+        .map_err(|e| anyhow!("failed to run fencing command: {}", e))?;
+
+    if !output.status.success() {
+        return Err(anyhow!(
+            "fencing command exited with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(format!("fencing command succeeded: {}", command))
+}
+
+async fn call_endpoint(
+    config: &FencingConfig,
+    node: &NodeConfig,
+    validator_pair: &ValidatorPair,
+) -> Result<String> {
+    let template = config.url.as_deref().ok_or_else(|| {
+        anyhow!(
+            "switch_policy.fencing.url is required for method {:?}",
+            config.method
+        )
+    })?;
+    let url = expand_placeholders(template, node, validator_pair);
+    let method = reqwest::Method::from_bytes(config.http_method.to_uppercase().as_bytes())
+        .map_err(|e| anyhow!("invalid switch_policy.fencing.http_method: {}", e))?;
+
+    let mut request = reqwest::Client::new().request(method.clone(), &url);
+    for (name, value) in &config.headers {
+        request = request.header(name, expand_placeholders(value, node, validator_pair));
+    }
+    if let Some(body) = &config.body {
+        request = request.body(expand_placeholders(body, node, validator_pair));
+    }
+
+    let response = request.send().await?;
+    if !response.status().is_success() {
+        let status = response.status();
+        let error_text = response.text().await.unwrap_or_default();
+        return Err(anyhow!(
+            "fencing endpoint returned {}: {}",
+            status,
+            error_text
+        ));
+    }
+    Ok(format!("{} {} returned {}", method, url, response.status()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::NodePaths;
+
+    fn primary() -> (NodeConfig, ValidatorPair) {
+        let node = NodeConfig {
+            label: "primary".to_string(),
+            host: "10.0.0.1".to_string(),
+            port: 22,
+            user: "solana".to_string(),
+            paths: NodePaths {
+                funded_identity: "/keys/funded.json".to_string(),
+                unfunded_identity: "/keys/unfunded.json".to_string(),
+                vote_keypair: "/keys/vote.json".to_string(),
+            },
+            ssh_key_path: None,
+        };
+        let validator_pair = ValidatorPair {
+            vote_pubkey: "Vote111".to_string(),
+            identity_pubkey: "Ident111".to_string(),
+            rpc: "http://localhost:8899".to_string(),
+            rpcs: Vec::new(),
+            rpc_quorum: None,
+            nodes: vec![node.clone()],
+        };
+        (node, validator_pair)
+    }
+
+    fn command_config(command: &str) -> FencingConfig {
+        serde_yaml::from_str(&format!(
+            "method: command\ncommand: \"{}\"\ntimeout_seconds: 5",
+            command
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_placeholders_and_defaults() {
+        let (node, validator_pair) = primary();
+        assert_eq!(
+            expand_placeholders(
+                "https://bmc-{label}/{host}?id={identity}&v={vote}",
+                &node,
+                &validator_pair
+            ),
+            "https://bmc-primary/10.0.0.1?id=Ident111&v=Vote111"
+        );
+
+        let config: FencingConfig =
+            serde_yaml::from_str("method: firewall\nurl: https://fw.example/block/{host}").unwrap();
+        assert_eq!(config.method, FencingMethod::Firewall);
+        assert_eq!(config.http_method, "POST");
+        assert_eq!(config.timeout_seconds, 30);
+    }
+
+    #[tokio::test]
+    async fn test_command_fencing_uses_exit_status() {
+        let (node, validator_pair) = primary();
+
+        let ok = fence_node(
+            &command_config("test {host} = 10.0.0.1"),
+            &node,
+            &validator_pair,
+        )
+        .await;
+        assert!(ok.is_ok());
+
+        let failed = fence_node(&command_config("exit 3"), &node, &validator_pair).await;
+        assert!(failed.unwrap_err().to_string().contains("exited"));
+
+        let mut missing = command_config("true");
+        missing.command = None;
+        assert!(fence_node(&missing, &node, &validator_pair).await.is_err());
+    }
+}
@@ -0,0 +1,221 @@
+use std::collections::HashMap;
+
+use crate::alert::{AlertManager, AlertTracker};
+use crate::ssh::AsyncSshPool;
+use crate::types::{NodeStatus, NodeWithStatus};
+
+// Repeat the alert while the double identity persists, but not on every refresh
+const DOUBLE_IDENTITY_ALERT_COOLDOWN_SECONDS: u64 = 300;
+
+/// Two or more nodes of one validator reporting the funded identity
+#[derive(Debug, Clone)]
+pub struct DoubleIdentity {
+    pub holders: Vec<String>,
+    pub kept: String,
+    /// Outcome of setting each other holder to unfunded (empty when fencing is off)
+    pub fence_results: Vec<(String, Result<(), String>)>,
+}
+
+/// Indices of the nodes whose reported identity is the funded one
+pub fn funded_identity_holders(identities: &[Option<String>], funded_identity: &str) -> Vec<usize> {
+    identities
+        .iter()
+        .enumerate()
+        .filter(|(_, identity)| identity.as_deref() == Some(funded_identity))
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
+/// The holder that keeps the funded identity: the node that held it alone on the
+/// previous check, otherwise the node we know as active, otherwise the first one
+pub fn preferred_holder(
+    nodes: &[NodeWithStatus],
+    holders: &[usize],
+    previous_holder: Option<usize>,
+) -> Option<usize> {
+    previous_holder
+        .filter(|idx| holders.contains(idx))
+        .or_else(|| {
+            holders
+                .iter()
+                .copied()
+                .find(|idx| nodes.get(*idx).map(|n| &n.status) == Some(&NodeStatus::Active))
+        })
+        .or_else(|| holders.first().copied())
+}
+
+/// Ask every node for its identity over RPC (`None` for a node that can't be asked)
+pub async fn probe_identities(
+    nodes: &[NodeWithStatus],
+    ssh_pool: &AsyncSshPool,
+    detected_ssh_keys: &HashMap<String, String>,
+) -> Vec<Option<String>> {
+    futures::future::join_all(nodes.iter().map(|n| async move {
+        let ssh_key = detected_ssh_keys.get(&n.node.host)?;
+        let rpc_port = crate::validator_rpc::get_rpc_port(n.validator_type.clone(), None);
+        crate::validator_rpc::get_identity(ssh_pool, &n.node, ssh_key, rpc_port)
+            .await
+            .ok()
+    }))
+    .await
+}
+
+/// Watches that at most one node per validator runs the funded identity
+pub struct IdentityGuard {
+    previous_holders: Vec<Option<usize>>,
+    alert_tracker: AlertTracker,
+    fence: bool,
+}
+
+impl IdentityGuard {
+    /// With `fence` set, every holder but the preferred one is switched to its unfunded
+    /// identity right away
+    pub fn new(validator_count: usize, fence: bool) -> Self {
+        Self {
+            previous_holders: vec![None; validator_count],
+            alert_tracker: AlertTracker::with_cooldown(
+                validator_count,
+                DOUBLE_IDENTITY_ALERT_COOLDOWN_SECONDS,
+            ),
+            fence,
+        }
+    }
+
+    /// Check the identities the nodes of a validator reported (see `probe_identities`)
+    pub async fn check(
+        &mut self,
+        validator_idx: usize,
+        validator_status: &crate::ValidatorStatus,
+        identities: &[Option<String>],
+        ssh_pool: &AsyncSshPool,
+        detected_ssh_keys: &HashMap<String, String>,
+        alert_manager: Option<&AlertManager>,
+    ) -> Option<DoubleIdentity> {
+        let nodes = &validator_status.nodes_with_status;
+        let funded_identity = &validator_status.validator_pair.identity_pubkey;
+        let holders = funded_identity_holders(identities, funded_identity);
+        let previous_holder = self.previous_holders.get(validator_idx).copied().flatten();
+
+        if holders.len() < 2 {
+            if let (Some(slot), [holder]) =
+                (self.previous_holders.get_mut(validator_idx), &holders[..])
+            {
+                *slot = Some(*holder);
+            }
+            self.alert_tracker.reset(validator_idx);
+            return None;
+        }
+
+        let kept = preferred_holder(nodes, &holders, previous_holder)?;
+        let mut fence_results = Vec::new();
+        if self.fence {
+            for &idx in holders.iter().filter(|idx| **idx != kept) {
+                let node = &nodes[idx];
+                let result = match detected_ssh_keys.get(&node.node.host) {
+                    Some(ssh_key) => {
+                        crate::commands::switch::set_node_unfunded(ssh_pool, node, ssh_key)
+                            .await
+                            .map_err(|e| e.to_string())
+                    }
+                    None => Err("no SSH key".to_string()),
+                };
+                fence_results.push((node.node.label.clone(), result));
+            }
+        }
+
+        let event = DoubleIdentity {
+            holders: holders
+                .iter()
+                .map(|idx| nodes[*idx].node.label.clone())
+                .collect(),
+            kept: nodes[kept].node.label.clone(),
+            fence_results,
+        };
+
+        // A node fenced now is always reported; a persisting condition only after the cooldown
+        if let Some(alert_manager) = alert_manager {
+            let fenced_any = event.fence_results.iter().any(|(_, result)| result.is_ok());
+            if self.alert_tracker.should_send_alert(validator_idx) || fenced_any {
+                let _ = alert_manager
+                    .send_double_identity_alert(
+                        funded_identity,
+                        &event.holders,
+                        &event.kept,
+                        &event.fence_results,
+                    )
+                    .await;
+            }
+        }
+
+        Some(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{NodeConfig, NodePaths, ValidatorType};
+
+    fn node(label: &str, status: NodeStatus) -> NodeWithStatus {
+        NodeWithStatus {
+            node: NodeConfig {
+                label: label.to_string(),
+                host: format!("{}.example", label),
+                port: 22,
+                user: "solana".to_string(),
+                paths: NodePaths {
+                    funded_identity: "/keys/funded.json".to_string(),
+                    unfunded_identity: "/keys/unfunded.json".to_string(),
+                    vote_keypair: "/keys/vote.json".to_string(),
+                },
+                ssh_key_path: None,
+            },
+            status,
+            validator_type: ValidatorType::Agave,
+            agave_validator_executable: None,
+            fdctl_executable: None,
+            solana_cli_executable: None,
+            version: None,
+            sync_status: None,
+            current_identity: None,
+            ledger_path: None,
+            tower_path: None,
+            swap_ready: None,
+            swap_issues: Vec::new(),
+            ssh_key_path: None,
+        }
+    }
+
+    #[test]
+    fn test_funded_identity_holders_ignores_unreachable_nodes() {
+        let identities = vec![
+            Some("Funded111".to_string()),
+            None,
+            Some("Unfunded222".to_string()),
+            Some("Funded111".to_string()),
+        ];
+        assert_eq!(
+            funded_identity_holders(&identities, "Funded111"),
+            vec![0, 3]
+        );
+    }
+
+    #[test]
+    fn test_preferred_holder_keeps_previous_sole_holder() {
+        let nodes = vec![
+            node("primary", NodeStatus::Active),
+            node("spare", NodeStatus::Standby),
+        ];
+        // A rebooted spare came up with the funded identity: the primary keeps it
+        assert_eq!(preferred_holder(&nodes, &[0, 1], Some(0)), Some(0));
+        // Without history the node known as active wins
+        assert_eq!(preferred_holder(&nodes, &[0, 1], None), Some(0));
+        // Previous holder no longer reports it: fall back to the active node, then the first
+        assert_eq!(preferred_holder(&nodes, &[0, 1], Some(2)), Some(0));
+        let standbys = vec![
+            node("a", NodeStatus::Standby),
+            node("b", NodeStatus::Standby),
+        ];
+        assert_eq!(preferred_holder(&standbys, &[1, 0], None), Some(1));
+    }
+}
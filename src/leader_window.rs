@@ -0,0 +1,137 @@
+use anyhow::{anyhow, Result};
+use solana_client::rpc_config::RpcLeaderScheduleConfig;
+use std::time::Duration;
+
+/// Target slot time on mainnet
+pub const SLOT_DURATION: Duration = Duration::from_millis(400);
+
+/// Upcoming leader slots for one identity, as absolute slot numbers
+#[derive(Debug, Clone)]
+pub struct LeaderSchedule {
+    pub current_slot: u64,
+    pub leader_slots: Vec<u64>,
+}
+
+/// Fetch the leader slots of `identity` for the current epoch and, when the
+/// RPC already knows it, the next one
+pub async fn fetch_leader_schedule(rpc_url: &str, identity: &str) -> Result<LeaderSchedule> {
+    if rpc_url.is_empty() {
+        return Err(anyhow!("RPC URL is empty"));
+    }
+
+    let rpc_client = crate::solana_rpc::rpc_client(rpc_url);
+    let epoch_info = rpc_client
+        .get_epoch_info()
+        .await
+        .map_err(|e| anyhow!("Failed to get epoch info: {}", e))?;
+
+    let epoch_start = epoch_info.absolute_slot - epoch_info.slot_index;
+    let next_epoch_start = epoch_start + epoch_info.slots_in_epoch;
+
+    let mut leader_slots = Vec::new();
+    for (start, required) in [(epoch_start, true), (next_epoch_start, false)] {
+        let schedule = rpc_client
+            .get_leader_schedule_with_config(
+                Some(start),
+                RpcLeaderScheduleConfig {
+                    identity: Some(identity.to_string()),
+                    commitment: None,
+                },
+            )
+            .await;
+
+        match schedule {
+            Ok(Some(schedule)) => {
+                if let Some(indexes) = schedule.get(identity) {
+                    leader_slots.extend(indexes.iter().map(|index| start + *index as u64));
+                }
+            }
+            // The next epoch's schedule may not be available yet
+            Ok(None) if !required => {}
+            Ok(None) => return Err(anyhow!("Leader schedule not available")),
+            Err(_) if !required => {}
+            Err(e) => return Err(anyhow!("Failed to get leader schedule: {}", e)),
+        }
+    }
+    leader_slots.sort_unstable();
+
+    Ok(LeaderSchedule {
+        current_slot: epoch_info.absolute_slot,
+        leader_slots,
+    })
+}
+
+pub async fn fetch_current_slot(rpc_url: &str) -> Result<u64> {
+    if rpc_url.is_empty() {
+        return Err(anyhow!("RPC URL is empty"));
+    }
+
+    crate::solana_rpc::rpc_client(rpc_url)
+        .get_slot()
+        .await
+        .map_err(|e| anyhow!("Failed to get current slot: {}", e))
+}
+
+/// Slots to wait from `current_slot` until a run of `window_slots` slots with none of
+/// our leader slots begins. `leader_slots` must be sorted.
+pub fn slots_until_safe_window(current_slot: u64, leader_slots: &[u64], window_slots: u64) -> u64 {
+    let mut window_start = current_slot;
+
+    for &slot in leader_slots.iter().filter(|slot| **slot >= current_slot) {
+        if slot >= window_start + window_slots {
+            break;
+        }
+        if slot >= window_start {
+            window_start = slot + 1;
+        }
+    }
+
+    window_start - current_slot
+}
+
+/// Our next leader slot at or after `current_slot`
+pub fn next_leader_slot(current_slot: u64, leader_slots: &[u64]) -> Option<u64> {
+    leader_slots
+        .iter()
+        .copied()
+        .find(|slot| *slot >= current_slot)
+}
+
+pub fn slots_to_duration(slots: u64) -> Duration {
+    SLOT_DURATION * slots as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_safe_window_with_no_leader_slots() {
+        assert_eq!(slots_until_safe_window(1_000, &[], 40), 0);
+        // Only past leader slots
+        assert_eq!(slots_until_safe_window(1_000, &[100, 101, 102, 103], 40), 0);
+    }
+
+    #[test]
+    fn test_safe_window_waits_past_leader_group() {
+        let leader_slots = [1_010, 1_011, 1_012, 1_013, 1_200, 1_201, 1_202, 1_203];
+
+        // Next leader slot is far enough away
+        assert_eq!(slots_until_safe_window(960, &leader_slots, 40), 0);
+        // Too close to the next group - wait until it has passed
+        assert_eq!(slots_until_safe_window(1_000, &leader_slots, 40), 14);
+        // In the middle of our leader slots
+        assert_eq!(slots_until_safe_window(1_011, &leader_slots, 40), 3);
+        // Gap between groups is too small for the window, skip both
+        assert_eq!(slots_until_safe_window(1_000, &leader_slots, 200), 204);
+    }
+
+    #[test]
+    fn test_next_leader_slot() {
+        let leader_slots = [1_010, 1_011, 1_200];
+        assert_eq!(next_leader_slot(1_000, &leader_slots), Some(1_010));
+        assert_eq!(next_leader_slot(1_011, &leader_slots), Some(1_011));
+        assert_eq!(next_leader_slot(1_201, &leader_slots), None);
+        assert_eq!(slots_to_duration(10), Duration::from_secs(4));
+    }
+}
@@ -26,9 +26,15 @@ mod commands;
 mod config;
 mod emergency_failover;
 mod executable_utils;
+mod failover_policy;
+mod fencing;
+mod identity_guard;
+mod leader_window;
+mod monitor;
 mod solana_rpc;
 mod ssh;
 mod ssh_key_detector;
+mod standby_selection;
 mod startup;
 mod startup_checks;
 mod startup_logger;
@@ -36,13 +42,24 @@ mod startup_logger;
 mod startup_validation_tests;
 #[cfg(test)]
 mod status_ui_alert_tests;
+mod switch_hooks;
+mod switch_journal;
+mod switch_lock;
+mod switch_plan;
+mod switch_schedule;
+mod switch_state;
 #[cfg(test)]
 mod switch_validation_tests;
+mod tower_file;
 mod types;
+mod validator_backend;
 mod validator_metadata;
 mod validator_rpc;
 
-use commands::{status_command, switch_command, test_alert_command};
+use commands::{
+    batch_switch_command, history_command, monitor_command, status_command, switch_command,
+    test_alert_command, HistoryOptions, LogFormat, MonitorOptions, SwitchOptions,
+};
 use ssh::AsyncSshPool;
 
 #[derive(Parser)]
@@ -58,6 +75,16 @@ struct Cli {
     command: Option<Commands>,
 }
 
+/// Switch options that only apply to a single validator
+const BATCH_CONFLICTS: [&str; 6] = [
+    "validator",
+    "plan",
+    "plan_out",
+    "standby",
+    "at",
+    "at_epoch_boundary",
+];
+
 #[derive(Subcommand)]
 enum Commands {
     /// Check current validator status
@@ -74,9 +101,78 @@ enum Commands {
         /// Select validator by index (0-based) or identity prefix
         #[arg(short, long)]
         validator: Option<String>,
+        /// Switch immediately instead of waiting for a window without our leader slots
+        #[arg(long)]
+        no_wait: bool,
+        /// Schedule the switch for a time in UTC (HH:MM, YYYY-MM-DD HH:MM or RFC 3339)
+        #[arg(long, value_name = "TIME", conflicts_with = "at_epoch_boundary")]
+        at: Option<String>,
+        /// Schedule the switch for right after the next epoch boundary
+        #[arg(long)]
+        at_epoch_boundary: bool,
+        /// Write the switch plan of a dry run to this file for review
+        #[arg(long, value_name = "FILE", requires = "dry_run")]
+        plan_out: Option<std::path::PathBuf>,
+        /// Execute a reviewed switch plan (aborts if the cluster no longer matches it)
+        #[arg(long, value_name = "FILE", conflicts_with_all = ["plan_out", "validator"])]
+        plan: Option<std::path::PathBuf>,
+        /// Activate this standby node (by label) instead of the best-ranked one
+        #[arg(long, value_name = "LABEL", conflicts_with = "plan")]
+        standby: Option<String>,
+        /// Remove a switch lock held by another svs instance (sends an alert)
+        #[arg(long)]
+        break_lock: bool,
+        /// Switch every configured validator
+        #[arg(long, conflicts_with = "validators", conflicts_with_all = BATCH_CONFLICTS)]
+        all: bool,
+        /// Switch these validators (indices or identity prefixes, comma-separated)
+        #[arg(long, value_name = "LIST", value_delimiter = ',', conflicts_with_all = BATCH_CONFLICTS)]
+        validators: Vec<String>,
+        /// Number of validators switched at the same time with --all / --validators
+        #[arg(long, value_name = "N", default_value_t = 1)]
+        concurrency: usize,
     },
     /// Test alert configuration
     TestAlert,
+    /// Run alerts and auto-failover without the dashboard, e.g. as a systemd service
+    Monitor {
+        /// Seconds between checks
+        #[arg(long, value_name = "SECONDS", default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+        interval: u64,
+        /// Log line format (json or text)
+        #[arg(long, value_name = "FORMAT", default_value = "json")]
+        log_format: LogFormat,
+        /// Only log and alert what auto-failover would do, never take over
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Show the switch history journal
+    History {
+        /// Filter by validator identity or vote pubkey prefix
+        #[arg(short, long)]
+        validator: Option<String>,
+        /// Filter by trigger (manual, tui, auto_failover)
+        #[arg(short, long)]
+        trigger: Option<String>,
+        /// Only show switches since this date (YYYY-MM-DD or RFC 3339)
+        #[arg(long)]
+        since: Option<String>,
+        /// Only show failed switches
+        #[arg(long)]
+        failed: bool,
+        /// Include dry runs
+        #[arg(long)]
+        include_dry_runs: bool,
+        /// Show only the most recent N records
+        #[arg(short = 'n', long)]
+        limit: Option<usize>,
+        /// Export format instead of a table (json or csv)
+        #[arg(short, long)]
+        format: Option<String>,
+        /// Write the export to a file instead of stdout
+        #[arg(short, long)]
+        output: Option<String>,
+    },
 }
 
 /// Application state that persists throughout the CLI session
@@ -110,11 +206,16 @@ impl AppState {
 
     /// Parse validator selection from CLI argument
     fn select_validator_from_arg(&mut self, validator_arg: &str) -> Result<()> {
+        self.selected_validator_index = self.validator_index_from_arg(validator_arg)?;
+        Ok(())
+    }
+
+    /// Resolve a validator by index (0-based) or identity prefix
+    fn validator_index_from_arg(&self, validator_arg: &str) -> Result<usize> {
         // Try parsing as index first
         if let Ok(index) = validator_arg.parse::<usize>() {
             if index < self.validator_statuses.len() {
-                self.selected_validator_index = index;
-                return Ok(());
+                return Ok(index);
             } else {
                 return Err(anyhow::anyhow!(
                     "Validator index {} out of range (max: {})",
@@ -137,10 +238,7 @@ impl AppState {
                 "No validator found matching '{}'",
                 validator_arg
             )),
-            1 => {
-                self.selected_validator_index = matches[0].0;
-                Ok(())
-            }
+            1 => Ok(matches[0].0),
             _ => Err(anyhow::anyhow!(
                 "Multiple validators match '{}'. Please be more specific.",
                 validator_arg
@@ -153,6 +251,31 @@ impl AppState {
 async fn main() -> Result<()> {
     let cli = Cli::parse();
 
+    // History only reads the local journal - no need for SSH or startup checks
+    if let Some(Commands::History {
+        validator,
+        trigger,
+        since,
+        failed,
+        include_dry_runs,
+        limit,
+        format,
+        output,
+    }) = cli.command
+    {
+        return history_command(HistoryOptions {
+            validator,
+            trigger,
+            since,
+            failed_only: failed,
+            include_dry_runs,
+            limit,
+            format,
+            output,
+        })
+        .await;
+    }
+
     // Initialize app state with persistent SSH connections
     let app_state = AppState::new_with_config(cli.config).await?;
 
@@ -169,13 +292,67 @@ async fn main() -> Result<()> {
                 std::process::exit(1);
             }
         }
-        Some(Commands::Switch { dry_run, validator }) => {
+        Some(Commands::Switch {
+            dry_run,
+            validator,
+            no_wait,
+            at,
+            at_epoch_boundary,
+            plan_out,
+            plan,
+            standby,
+            break_lock,
+            all,
+            validators,
+            concurrency,
+        }) => {
             if let Some(mut state) = app_state {
-                // Apply validator selection if provided
-                if let Some(validator_arg) = validator {
+                if all || !validators.is_empty() {
+                    let indices = if all {
+                        (0..state.validator_statuses.len()).collect()
+                    } else {
+                        let mut indices = Vec::new();
+                        for validator_arg in &validators {
+                            let index = state.validator_index_from_arg(validator_arg)?;
+                            if !indices.contains(&index) {
+                                indices.push(index);
+                            }
+                        }
+                        indices
+                    };
+                    let options = SwitchOptions {
+                        dry_run,
+                        no_wait,
+                        break_lock,
+                        ..Default::default()
+                    };
+                    batch_switch_command(options, &mut state, indices, concurrency).await?;
+                    return Ok(());
+                }
+
+                // A plan names its validator; otherwise apply the selection if provided
+                let plan = plan
+                    .map(|path| switch_plan::SwitchPlan::load(&path))
+                    .transpose()?;
+                if let Some(plan) = &plan {
+                    state.select_validator_from_arg(&plan.validator_identity)?;
+                } else if let Some(validator_arg) = validator {
                     state.select_validator_from_arg(&validator_arg)?;
                 }
-                let show_status = switch_command(dry_run, &mut state).await?;
+                let options = SwitchOptions {
+                    dry_run,
+                    no_wait,
+                    schedule: switch_schedule::SwitchSchedule::from_args(
+                        at.as_deref(),
+                        at_epoch_boundary,
+                    )?,
+                    // A plan names its standby, so the ranking cannot pick another one
+                    standby: plan.as_ref().map(|p| p.standby.label.clone()).or(standby),
+                    plan,
+                    plan_out,
+                    break_lock,
+                };
+                let show_status = switch_command(options, &mut state).await?;
                 if show_status && !dry_run {
                     status_command(&state).await?;
                 }
@@ -192,6 +369,27 @@ async fn main() -> Result<()> {
                 std::process::exit(1);
             }
         }
+        Some(Commands::Monitor {
+            interval,
+            log_format,
+            dry_run,
+        }) => {
+            if let Some(state) = app_state {
+                monitor_command(
+                    MonitorOptions {
+                        interval_seconds: interval,
+                        log_format,
+                        dry_run,
+                    },
+                    state,
+                )
+                .await?;
+            } else {
+                // Startup validation already showed detailed error messages
+                std::process::exit(1);
+            }
+        }
+        Some(Commands::History { .. }) => unreachable!("history is handled before startup"),
         None => {
             // Interactive main menu only if app state is valid
             if let Some(state) = app_state {
@@ -354,7 +552,7 @@ async fn show_switch_menu(app_state: &mut AppState) -> Result<()> {
 
         match index {
             0 => {
-                let show_status = switch_command(false, app_state).await?;
+                let show_status = switch_command(SwitchOptions::default(), app_state).await?;
                 if show_status {
                     status_command(app_state).await?;
                 }
@@ -362,7 +560,11 @@ async fn show_switch_menu(app_state: &mut AppState) -> Result<()> {
                 break;
             }
             1 => {
-                let _ = switch_command(true, app_state).await?;
+                let options = SwitchOptions {
+                    dry_run: true,
+                    ..SwitchOptions::default()
+                };
+                let _ = switch_command(options, app_state).await?;
                 // Dry run doesn't show status
             }
             2 => break, // Back to main menu
@@ -0,0 +1,1232 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+
+use crate::alert::{AlertManager, AlertTracker, ComprehensiveAlertTracker};
+use crate::failover_policy::{decide, FailoverDecision, FailoverSituation, FailoverVerdict};
+use crate::identity_guard::{probe_identities, IdentityGuard};
+use crate::solana_rpc::{fetch_vote_account_data, ValidatorVoteData};
+use crate::ssh::AsyncSshPool;
+use crate::standby_selection::{fetch_slot_lags, rank_standby_candidates};
+use crate::switch_journal::{SwitchJournal, SwitchTrigger};
+use crate::types::{
+    default_delinquency_threshold, default_rpc_failure_threshold, AlertConfig, FailureTracker,
+    NodeHealthStatus, NodeStatus, NodeWithStatus, SwitchPolicy,
+};
+use crate::{AppState, ValidatorStatus};
+
+/// Severity of a monitor event
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EventLevel {
+    Info,
+    Warning,
+    Error,
+}
+
+/// Something the monitor noticed or did
+#[derive(Debug, Clone, Serialize)]
+pub struct MonitorEvent {
+    pub level: EventLevel,
+    /// Short name to filter on, e.g. `delinquent` or `failover_started`
+    pub event: &'static str,
+    /// Identity of the validator (empty for events about the monitor itself)
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub validator: String,
+    pub message: String,
+}
+
+/// Remember when the last vote slot changed. Returns true when it moved.
+fn track_last_vote(tracked: &mut Option<(u64, Instant)>, slot: Option<u64>, now: Instant) -> bool {
+    match (slot, *tracked) {
+        (Some(slot), Some((last, _))) if slot == last => false,
+        (Some(slot), _) => {
+            *tracked = Some((slot, now));
+            true
+        }
+        (None, _) => {
+            *tracked = None;
+            false
+        }
+    }
+}
+
+fn last_vote_slot(data: &ValidatorVoteData) -> Option<u64> {
+    data.recent_votes.last().map(|v| v.slot)
+}
+
+/// What one RPC endpoint says about a validator's votes
+#[derive(Debug, Clone)]
+pub struct EndpointWatch {
+    pub url: String,
+    /// Last vote slot this endpoint reported and when it last changed
+    pub last_vote_change: Option<(u64, Instant)>,
+    pub rpc: FailureTracker,
+}
+
+impl EndpointWatch {
+    pub fn new(url: String) -> Self {
+        Self {
+            url,
+            last_vote_change: None,
+            rpc: FailureTracker::new(),
+        }
+    }
+
+    /// Host of the endpoint, leaving out API keys in its path or query
+    pub fn host(&self) -> String {
+        url::Url::parse(&self.url)
+            .ok()
+            .and_then(|url| url.host_str().map(str::to_string))
+            .unwrap_or_else(|| self.url.clone())
+    }
+
+    /// Record the last vote slot this endpoint reported. Returns true when it moved.
+    pub fn record_votes(&mut self, slot: Option<u64>, now: Instant) -> bool {
+        self.rpc.record_success();
+        track_last_vote(&mut self.last_vote_change, slot, now)
+    }
+
+    /// Seconds since this endpoint last saw a new vote, if it answered the last request
+    pub fn seconds_since_vote(&self, now: Instant) -> Option<u64> {
+        if self.rpc.consecutive_failures > 0 {
+            return None;
+        }
+        self.last_vote_change
+            .map(|(_, changed)| now.saturating_duration_since(changed).as_secs())
+    }
+
+    /// One line for alerts, logs and the dashboard
+    pub fn describe(&self, now: Instant, threshold: u64) -> String {
+        let host = self.host();
+        if self.rpc.consecutive_failures > 0 {
+            return format!(
+                "❌ {}: {}",
+                host,
+                self.rpc.last_error.as_deref().unwrap_or("unreachable")
+            );
+        }
+        match (self.last_vote_change, self.seconds_since_vote(now)) {
+            (Some((slot, _)), Some(seconds)) if seconds >= threshold => {
+                format!("⚠️ {}: no new vote for {}s (slot {})", host, seconds, slot)
+            }
+            (Some((slot, _)), _) => format!("✅ {}: slot {}", host, slot),
+            (None, _) => format!("❔ {}: no recent votes", host),
+        }
+    }
+}
+
+/// How the RPC endpoints of a validator see its votes
+#[derive(Debug, Clone)]
+pub struct QuorumSummary {
+    /// Endpoints that must agree before the validator counts as delinquent
+    pub quorum: usize,
+    pub total: usize,
+    /// Endpoints without a new vote for the delinquency threshold
+    pub lagging: usize,
+    /// Endpoints that did not answer the last request
+    pub failing: usize,
+    /// One line per endpoint
+    pub endpoints: Vec<String>,
+}
+
+impl QuorumSummary {
+    pub fn headline(&self) -> String {
+        format!(
+            "{} of {} RPC endpoints see no new votes ({} needed)",
+            self.lagging, self.total, self.quorum
+        )
+    }
+
+    pub fn is_delinquent(&self) -> bool {
+        self.lagging >= self.quorum
+    }
+
+    /// Some endpoints see the validator vote and others don't
+    pub fn has_disagreement(&self) -> bool {
+        self.lagging > 0 && self.lagging < self.total
+    }
+}
+
+/// Seconds that at least `quorum` endpoints agree the validator has not voted for: the
+/// quorum-th longest wait among the endpoints that answered, `None` with fewer answers
+pub fn quorum_seconds_since_vote(endpoint_seconds: &[Option<u64>], quorum: usize) -> Option<u64> {
+    let mut seconds: Vec<u64> = endpoint_seconds.iter().flatten().copied().collect();
+    seconds.sort_unstable_by(|a, b| b.cmp(a));
+    seconds.get(quorum.checked_sub(1)?).copied()
+}
+
+/// Vote and connectivity tracking for one validator
+#[derive(Debug, Clone)]
+pub struct VoteWatch {
+    /// Vote data of the endpoint with the newest last vote
+    pub vote_data: Option<ValidatorVoteData>,
+    /// Newest last vote slot and when it last changed
+    pub last_vote_change: Option<(u64, Instant)>,
+    /// Getting vote data from enough endpoints to reach the quorum
+    pub rpc: FailureTracker,
+    /// Asking the active node for its identity
+    pub ssh: FailureTracker,
+    pub endpoints: Vec<EndpointWatch>,
+    pub quorum: usize,
+    delinquent: bool,
+    disagreement: bool,
+}
+
+impl VoteWatch {
+    pub fn new(endpoints: Vec<String>, quorum: usize) -> Self {
+        Self {
+            vote_data: None,
+            last_vote_change: None,
+            rpc: FailureTracker::new(),
+            ssh: FailureTracker::new(),
+            endpoints: endpoints.into_iter().map(EndpointWatch::new).collect(),
+            quorum,
+            delinquent: false,
+            disagreement: false,
+        }
+    }
+
+    /// Record the freshest vote data of this round. Returns true when the last vote slot moved.
+    pub fn record_votes(&mut self, data: ValidatorVoteData, now: Instant) -> bool {
+        self.rpc.record_success();
+        let slot = last_vote_slot(&data);
+        self.vote_data = Some(data);
+        track_last_vote(&mut self.last_vote_change, slot, now)
+    }
+
+    /// Seconds the quorum of endpoints agrees the validator has not voted for
+    pub fn quorum_seconds_since_vote(&self, now: Instant) -> Option<u64> {
+        let seconds: Vec<Option<u64>> = self
+            .endpoints
+            .iter()
+            .map(|endpoint| endpoint.seconds_since_vote(now))
+            .collect();
+        quorum_seconds_since_vote(&seconds, self.quorum)
+    }
+
+    pub fn quorum_summary(&self, now: Instant, threshold: u64) -> QuorumSummary {
+        QuorumSummary {
+            quorum: self.quorum,
+            total: self.endpoints.len(),
+            lagging: self
+                .endpoints
+                .iter()
+                .filter(|e| e.seconds_since_vote(now).is_some_and(|s| s >= threshold))
+                .count(),
+            failing: self
+                .endpoints
+                .iter()
+                .filter(|e| e.rpc.consecutive_failures > 0)
+                .count(),
+            endpoints: self
+                .endpoints
+                .iter()
+                .map(|e| e.describe(now, threshold))
+                .collect(),
+        }
+    }
+}
+
+/// Whether auto-failover may take over a validator whose last vote slot has not moved
+/// for `seconds_since_vote`. Only on-chain data counts: with the vote RPC failing there
+/// is no telling whether it votes, while SSH to a primary that went down fails anyway.
+pub fn should_fail_over(
+    alert_config: Option<&AlertConfig>,
+    seconds_since_vote: u64,
+    vote_rpc: &FailureTracker,
+) -> bool {
+    alert_config.is_some_and(|config| {
+        config.enabled
+            && config.auto_failover_enabled
+            && seconds_since_vote >= config.delinquency_threshold_seconds
+    }) && vote_rpc.consecutive_failures == 0
+}
+
+/// Update node statuses from the identities the nodes reported and return the label of
+/// a node that became active. A node that can't be asked keeps its status, so a primary
+/// that went down still counts as active until another node holds the funded identity.
+pub fn apply_identities(
+    nodes: &mut [NodeWithStatus],
+    identities: &[Option<String>],
+    funded_identity: &str,
+) -> Option<String> {
+    let held = identities
+        .iter()
+        .any(|identity| identity.as_deref() == Some(funded_identity));
+    let mut newly_active = None;
+
+    for (node, identity) in nodes.iter_mut().zip(identities) {
+        let Some(identity) = identity else {
+            if held && node.status == NodeStatus::Active {
+                node.status = NodeStatus::Unknown;
+            }
+            continue;
+        };
+        let status = if identity == funded_identity {
+            NodeStatus::Active
+        } else {
+            NodeStatus::Standby
+        };
+        if status == NodeStatus::Active && node.status != NodeStatus::Active {
+            newly_active = Some(node.node.label.clone());
+        }
+        node.status = status;
+        node.current_identity = Some(identity.clone());
+    }
+    newly_active
+}
+
+/// Start times of the automatic failovers of a validator in the switch journal, so rate
+/// limits and cooldowns hold across restarts
+fn journaled_failovers(validator_identity: &str) -> Vec<DateTime<Utc>> {
+    SwitchJournal::new()
+        .and_then(|journal| journal.load())
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|record| {
+            record.trigger == SwitchTrigger::AutoFailover
+                && !record.dry_run
+                && record.validator_identity == validator_identity
+        })
+        .filter_map(|record| DateTime::parse_from_rfc3339(&record.timestamp).ok())
+        .map(|timestamp| timestamp.with_timezone(&Utc))
+        .collect()
+}
+
+/// Delinquency alerts, auto-failover and the double-identity guard. Shared by the status
+/// dashboard and `svs monitor`; call `tick` every few seconds.
+pub struct MonitorEngine {
+    app_state: Arc<AppState>,
+    statuses: Vec<ValidatorStatus>,
+    watches: Vec<VoteWatch>,
+    alert_manager: Option<AlertManager>,
+    alert_tracker: ComprehensiveAlertTracker,
+    disagreement_tracker: AlertTracker,
+    identity_guard: IdentityGuard,
+    failover_in_progress: Arc<RwLock<bool>>,
+    /// Per validator, when automatic failover took over (or would have, in dry-run mode)
+    failover_history: Vec<Vec<DateTime<Utc>>>,
+    /// Validator index and task of a running emergency takeover
+    failover: Option<(usize, JoinHandle<Result<()>>)>,
+}
+
+impl MonitorEngine {
+    /// `failover_in_progress` is set while an emergency takeover runs
+    pub fn new(app_state: Arc<AppState>, failover_in_progress: Arc<RwLock<bool>>) -> Self {
+        let validator_count = app_state.validator_statuses.len();
+        let alert_manager = app_state
+            .config
+            .alert_config
+            .as_ref()
+            .filter(|config| config.enabled)
+            .map(|config| AlertManager::new(config.clone()));
+        let fence = app_state
+            .config
+            .switch_policy
+            .as_ref()
+            .is_some_and(|policy| policy.fence_double_identity);
+
+        Self {
+            statuses: app_state.validator_statuses.clone(),
+            watches: app_state
+                .validator_statuses
+                .iter()
+                .map(|status| {
+                    let pair = &status.validator_pair;
+                    VoteWatch::new(pair.rpc_endpoints(), pair.rpc_quorum())
+                })
+                .collect(),
+            alert_manager,
+            alert_tracker: ComprehensiveAlertTracker::new(validator_count, 2),
+            // Low severity like RPC failures: a lagging endpoint is not an outage
+            disagreement_tracker: AlertTracker::with_cooldown(validator_count, 1800),
+            identity_guard: IdentityGuard::new(validator_count, fence),
+            failover_in_progress,
+            failover_history: app_state
+                .validator_statuses
+                .iter()
+                .map(|status| journaled_failovers(&status.validator_pair.identity_pubkey))
+                .collect(),
+            failover: None,
+            app_state,
+        }
+    }
+
+    pub fn watch(&self, validator_idx: usize) -> Option<&VoteWatch> {
+        self.watches.get(validator_idx)
+    }
+
+    /// How the RPC endpoints of a validator see its votes, if it has more than one
+    pub fn quorum_summary(&self, validator_idx: usize) -> Option<QuorumSummary> {
+        self.watches
+            .get(validator_idx)
+            .filter(|watch| watch.endpoints.len() > 1)
+            .map(|watch| watch.quorum_summary(Instant::now(), self.delinquency_threshold()))
+    }
+
+    fn delinquency_threshold(&self) -> u64 {
+        self.app_state
+            .config
+            .alert_config
+            .as_ref()
+            .map_or_else(default_delinquency_threshold, |config| {
+                config.delinquency_threshold_seconds
+            })
+    }
+
+    /// Probe identities, fetch vote data and act on what changed
+    pub async fn tick(&mut self) -> Vec<MonitorEvent> {
+        let mut events = Vec::new();
+        self.finish_failover(false, &mut events).await;
+        self.check_identities(&mut events).await;
+        self.check_votes(&mut events).await;
+        events
+    }
+
+    pub fn is_failing_over(&self) -> bool {
+        self.failover.is_some()
+    }
+
+    /// Wait for an emergency takeover that is still running
+    pub async fn shutdown(&mut self) -> Vec<MonitorEvent> {
+        let mut events = Vec::new();
+        self.finish_failover(true, &mut events).await;
+        events
+    }
+
+    fn event(
+        &self,
+        validator_idx: usize,
+        level: EventLevel,
+        event: &'static str,
+        message: String,
+    ) -> MonitorEvent {
+        MonitorEvent {
+            level,
+            event,
+            validator: self.statuses[validator_idx]
+                .validator_pair
+                .identity_pubkey
+                .clone(),
+            message,
+        }
+    }
+
+    async fn finish_failover(&mut self, wait: bool, events: &mut Vec<MonitorEvent>) {
+        let finished = self
+            .failover
+            .as_ref()
+            .is_some_and(|(_, handle)| handle.is_finished());
+        if !(wait || finished) {
+            return;
+        }
+        let Some((validator_idx, handle)) = self.failover.take() else {
+            return;
+        };
+        events.push(match handle.await {
+            Ok(Ok(())) => self.event(
+                validator_idx,
+                EventLevel::Info,
+                "failover_finished",
+                "emergency takeover finished".to_string(),
+            ),
+            Ok(Err(e)) => self.event(
+                validator_idx,
+                EventLevel::Error,
+                "failover_failed",
+                format!("emergency takeover failed: {}", e),
+            ),
+            Err(e) => self.event(
+                validator_idx,
+                EventLevel::Error,
+                "failover_failed",
+                format!("emergency takeover task failed: {}", e),
+            ),
+        });
+    }
+
+    async fn check_identities(&mut self, events: &mut Vec<MonitorEvent>) {
+        let ssh_pool = Arc::clone(&self.app_state.ssh_pool);
+        let detected_ssh_keys = &self.app_state.detected_ssh_keys;
+
+        for validator_idx in 0..self.statuses.len() {
+            let identities = probe_identities(
+                &self.statuses[validator_idx].nodes_with_status,
+                &ssh_pool,
+                detected_ssh_keys,
+            )
+            .await;
+
+            let status = &mut self.statuses[validator_idx];
+            let funded_identity = status.validator_pair.identity_pubkey.clone();
+            let newly_active =
+                apply_identities(&mut status.nodes_with_status, &identities, &funded_identity);
+            if let Some(active) = status
+                .nodes_with_status
+                .iter()
+                .position(|n| n.status == NodeStatus::Active)
+            {
+                let watch = &mut self.watches[validator_idx];
+                match &identities[active] {
+                    Some(_) => watch.ssh.record_success(),
+                    None => watch.ssh.record_failure(format!(
+                        "{} did not report its identity",
+                        status.nodes_with_status[active].node.label
+                    )),
+                }
+            }
+            if let Some(label) = newly_active {
+                events.push(self.event(
+                    validator_idx,
+                    EventLevel::Info,
+                    "active_changed",
+                    format!("{} now runs the funded identity", label),
+                ));
+            }
+
+            if let Some(double) = self
+                .identity_guard
+                .check(
+                    validator_idx,
+                    &self.statuses[validator_idx],
+                    &identities,
+                    &ssh_pool,
+                    detected_ssh_keys,
+                    self.alert_manager.as_ref(),
+                )
+                .await
+            {
+                let fenced: Vec<String> = double
+                    .fence_results
+                    .iter()
+                    .map(|(label, result)| match result {
+                        Ok(()) => format!("{} set to unfunded", label),
+                        Err(e) => format!("{} not fenced: {}", label, e),
+                    })
+                    .collect();
+                let mut message = format!(
+                    "{} report the funded identity, {} keeps it",
+                    double.holders.join(", "),
+                    double.kept
+                );
+                if !fenced.is_empty() {
+                    message.push_str(&format!(" ({})", fenced.join(", ")));
+                }
+                events.push(self.event(
+                    validator_idx,
+                    EventLevel::Error,
+                    "double_identity",
+                    message,
+                ));
+            }
+        }
+    }
+
+    async fn check_votes(&mut self, events: &mut Vec<MonitorEvent>) {
+        let results = futures::future::join_all(self.watches.iter().zip(&self.statuses).map(
+            |(watch, status)| {
+                futures::future::join_all(watch.endpoints.iter().map(|endpoint| {
+                    fetch_vote_account_data(&endpoint.url, &status.validator_pair.vote_pubkey)
+                }))
+            },
+        ))
+        .await;
+        let now = Instant::now();
+        let threshold = self.delinquency_threshold();
+
+        for (validator_idx, results) in results.into_iter().enumerate() {
+            let watch = &mut self.watches[validator_idx];
+            let mut freshest: Option<ValidatorVoteData> = None;
+            let mut new_errors = Vec::new();
+            for (endpoint, result) in watch.endpoints.iter_mut().zip(results) {
+                match result {
+                    Ok(data) => {
+                        let slot = last_vote_slot(&data);
+                        endpoint.record_votes(slot, now);
+                        if freshest.as_ref().is_none_or(|f| last_vote_slot(f) < slot) {
+                            freshest = Some(data);
+                        }
+                    }
+                    Err(e) => {
+                        endpoint.rpc.record_failure(e.to_string());
+                        if endpoint.rpc.consecutive_failures == 1 {
+                            new_errors.push(format!("{}: {}", endpoint.host(), e));
+                        }
+                    }
+                }
+            }
+            for error in new_errors {
+                events.push(self.event(
+                    validator_idx,
+                    EventLevel::Warning,
+                    "rpc_error",
+                    format!("failed to fetch vote data from {}", error),
+                ));
+            }
+
+            // Without enough answers there is no quorum to decide on
+            let watch = &mut self.watches[validator_idx];
+            let answered = watch
+                .endpoints
+                .iter()
+                .filter(|e| e.rpc.consecutive_failures == 0)
+                .count();
+            let Some(data) = freshest.filter(|_| answered >= watch.quorum) else {
+                let failing: Vec<String> = watch
+                    .endpoints
+                    .iter()
+                    .filter(|e| e.rpc.consecutive_failures > 0)
+                    .map(|e| e.describe(now, threshold))
+                    .collect();
+                let error = anyhow!(
+                    "{} of {} RPC endpoints answered, {} needed ({})",
+                    answered,
+                    watch.endpoints.len(),
+                    watch.quorum,
+                    failing.join("; ")
+                );
+                self.record_rpc_failure(validator_idx, error, events).await;
+                continue;
+            };
+
+            let recovered = watch.rpc.consecutive_failures > 0;
+            watch.record_votes(data, now);
+            if recovered {
+                self.alert_tracker.rpc_failure_tracker.reset(validator_idx);
+                events.push(self.event(
+                    validator_idx,
+                    EventLevel::Info,
+                    "rpc_recovered",
+                    "vote data available again".to_string(),
+                ));
+            }
+
+            let watch = &mut self.watches[validator_idx];
+            match watch.quorum_seconds_since_vote(now) {
+                Some(seconds) if seconds >= threshold => {
+                    self.check_delinquency(validator_idx, seconds, now, events)
+                        .await;
+                }
+                _ => {
+                    let resumed = std::mem::take(&mut watch.delinquent);
+                    let slot = watch.last_vote_change.map(|(slot, _)| slot).unwrap_or(0);
+                    self.alert_tracker.delinquency_tracker.reset(validator_idx);
+                    if resumed {
+                        events.push(self.event(
+                            validator_idx,
+                            EventLevel::Info,
+                            "voting",
+                            format!("voting again (last vote slot {})", slot),
+                        ));
+                    }
+                    self.check_disagreement(validator_idx, now, events).await;
+                }
+            }
+        }
+    }
+
+    /// Report endpoints that see no new votes while the quorum still does, once per episode
+    async fn check_disagreement(
+        &mut self,
+        validator_idx: usize,
+        now: Instant,
+        events: &mut Vec<MonitorEvent>,
+    ) {
+        let summary = self.watches[validator_idx].quorum_summary(now, self.delinquency_threshold());
+        let watch = &mut self.watches[validator_idx];
+        if !summary.has_disagreement() {
+            if std::mem::take(&mut watch.disagreement) {
+                self.disagreement_tracker.reset(validator_idx);
+                events.push(self.event(
+                    validator_idx,
+                    EventLevel::Info,
+                    "rpc_agreement",
+                    "all RPC endpoints see new votes again".to_string(),
+                ));
+            }
+            return;
+        }
+        if std::mem::replace(&mut watch.disagreement, true) {
+            return;
+        }
+
+        events.push(self.event(
+            validator_idx,
+            EventLevel::Warning,
+            "rpc_disagreement",
+            format!(
+                "{}, not delinquent: {}",
+                summary.headline(),
+                summary.endpoints.join("; ")
+            ),
+        ));
+        if let Some(alert_manager) = &self.alert_manager {
+            if !self.disagreement_tracker.should_send_alert(validator_idx) {
+                return;
+            }
+            if let Err(e) = alert_manager
+                .send_rpc_disagreement_alert(
+                    &self.statuses[validator_idx].validator_pair.identity_pubkey,
+                    &summary.headline(),
+                    &summary.endpoints,
+                )
+                .await
+            {
+                events.push(self.alert_failed(validator_idx, e));
+            }
+        }
+    }
+
+    async fn record_rpc_failure(
+        &mut self,
+        validator_idx: usize,
+        error: anyhow::Error,
+        events: &mut Vec<MonitorEvent>,
+    ) {
+        let watch = &mut self.watches[validator_idx];
+        watch.rpc.record_failure(error.to_string());
+        let rpc = watch.rpc.clone();
+
+        let threshold = self
+            .app_state
+            .config
+            .alert_config
+            .as_ref()
+            .map_or_else(default_rpc_failure_threshold, |config| {
+                config.rpc_failure_threshold_seconds
+            });
+        let seconds = rpc.seconds_since_first_failure().unwrap_or(0);
+        if seconds < threshold
+            || !self
+                .alert_tracker
+                .rpc_failure_tracker
+                .should_send_alert(validator_idx)
+        {
+            return;
+        }
+
+        events.push(self.event(
+            validator_idx,
+            EventLevel::Error,
+            "rpc_failure",
+            format!(
+                "no vote data for {}s ({} failures), auto-failover is suspended",
+                seconds, rpc.consecutive_failures
+            ),
+        ));
+        if let Some(alert_manager) = &self.alert_manager {
+            let pair = &self.statuses[validator_idx].validator_pair;
+            if let Err(e) = alert_manager
+                .send_rpc_failure_alert(
+                    &pair.identity_pubkey,
+                    &pair.vote_pubkey,
+                    rpc.consecutive_failures,
+                    seconds,
+                    rpc.last_error.as_deref().unwrap_or("unknown"),
+                )
+                .await
+            {
+                events.push(self.alert_failed(validator_idx, e));
+            }
+        }
+    }
+
+    async fn check_delinquency(
+        &mut self,
+        validator_idx: usize,
+        seconds: u64,
+        now: Instant,
+        events: &mut Vec<MonitorEvent>,
+    ) {
+        let watch = &self.watches[validator_idx];
+        let Some((slot, changed)) = watch.last_vote_change else {
+            return;
+        };
+        if !self
+            .alert_tracker
+            .delinquency_tracker
+            .should_send_alert(validator_idx)
+        {
+            return;
+        }
+
+        let alert_config = self.app_state.config.alert_config.as_ref();
+        let fail_over = should_fail_over(alert_config, seconds, &watch.rpc);
+        let health = NodeHealthStatus {
+            ssh_status: watch.ssh.clone(),
+            rpc_status: watch.rpc.clone(),
+            is_voting: false,
+            last_vote_slot: Some(slot),
+            last_vote_time: Some(changed),
+        };
+        let summary = (watch.endpoints.len() > 1)
+            .then(|| watch.quorum_summary(now, self.delinquency_threshold()));
+        self.watches[validator_idx].delinquent = true;
+
+        let nodes = &self.statuses[validator_idx].nodes_with_status;
+        let active = nodes.iter().find(|n| n.status == NodeStatus::Active);
+        let label = active
+            .or(nodes.first())
+            .map(|n| n.node.label.clone())
+            .unwrap_or_default();
+        let mut message = format!(
+            "no new vote for {}s (last vote slot {}, active node {})",
+            seconds, slot, label
+        );
+        if let Some(summary) = &summary {
+            message.push_str(&format!(
+                "; {}: {}",
+                summary.headline(),
+                summary.endpoints.join("; ")
+            ));
+        }
+        events.push(self.event(validator_idx, EventLevel::Warning, "delinquent", message));
+
+        if let Some(alert_manager) = &self.alert_manager {
+            if let Err(e) = alert_manager
+                .send_delinquency_alert_with_health(
+                    &self.statuses[validator_idx].validator_pair.identity_pubkey,
+                    &label,
+                    active.is_some(),
+                    slot,
+                    seconds,
+                    &health,
+                    summary.as_ref().map_or(&[][..], |s| &s.endpoints[..]),
+                )
+                .await
+            {
+                events.push(self.alert_failed(validator_idx, e));
+            }
+        }
+
+        if fail_over {
+            let decision = self.decide_failover(validator_idx, seconds).await;
+            self.apply_decision(validator_idx, decision, events).await;
+        }
+    }
+
+    /// Check a delinquent validator against `alert_config.auto_failover_policy`
+    async fn decide_failover(&self, validator_idx: usize, seconds: u64) -> FailoverDecision {
+        let default_policy = Default::default();
+        let policy = self
+            .app_state
+            .config
+            .alert_config
+            .as_ref()
+            .map_or(&default_policy, |config| &config.auto_failover_policy);
+        let status = &self.statuses[validator_idx];
+        let nodes = &status.nodes_with_status;
+        let active_index = nodes.iter().position(|n| n.status == NodeStatus::Active);
+
+        let standby = if policy.require_standby_healthy {
+            let slot_lags = fetch_slot_lags(
+                &self.app_state.ssh_pool,
+                nodes,
+                &self.app_state.detected_ssh_keys,
+                &status.validator_pair.rpc,
+            )
+            .await;
+            rank_standby_candidates(nodes, active_index, &slot_lags)
+                .into_iter()
+                .next()
+        } else {
+            None
+        };
+
+        decide(
+            policy,
+            &FailoverSituation {
+                now: Utc::now(),
+                seconds_since_vote: seconds,
+                delinquency_threshold: self.delinquency_threshold(),
+                previous_failovers: &self.failover_history[validator_idx],
+                active_label: active_index.map_or("unknown", |idx| &nodes[idx].node.label),
+                active_reachable: self.watches[validator_idx].ssh.consecutive_failures == 0,
+                standby: standby.as_ref(),
+            },
+        )
+    }
+
+    async fn apply_decision(
+        &mut self,
+        validator_idx: usize,
+        decision: FailoverDecision,
+        events: &mut Vec<MonitorEvent>,
+    ) {
+        let (level, event) = match decision.verdict {
+            FailoverVerdict::TakeOver => (EventLevel::Info, "failover_approved"),
+            FailoverVerdict::DryRun => (EventLevel::Warning, "failover_dry_run"),
+            FailoverVerdict::Blocked => (EventLevel::Warning, "failover_blocked"),
+        };
+        events.push(self.event(validator_idx, level, event, decision.summary()));
+
+        if decision.verdict == FailoverVerdict::TakeOver {
+            self.start_failover(validator_idx, decision.reasons, events);
+            return;
+        }
+        if decision.verdict == FailoverVerdict::DryRun {
+            // Rate limits and cooldowns apply to the takeovers it would have run
+            self.failover_history[validator_idx].push(Utc::now());
+        }
+        if let Some(alert_manager) = &self.alert_manager {
+            if let Err(e) = alert_manager
+                .send_failover_decision_alert(
+                    &self.statuses[validator_idx].validator_pair.identity_pubkey,
+                    decision.headline(),
+                    &decision.reasons,
+                )
+                .await
+            {
+                events.push(self.alert_failed(validator_idx, e));
+            }
+        }
+    }
+
+    fn start_failover(
+        &mut self,
+        validator_idx: usize,
+        decision: Vec<String>,
+        events: &mut Vec<MonitorEvent>,
+    ) {
+        if let Some((running, _)) = &self.failover {
+            let running = self.statuses[*running]
+                .validator_pair
+                .identity_pubkey
+                .clone();
+            events.push(self.event(
+                validator_idx,
+                EventLevel::Warning,
+                "failover_suppressed",
+                format!("an emergency takeover of {} is still running", running),
+            ));
+            return;
+        }
+        // Auto-failover requires alerts to be enabled
+        let Some(alert_manager) = self.alert_manager.clone() else {
+            return;
+        };
+
+        events.push(self.event(
+            validator_idx,
+            EventLevel::Error,
+            "failover_started",
+            "auto-failover: starting emergency takeover".to_string(),
+        ));
+        let handle = tokio::spawn(execute_emergency_failover(
+            self.statuses[validator_idx].clone(),
+            alert_manager,
+            Arc::clone(&self.app_state.ssh_pool),
+            self.app_state.detected_ssh_keys.clone(),
+            Arc::clone(&self.failover_in_progress),
+            self.app_state
+                .config
+                .switch_policy
+                .clone()
+                .unwrap_or_default(),
+            decision,
+        ));
+        self.failover = Some((validator_idx, handle));
+        self.failover_history[validator_idx].push(Utc::now());
+    }
+
+    fn alert_failed(&self, validator_idx: usize, error: anyhow::Error) -> MonitorEvent {
+        self.event(
+            validator_idx,
+            EventLevel::Error,
+            "alert_failed",
+            format!("failed to send alert: {}", error),
+        )
+    }
+}
+
+/// Take over a validator with its best-ranked standby nodes
+async fn execute_emergency_failover(
+    validator_status: ValidatorStatus,
+    alert_manager: AlertManager,
+    ssh_pool: Arc<AsyncSshPool>,
+    detected_ssh_keys: HashMap<String, String>,
+    failover_in_progress: Arc<RwLock<bool>>,
+    switch_policy: SwitchPolicy,
+    decision: Vec<String>,
+) -> Result<()> {
+    // Find the active node and rank the standbys to try, best first
+    let nodes = &validator_status.nodes_with_status;
+    let active_index = nodes
+        .iter()
+        .position(|n| n.status == NodeStatus::Active)
+        .ok_or_else(|| anyhow!("could not identify the active node"))?;
+    let active_node = nodes[active_index].clone();
+    let standby_candidates: Vec<_> =
+        crate::standby_selection::rank_standby_candidates(nodes, Some(active_index), &[])
+            .into_iter()
+            .filter(|c| nodes[c.index].status == NodeStatus::Standby)
+            .map(|c| nodes[c.index].clone())
+            .collect();
+    if standby_candidates.is_empty() {
+        return Err(anyhow!("no standby node available"));
+    }
+
+    // Set the flag to suspend the dashboard's rendering
+    *failover_in_progress.write().await = true;
+
+    // Wait a moment for the dashboard to stop rendering and clean up the terminal
+    tokio::time::sleep(Duration::from_millis(300)).await;
+
+    let mut emergency_failover = crate::emergency_failover::EmergencyFailover::new(
+        active_node,
+        standby_candidates,
+        validator_status.validator_pair,
+        ssh_pool,
+        detected_ssh_keys,
+        alert_manager,
+        switch_policy,
+    )
+    .with_decision(decision);
+    let result = emergency_failover.execute_emergency_takeover().await;
+
+    // Give a dashboard user a moment to see the results
+    tokio::time::sleep(Duration::from_secs(3)).await;
+
+    *failover_in_progress.write().await = false;
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solana_rpc::{RecentVote, VoteAccountInfo};
+    use crate::types::{NodeConfig, NodePaths, ValidatorType};
+
+    fn node(label: &str, status: NodeStatus) -> NodeWithStatus {
+        NodeWithStatus {
+            node: NodeConfig {
+                label: label.to_string(),
+                host: format!("{}.example", label),
+                port: 22,
+                user: "solana".to_string(),
+                paths: NodePaths {
+                    funded_identity: "/keys/funded.json".to_string(),
+                    unfunded_identity: "/keys/unfunded.json".to_string(),
+                    vote_keypair: "/keys/vote.json".to_string(),
+                },
+                ssh_key_path: None,
+            },
+            status,
+            validator_type: ValidatorType::Agave,
+            agave_validator_executable: None,
+            fdctl_executable: None,
+            solana_cli_executable: None,
+            version: None,
+            sync_status: None,
+            current_identity: None,
+            ledger_path: None,
+            tower_path: None,
+            swap_ready: None,
+            swap_issues: Vec::new(),
+            ssh_key_path: None,
+        }
+    }
+
+    fn votes(last_slot: Option<u64>) -> ValidatorVoteData {
+        ValidatorVoteData {
+            vote_account_info: VoteAccountInfo {
+                vote_pubkey: "Vote111".to_string(),
+                validator_identity: "Funded111".to_string(),
+                activated_stake: 0,
+                commission: 0,
+                root_slot: 0,
+                last_vote: last_slot.unwrap_or(0),
+                credits: 0,
+                recent_timestamp: None,
+                current_slot: None,
+            },
+            recent_votes: last_slot
+                .map(|slot| RecentVote {
+                    slot,
+                    confirmation_count: 1,
+                    latency: 1,
+                })
+                .into_iter()
+                .collect(),
+            is_voting: last_slot.is_some(),
+        }
+    }
+
+    #[test]
+    fn test_vote_watch_tracks_when_the_last_vote_moved() {
+        let start = Instant::now();
+        let mut watch = VoteWatch::new(vec!["http://localhost:8899".to_string()], 1);
+        watch
+            .rpc
+            .record_failure("429 Too Many Requests".to_string());
+
+        assert!(watch.record_votes(votes(Some(100)), start));
+        assert_eq!(watch.rpc.consecutive_failures, 0);
+
+        let later = start + Duration::from_secs(45);
+        assert!(!watch.record_votes(votes(Some(100)), later));
+        assert_eq!(watch.last_vote_change, Some((100, start)));
+
+        assert!(watch.record_votes(votes(Some(101)), later));
+        assert_eq!(watch.last_vote_change, Some((101, later)));
+
+        assert!(!watch.record_votes(votes(None), later));
+        assert_eq!(watch.last_vote_change, None);
+    }
+
+    #[test]
+    fn test_quorum_of_endpoints_decides_delinquency() {
+        // One lagging endpoint out of three is outvoted by the majority
+        let seconds = [Some(45), Some(2), Some(3)];
+        assert_eq!(quorum_seconds_since_vote(&seconds, 2), Some(3));
+        assert_eq!(quorum_seconds_since_vote(&seconds, 1), Some(45));
+        // Endpoints that did not answer don't count towards the quorum
+        assert_eq!(
+            quorum_seconds_since_vote(&[Some(45), None, Some(40)], 2),
+            Some(40)
+        );
+        assert_eq!(quorum_seconds_since_vote(&[Some(45), None, None], 2), None);
+        assert_eq!(quorum_seconds_since_vote(&[Some(45)], 0), None);
+
+        let start = Instant::now();
+        let later = start + Duration::from_secs(45);
+        let mut watch = VoteWatch::new(
+            vec![
+                "https://lagging.example/?api-key=secret".to_string(),
+                "https://rpc-a.example".to_string(),
+                "https://rpc-b.example".to_string(),
+            ],
+            2,
+        );
+        for endpoint in &mut watch.endpoints {
+            endpoint.record_votes(Some(100), start);
+        }
+        watch.endpoints[1].record_votes(Some(140), later);
+        watch.endpoints[2].record_votes(Some(141), later);
+
+        let summary = watch.quorum_summary(later, 30);
+        assert_eq!(watch.quorum_seconds_since_vote(later), Some(0));
+        assert!(!summary.is_delinquent());
+        assert!(summary.has_disagreement());
+        assert_eq!(
+            summary.headline(),
+            "1 of 3 RPC endpoints see no new votes (2 needed)"
+        );
+        assert_eq!(
+            summary.endpoints[0],
+            "⚠️ lagging.example: no new vote for 45s (slot 100)"
+        );
+        assert_eq!(summary.endpoints[2], "✅ rpc-b.example: slot 141");
+
+        // A second lagging endpoint reaches the quorum
+        watch.endpoints[2].last_vote_change = Some((141, start));
+        assert_eq!(watch.quorum_seconds_since_vote(later), Some(45));
+        assert!(watch.quorum_summary(later, 30).is_delinquent());
+
+        watch.endpoints[0]
+            .rpc
+            .record_failure("timed out".to_string());
+        let summary = watch.quorum_summary(later, 30);
+        assert_eq!(summary.failing, 1);
+        assert_eq!(summary.endpoints[0], "❌ lagging.example: timed out");
+    }
+
+    #[test]
+    fn test_rpc_endpoints_and_default_quorum() {
+        let pair = |extra: serde_json::Value| -> crate::types::ValidatorPair {
+            let mut value = serde_json::json!({
+                "votePubkey": "Vote111",
+                "identityPubkey": "Funded111",
+                "rpc": "https://rpc-a.example",
+                "nodes": [],
+            });
+            value
+                .as_object_mut()
+                .unwrap()
+                .extend(extra.as_object().unwrap().clone());
+            serde_json::from_value(value).unwrap()
+        };
+
+        let single = pair(serde_json::json!({}));
+        assert_eq!(single.rpc_endpoints(), vec!["https://rpc-a.example"]);
+        assert_eq!(single.rpc_quorum(), 1);
+
+        let three = pair(serde_json::json!({
+            "rpcs": ["https://rpc-b.example", "https://rpc-a.example", "https://rpc-c.example"],
+        }));
+        assert_eq!(three.rpc_endpoints().len(), 3);
+        assert_eq!(three.rpc_quorum(), 2);
+
+        let two = pair(serde_json::json!({ "rpcs": ["https://rpc-b.example"] }));
+        assert_eq!(two.rpc_quorum(), 2);
+        let lenient =
+            pair(serde_json::json!({ "rpcs": ["https://rpc-b.example"], "rpcQuorum": 1 }));
+        assert_eq!(lenient.rpc_quorum(), 1);
+    }
+
+    #[test]
+    fn test_should_fail_over_needs_auto_failover_and_working_vote_rpc() {
+        let config = AlertConfig {
+            enabled: true,
+            delinquency_threshold_seconds: 30,
+            ssh_failure_threshold_seconds: 1800,
+            rpc_failure_threshold_seconds: 1800,
+            telegram: None,
+            auto_failover_enabled: true,
+            auto_failover_policy: Default::default(),
+        };
+        let rpc = FailureTracker::new();
+        assert!(should_fail_over(Some(&config), 40, &rpc));
+        assert!(!should_fail_over(Some(&config), 20, &rpc));
+        assert!(!should_fail_over(None, 40, &rpc));
+
+        let mut failing = FailureTracker::new();
+        failing.record_failure("timeout".to_string());
+        assert!(!should_fail_over(Some(&config), 40, &failing));
+
+        let disabled = AlertConfig {
+            auto_failover_enabled: false,
+            auto_failover_policy: Default::default(),
+            ..config
+        };
+        assert!(!should_fail_over(Some(&disabled), 40, &rpc));
+    }
+
+    #[test]
+    fn test_apply_identities_keeps_unreachable_primary_active() {
+        let mut nodes = vec![
+            node("primary", NodeStatus::Active),
+            node("spare", NodeStatus::Standby),
+        ];
+
+        // The primary went down: it stays the node to take over from
+        let identities = vec![None, Some("Unfunded222".to_string())];
+        assert_eq!(apply_identities(&mut nodes, &identities, "Funded111"), None);
+        assert_eq!(nodes[0].status, NodeStatus::Active);
+        assert_eq!(nodes[1].status, NodeStatus::Standby);
+
+        // The spare took over while the primary is still unreachable
+        let identities = vec![None, Some("Funded111".to_string())];
+        assert_eq!(
+            apply_identities(&mut nodes, &identities, "Funded111"),
+            Some("spare".to_string())
+        );
+        assert_eq!(nodes[0].status, NodeStatus::Unknown);
+        assert_eq!(nodes[1].status, NodeStatus::Active);
+
+        // The old primary is back with its unfunded identity
+        let identities = vec![
+            Some("Unfunded111".to_string()),
+            Some("Funded111".to_string()),
+        ];
+        assert_eq!(apply_identities(&mut nodes, &identities, "Funded111"), None);
+        assert_eq!(nodes[0].status, NodeStatus::Standby);
+        assert_eq!(nodes[0].current_identity.as_deref(), Some("Unfunded111"));
+    }
+}
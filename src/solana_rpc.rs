@@ -1,8 +1,15 @@
 use anyhow::{anyhow, Result};
 use serde::{Deserialize, Serialize};
-use solana_client::rpc_client::RpcClient;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_config::RpcGetVoteAccountsConfig;
 use solana_sdk::pubkey::Pubkey;
+use std::collections::HashMap;
 use std::str::FromStr;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+
+/// Timeout of each RPC request
+const RPC_TIMEOUT: Duration = Duration::from_secs(3);
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VoteAccountInfo {
@@ -32,55 +39,80 @@ pub struct ValidatorVoteData {
     pub is_voting: bool,
 }
 
+/// Shared nonblocking client for `rpc_url`. Clients are kept per URL for the life of
+/// the process, so repeated polls reuse their HTTP connections.
+pub fn rpc_client(rpc_url: &str) -> Arc<RpcClient> {
+    static CLIENTS: OnceLock<Mutex<HashMap<String, Arc<RpcClient>>>> = OnceLock::new();
+
+    let mut clients = CLIENTS
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    Arc::clone(clients.entry(rpc_url.to_string()).or_insert_with(|| {
+        Arc::new(RpcClient::new_with_timeout(
+            rpc_url.to_string(),
+            RPC_TIMEOUT,
+        ))
+    }))
+}
+
+/// Vote account, stake and recent votes of one validator. getVoteAccounts is filtered
+/// to this vote account and runs concurrently with getAccountInfo and getSlot.
 pub async fn fetch_vote_account_data(
     rpc_url: &str,
     vote_pubkey_str: &str,
 ) -> Result<ValidatorVoteData> {
-    use std::time::Duration;
-
     // Validate RPC URL
     if rpc_url.is_empty() {
         return Err(anyhow!("RPC URL is empty"));
     }
 
-    // Log the RPC URL being used (for debugging)
-    // eprintln!("Using RPC URL: {}", rpc_url);
-    // eprintln!("Looking for vote account: {}", vote_pubkey_str);
-
-    let rpc_client = RpcClient::new_with_timeout(rpc_url.to_string(), Duration::from_secs(3));
+    let rpc_client = rpc_client(rpc_url);
     let vote_pubkey =
         Pubkey::from_str(vote_pubkey_str).map_err(|e| anyhow!("Invalid vote pubkey: {}", e))?;
 
-    // Get vote account info
-    let vote_account = rpc_client
-        .get_vote_accounts()
-        .map_err(|e| anyhow!("Failed to get vote accounts: {}", e))?;
+    let (vote_accounts, account_data, current_slot) = tokio::try_join!(
+        async {
+            rpc_client
+                .get_vote_accounts_with_config(RpcGetVoteAccountsConfig {
+                    vote_pubkey: Some(vote_pubkey_str.to_string()),
+                    // A delinquent validator may have lost its stake
+                    keep_unstaked_delinquents: Some(true),
+                    ..Default::default()
+                })
+                .await
+                .map_err(|e| anyhow!("Failed to get vote accounts: {}", e))
+        },
+        async {
+            rpc_client
+                .get_account(&vote_pubkey)
+                .await
+                .map_err(|e| anyhow!("Failed to get vote account data: {}", e))
+        },
+        async {
+            rpc_client
+                .get_slot()
+                .await
+                .map_err(|e| anyhow!("Failed to get current slot: {}", e))
+        },
+    )?;
 
-    // Find our specific vote account in current or delinquent
-    let vote_info = vote_account
+    // Our vote account is either current or delinquent
+    let vote_info = vote_accounts
         .current
         .iter()
-        .chain(vote_account.delinquent.iter())
+        .chain(vote_accounts.delinquent.iter())
         .find(|account| account.vote_pubkey == vote_pubkey_str)
         .ok_or_else(|| {
-            let total_accounts = vote_account.current.len() + vote_account.delinquent.len();
-            anyhow!("Vote account {} not found among {} vote accounts. Make sure the RPC endpoint matches the network (mainnet/testnet/devnet) where this vote account exists.", vote_pubkey_str, total_accounts)
+            anyhow!("Vote account {} not found. Make sure the RPC endpoint matches the network (mainnet/testnet/devnet) where this vote account exists.", vote_pubkey_str)
         })?;
 
-    // Get detailed vote account data
-    let account_data = rpc_client
-        .get_account(&vote_pubkey)
-        .map_err(|e| anyhow!("Failed to get vote account data: {}", e))?;
-
     // Parse vote state from account data
     let vote_state = solana_sdk::vote::state::VoteState::deserialize(&account_data.data)
         .map_err(|e| anyhow!("Failed to deserialize vote state: {}", e))?;
 
     // Get recent votes with latency
     let mut recent_votes = Vec::new();
-    let current_slot = rpc_client
-        .get_slot()
-        .map_err(|e| anyhow!("Failed to get current slot: {}", e))?;
 
     // Get the most recent votes (up to 31 as shown in the example)
     // The votes are stored in order, with most recent at the end
@@ -140,3 +172,19 @@ pub async fn fetch_vote_account_data(
         is_voting,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rpc_clients_are_shared_per_url() {
+        let first = rpc_client("http://localhost:8899");
+        let second = rpc_client("http://localhost:8899");
+        let other = rpc_client("http://localhost:8900");
+
+        assert!(Arc::ptr_eq(&first, &second));
+        assert!(!Arc::ptr_eq(&first, &other));
+        assert_eq!(other.url(), "http://localhost:8900");
+    }
+}
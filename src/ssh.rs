@@ -468,9 +468,44 @@ impl AsyncSshPool {
         ssh_key_path: &str,
         remote_path: &str,
         base64_data: &str,
+    ) -> Result<()> {
+        self.transfer_base64_to_file_with_mode(node, ssh_key_path, remote_path, base64_data, None)
+            .await
+    }
+
+    /// Same as `transfer_base64_to_file`, but with `mode` the file is created with
+    /// those permissions before any data is written, and checked again afterwards
+    pub async fn transfer_base64_to_file_with_mode(
+        &self,
+        node: &NodeConfig,
+        ssh_key_path: &str,
+        remote_path: &str,
+        base64_data: &str,
+        mode: Option<&str>,
     ) -> Result<()> {
         let session = self.get_session(node, ssh_key_path).await?;
 
+        // Create an empty file with the final permissions so the data is never readable
+        // by others, not even for a moment. dd keeps the mode of an existing file.
+        if let Some(mode) = mode {
+            let status = session
+                .command("install")
+                .arg("-m")
+                .arg(mode)
+                .arg("/dev/null")
+                .arg(remote_path)
+                .status()
+                .await
+                .map_err(|e| anyhow!("Failed to spawn install command: {}", e))?;
+            if !status.success() {
+                return Err(anyhow!(
+                    "Could not create {} with mode {}",
+                    remote_path,
+                    mode
+                ));
+            }
+        }
+
         // Start base64 -d on remote, writing to stdout
         let mut base64_child = session
             .command("base64")
@@ -529,6 +564,26 @@ impl AsyncSshPool {
             return Err(anyhow!("dd command failed"));
         }
 
+        if let Some(mode) = mode {
+            let output = session
+                .command("stat")
+                .arg("-c")
+                .arg("%a")
+                .arg(remote_path)
+                .output()
+                .await
+                .map_err(|e| anyhow!("Failed to spawn stat command: {}", e))?;
+            let actual = String::from_utf8_lossy(&output.stdout).trim().to_string();
+            if actual.trim_start_matches('0') != mode.trim_start_matches('0') {
+                return Err(anyhow!(
+                    "{} has mode {} instead of {}",
+                    remote_path,
+                    actual,
+                    mode
+                ));
+            }
+        }
+
         Ok(())
     }
 
@@ -0,0 +1,324 @@
+use std::collections::HashMap;
+
+use crate::ssh::AsyncSshPool;
+use crate::types::{NodeStatus, NodeWithStatus};
+
+/// A standby node considered for activation, ranked by health, slot lag and version
+#[derive(Debug, Clone)]
+pub struct StandbyCandidate {
+    /// Index of the node in `nodes_with_status`
+    pub index: usize,
+    pub label: String,
+    pub healthy: bool,
+    pub version_matches: bool,
+    /// Slots behind the cluster (None if the node's slot could not be read)
+    pub slot_lag: Option<u64>,
+    pub issues: Vec<String>,
+}
+
+impl StandbyCandidate {
+    /// One-line summary for the switch screen
+    pub fn describe(&self) -> String {
+        let lag = match self.slot_lag {
+            Some(lag) => format!("{} slot(s) behind", lag),
+            None => "slot lag unknown".to_string(),
+        };
+        if self.issues.is_empty() {
+            format!("healthy, {}, version matches", lag)
+        } else {
+            format!("{}, {}", lag, self.issues.join(", "))
+        }
+    }
+}
+
+/// Rank every node other than `active_index` as a switch target, best first.
+/// `slot_lags` is indexed like `nodes`; missing entries count as unknown lag.
+pub fn rank_standby_candidates(
+    nodes: &[NodeWithStatus],
+    active_index: Option<usize>,
+    slot_lags: &[Option<u64>],
+) -> Vec<StandbyCandidate> {
+    let active_version = active_index
+        .and_then(|idx| nodes.get(idx))
+        .and_then(|n| n.version.as_deref());
+
+    let mut candidates: Vec<StandbyCandidate> = nodes
+        .iter()
+        .enumerate()
+        .filter(|(idx, n)| Some(*idx) != active_index && n.status != NodeStatus::Active)
+        .map(|(idx, n)| {
+            let mut issues = Vec::new();
+            if n.status == NodeStatus::Unknown {
+                issues.push("unreachable".to_string());
+            }
+            if n.swap_ready == Some(false) {
+                issues.push("not swap-ready".to_string());
+            }
+            if let Some(sync) = n.sync_status.as_deref() {
+                if !sync.starts_with("Caught up") {
+                    issues.push(format!("sync: {}", sync));
+                }
+            }
+            let healthy = issues.is_empty();
+
+            let version_matches = match (active_version, n.version.as_deref()) {
+                (Some(active), Some(version)) => active == version,
+                _ => true,
+            };
+            if !version_matches {
+                issues.push(format!(
+                    "version {} differs from active",
+                    n.version.as_deref().unwrap_or("unknown")
+                ));
+            }
+
+            StandbyCandidate {
+                index: idx,
+                label: n.node.label.clone(),
+                healthy,
+                version_matches,
+                slot_lag: slot_lags.get(idx).copied().flatten(),
+                issues,
+            }
+        })
+        .collect();
+
+    candidates.sort_by_key(|c| {
+        (
+            !c.healthy,
+            !c.version_matches,
+            c.slot_lag.unwrap_or(u64::MAX),
+            c.index,
+        )
+    });
+    candidates
+}
+
+/// Read how far each node trails the cluster slot. Nodes that cannot be queried get None.
+pub async fn fetch_slot_lags(
+    ssh_pool: &AsyncSshPool,
+    nodes: &[NodeWithStatus],
+    detected_ssh_keys: &HashMap<String, String>,
+    rpc_url: &str,
+) -> Vec<Option<u64>> {
+    let node_slots = futures::future::join_all(nodes.iter().map(|n| async move {
+        if n.status == NodeStatus::Unknown {
+            return None;
+        }
+        let ssh_key = detected_ssh_keys.get(&n.node.host)?;
+        let rpc_port = crate::validator_rpc::get_rpc_port(n.validator_type.clone(), None);
+        crate::validator_rpc::get_slot(ssh_pool, &n.node, ssh_key, rpc_port)
+            .await
+            .ok()
+    }))
+    .await;
+
+    let cluster_slot = crate::leader_window::fetch_current_slot(rpc_url).await.ok();
+    node_slots
+        .into_iter()
+        .map(|slot| match (cluster_slot, slot) {
+            (Some(cluster), Some(slot)) => Some(cluster.saturating_sub(slot)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// What the readiness gate found on a standby right before it takes the funded identity
+#[derive(Debug, Clone, Default)]
+pub struct StandbyReadiness {
+    /// Why getHealth did not answer "ok", if it didn't
+    pub health_error: Option<String>,
+    /// Slots behind the cluster (None if either slot could not be read)
+    pub slot_lag: Option<u64>,
+    pub identity: Option<String>,
+    /// Key files and ledger problems found by `check_node_swap_readiness`
+    pub file_issues: Vec<String>,
+}
+
+impl StandbyReadiness {
+    /// Reasons the node can't take over, empty if it is ready
+    pub fn issues(&self, funded_identity: &str, max_slot_lag: u64) -> Vec<String> {
+        let mut issues = Vec::new();
+        if let Some(error) = &self.health_error {
+            issues.push(format!("getHealth: {}", error));
+        }
+        match self.slot_lag {
+            Some(lag) if lag > max_slot_lag => issues.push(format!(
+                "{} slots behind the cluster (max {})",
+                lag, max_slot_lag
+            )),
+            Some(_) => {}
+            None => issues.push("slot lag unknown".to_string()),
+        }
+        match self.identity.as_deref() {
+            Some(identity) if identity == funded_identity => {
+                issues.push("already reports the funded identity".to_string())
+            }
+            Some(_) => {}
+            None => issues.push("identity unknown".to_string()),
+        }
+        issues.extend(self.file_issues.iter().cloned());
+        issues
+    }
+}
+
+/// Ask a standby over SSH for its health, slot and identity and check its key files.
+/// The funded keypair is not required on a keyless standby.
+pub async fn check_standby_readiness(
+    ssh_pool: &AsyncSshPool,
+    node: &NodeWithStatus,
+    ssh_key: &str,
+    rpc_url: &str,
+    keyless_standby: bool,
+) -> StandbyReadiness {
+    let rpc_port = crate::validator_rpc::get_rpc_port(node.validator_type.clone(), None);
+    let (health, slot, cluster_slot, identity, (_, file_issues)) = tokio::join!(
+        crate::validator_rpc::get_health(ssh_pool, &node.node, ssh_key, rpc_port),
+        crate::validator_rpc::get_slot(ssh_pool, &node.node, ssh_key, rpc_port),
+        crate::leader_window::fetch_current_slot(rpc_url),
+        crate::validator_rpc::get_identity(ssh_pool, &node.node, ssh_key, rpc_port),
+        crate::startup::check_node_swap_readiness(
+            ssh_pool,
+            &node.node,
+            ssh_key,
+            node.ledger_path.as_ref(),
+            Some(true),
+            !keyless_standby,
+        ),
+    );
+
+    StandbyReadiness {
+        health_error: match health {
+            Ok(true) => None,
+            Ok(false) => Some("not healthy".to_string()),
+            Err(e) => Some(e.to_string()),
+        },
+        slot_lag: match (cluster_slot, slot) {
+            (Ok(cluster), Ok(slot)) => Some(cluster.saturating_sub(slot)),
+            _ => None,
+        },
+        identity: identity.ok(),
+        file_issues,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{NodeConfig, NodePaths, ValidatorType};
+
+    fn node(label: &str, status: NodeStatus, version: &str) -> NodeWithStatus {
+        NodeWithStatus {
+            node: NodeConfig {
+                label: label.to_string(),
+                host: format!("{}.example", label),
+                port: 22,
+                user: "solana".to_string(),
+                paths: NodePaths {
+                    funded_identity: "/keys/funded.json".to_string(),
+                    unfunded_identity: "/keys/unfunded.json".to_string(),
+                    vote_keypair: "/keys/vote.json".to_string(),
+                },
+                ssh_key_path: None,
+            },
+            status,
+            validator_type: ValidatorType::Agave,
+            agave_validator_executable: None,
+            fdctl_executable: None,
+            solana_cli_executable: None,
+            version: Some(version.to_string()),
+            sync_status: Some("Caught up".to_string()),
+            current_identity: None,
+            ledger_path: None,
+            tower_path: None,
+            swap_ready: Some(true),
+            swap_issues: Vec::new(),
+            ssh_key_path: None,
+        }
+    }
+
+    #[test]
+    fn test_lowest_lag_wins_among_healthy_standbys() {
+        let nodes = vec![
+            node("primary", NodeStatus::Active, "Agave 2.0.1"),
+            node("spare-a", NodeStatus::Standby, "Agave 2.0.1"),
+            node("spare-b", NodeStatus::Standby, "Agave 2.0.1"),
+        ];
+        let ranked = rank_standby_candidates(&nodes, Some(0), &[Some(0), Some(12), Some(2)]);
+
+        let labels: Vec<_> = ranked.iter().map(|c| c.label.as_str()).collect();
+        assert_eq!(labels, vec!["spare-b", "spare-a"]);
+    }
+
+    #[test]
+    fn test_unhealthy_and_mismatched_versions_rank_last() {
+        let mut not_ready = node("not-ready", NodeStatus::Standby, "Agave 2.0.1");
+        not_ready.swap_ready = Some(false);
+        let nodes = vec![
+            node("primary", NodeStatus::Active, "Agave 2.0.1"),
+            not_ready,
+            node("old", NodeStatus::Standby, "Agave 1.18.22"),
+            node("unreachable", NodeStatus::Unknown, "Agave 2.0.1"),
+            node("good", NodeStatus::Standby, "Agave 2.0.1"),
+        ];
+        let ranked =
+            rank_standby_candidates(&nodes, Some(0), &[None, Some(0), Some(0), None, Some(40)]);
+
+        let labels: Vec<_> = ranked.iter().map(|c| c.label.as_str()).collect();
+        assert_eq!(labels, vec!["good", "old", "not-ready", "unreachable"]);
+        assert!(ranked[0].issues.is_empty());
+        assert!(!ranked[1].version_matches);
+    }
+
+    #[test]
+    fn test_recovery_mode_ranks_all_but_the_kept_node() {
+        let nodes = vec![
+            node("a", NodeStatus::Standby, "Agave 2.0.1"),
+            node("b", NodeStatus::Standby, "Agave 2.0.1"),
+        ];
+        let ranked = rank_standby_candidates(&nodes, Some(0), &[]);
+
+        assert_eq!(ranked.len(), 1);
+        assert_eq!(ranked[0].index, 1);
+        assert_eq!(ranked[0].slot_lag, None);
+    }
+
+    #[test]
+    fn test_readiness_issues() {
+        let ready = StandbyReadiness {
+            health_error: None,
+            slot_lag: Some(3),
+            identity: Some("Unfunded111".to_string()),
+            file_issues: Vec::new(),
+        };
+        assert!(ready.issues("Funded111", 50).is_empty());
+
+        let lagging = StandbyReadiness {
+            slot_lag: Some(120),
+            identity: Some("Funded111".to_string()),
+            file_issues: vec!["Vote keypair missing or not readable".to_string()],
+            ..ready
+        };
+        assert_eq!(
+            lagging.issues("Funded111", 50),
+            vec![
+                "120 slots behind the cluster (max 50)",
+                "already reports the funded identity",
+                "Vote keypair missing or not readable",
+            ]
+        );
+
+        let unreachable = StandbyReadiness {
+            health_error: Some("Failed to execute RPC call: timeout".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            unreachable.issues("Funded111", 50),
+            vec![
+                "getHealth: Failed to execute RPC call: timeout",
+                "slot lag unknown",
+                "identity unknown",
+            ]
+        );
+    }
+}
@@ -242,7 +242,7 @@ pub async fn run_startup_checklist_with_config(config_path: Option<String>) -> R
             let metadata_cache =
                 Arc::new(Mutex::new(crate::validator_metadata::MetadataCache::new()));
 
-            let app_state = crate::AppState {
+            let mut app_state = crate::AppState {
                 ssh_pool: Arc::new(ssh_pool),
                 config,
                 validator_statuses,
@@ -253,6 +253,16 @@ pub async fn run_startup_checklist_with_config(config_path: Option<String>) -> R
 
             // Auto-failover safety checks are now done per-validator during status detection
 
+            // A switch interrupted by a crash or Ctrl-C is finished before anything else.
+            // The headless monitor can't prompt, it logs the switch as a warning instead.
+            if !std::env::args().any(|arg| arg == "monitor") {
+                if let Err(e) =
+                    crate::commands::switch::handle_interrupted_switches(&mut app_state).await
+                {
+                    println!("⚠️  Could not check for interrupted switches: {}", e);
+                }
+            }
+
             // Show "press any key to continue" prompt after all checks pass
             show_ready_prompt().await;
 
@@ -881,6 +891,19 @@ fn validate_config_completeness(config: &Config) -> Vec<String> {
         if validator_pair.rpc.is_empty() {
             issues.push(format!("{} RPC endpoint is empty", validator_name));
         }
+        if validator_pair.rpcs.iter().any(|rpc| rpc.is_empty()) {
+            issues.push(format!("{} has an empty entry in rpcs", validator_name));
+        }
+        let endpoint_count = validator_pair.rpc_endpoints().len();
+        if validator_pair
+            .rpc_quorum
+            .is_some_and(|quorum| quorum == 0 || quorum > endpoint_count)
+        {
+            issues.push(format!(
+                "{} rpcQuorum must be between 1 and {} (its number of RPC endpoints)",
+                validator_name, endpoint_count
+            ));
+        }
 
         // Check nodes - allow 1 or 2 nodes
         if validator_pair.nodes.is_empty() {
@@ -895,6 +918,40 @@ fn validate_config_completeness(config: &Config) -> Vec<String> {
         }
     }
 
+    if let Some(alert_config) = &config.alert_config {
+        let policy = &alert_config.auto_failover_policy;
+        if policy.max_failovers == Some(0) {
+            issues.push(
+                "auto_failover_policy.max_failovers must be at least 1 (set auto_failover_enabled to false instead)"
+                    .to_string(),
+            );
+        }
+        if policy.window_minutes == 0 {
+            issues.push("auto_failover_policy.window_minutes must be at least 1".to_string());
+        }
+        for window in &policy.blackout_windows {
+            if let Err(e) = window.times().and_then(|_| window.weekdays()) {
+                issues.push(format!(
+                    "auto_failover_policy blackout window {}: {}",
+                    window.describe(),
+                    e
+                ));
+            }
+        }
+
+        // Automatic failover usually runs because the old active is unreachable, so it
+        // can't be where a keyless standby gets the funded keypair from
+        let keyless_without_source = config.switch_policy.as_ref().is_some_and(|switch_policy| {
+            switch_policy.keyless_standby && switch_policy.funded_keypair_source.is_none()
+        });
+        if alert_config.enabled && alert_config.auto_failover_enabled && keyless_without_source {
+            issues.push(
+                "switch_policy.keyless_standby with auto-failover enabled requires switch_policy.funded_keypair_source"
+                    .to_string(),
+            );
+        }
+    }
+
     issues
 }
 
@@ -1104,8 +1161,8 @@ async fn show_ready_prompt() {
     // Flush stdout to ensure the prompt appears immediately
     io::stdout().flush().unwrap();
 
-    // Skip wait for status command
-    if std::env::args().any(|arg| arg == "status") {
+    // Skip wait for the status command and the headless monitor
+    if std::env::args().any(|arg| arg == "status" || arg == "monitor") {
         // For status command, just clear everything
         print!("\x1B[2J\x1B[1;1H"); // Clear entire screen and move to top
         io::stdout().flush().unwrap();
@@ -1156,9 +1213,10 @@ pub async fn detect_node_statuses(
             ) = detect_node_status_and_executable(node, validator_pair, ssh_pool).await?;
             // Derive tower path from ledger path and validator pair identity
             let tower_path = ledger_path.as_ref().map(|ledger| {
-                format!(
-                    "{}/tower-1_9-{}.bin",
-                    ledger, validator_pair.identity_pubkey
+                crate::validator_backend::tower_path(
+                    &validator_type,
+                    ledger,
+                    &validator_pair.identity_pubkey,
                 )
             });
 
@@ -1249,9 +1307,10 @@ async fn detect_node_statuses_with_progress(
 
             // Derive tower path from ledger path and validator pair identity
             let tower_path = ledger_path.as_ref().map(|ledger| {
-                format!(
-                    "{}/tower-1_9-{}.bin",
-                    ledger, validator_pair.identity_pubkey
+                crate::validator_backend::tower_path(
+                    &validator_type,
+                    ledger,
+                    &validator_pair.identity_pubkey,
                 )
             });
 
@@ -1469,6 +1528,27 @@ async fn detect_node_statuses_with_progress(
     Ok(validator_statuses)
 }
 
+/// Ask the detected client for its version. Agave builds may turn out to be Jito.
+async fn detect_version(
+    ssh_pool: &AsyncSshPool,
+    node: &crate::types::NodeConfig,
+    ssh_key: &str,
+    validator_type: &mut crate::types::ValidatorType,
+    agave_validator_executable: Option<&str>,
+    fdctl_executable: Option<&str>,
+) -> Option<String> {
+    let backend = crate::validator_backend::backend_for(validator_type)?;
+    let executable = backend.main_executable(agave_validator_executable, fdctl_executable)?;
+    let version_output = ssh_pool
+        .execute_command(node, ssh_key, &backend.version_command(executable))
+        .await
+        .ok()?;
+
+    let (detected_type, version) = backend.parse_version(&version_output);
+    *validator_type = detected_type;
+    version
+}
+
 #[allow(dead_code)]
 async fn detect_node_status_and_executable(
     node: &crate::types::NodeConfig,
@@ -1513,7 +1593,6 @@ async fn detect_node_status_and_executable(
     let mut fdctl_executable = None;
     let mut solana_cli_executable = None;
     let mut _main_validator_executable = None;
-    let mut version = None;
     let sync_status;
     let mut current_identity = None;
     let mut ledger_path = None;
@@ -1598,65 +1677,15 @@ async fn detect_node_status_and_executable(
     }
 
     // Detect version based on validator type
-    if validator_type == crate::types::ValidatorType::Firedancer {
-        // For Firedancer, use fdctl executable
-        if let Some(ref fdctl_exec) = fdctl_executable {
-            if let Ok(version_output) = ssh_pool
-                .execute_command(
-                    node,
-                    &ssh_key,
-                    &format!("timeout 5 {} --version 2>/dev/null", fdctl_exec),
-                )
-                .await
-            {
-                // Parse fdctl version output - first part is version, second is git hash
-                if let Some(line) = version_output.lines().next() {
-                    if let Some(version_match) = line.split_whitespace().next() {
-                        version = Some(format!("Firedancer {}", version_match));
-                    }
-                }
-            }
-        }
-    } else if let Some(ref agave_exec) = agave_validator_executable {
-        // For Agave/Jito, use agave-validator executable
-        if let Ok(version_output) = ssh_pool
-            .execute_command(
-                node,
-                &ssh_key,
-                &format!("timeout 5 {} --version 2>/dev/null", agave_exec),
-            )
-            .await
-        {
-            if let Some(line) = version_output.lines().next() {
-                // Handle both agave-validator and solana-cli output formats
-                if line.starts_with("agave-validator ") || line.starts_with("solana-cli ") {
-                    let parts: Vec<&str> = line.split_whitespace().collect();
-                    if parts.len() >= 2 {
-                        let version_num = parts[1];
-                        if line.contains("client:Firedancer") {
-                            version = Some(format!("Firedancer {}", version_num));
-                        } else if line.contains("client:Agave") {
-                            version = Some(format!("Agave {}", version_num));
-                        } else if version_num.starts_with("0.") {
-                            version = Some(format!("Firedancer {}", version_num));
-                        } else if version_num.starts_with("2.") {
-                            version = Some(format!("Agave {}", version_num));
-                        }
-                    }
-                }
-                // Check if it's Jito by looking for "jito" in the version output
-                if validator_type == crate::types::ValidatorType::Agave
-                    && version_output.to_lowercase().contains("jito")
-                {
-                    validator_type = crate::types::ValidatorType::Jito;
-                    // Update version string to indicate Jito
-                    if let Some(ref mut v) = version {
-                        *v = v.replace("Agave", "Jito");
-                    }
-                }
-            }
-        }
-    }
+    let version = detect_version(
+        ssh_pool,
+        node,
+        &ssh_key,
+        &mut validator_type,
+        agave_validator_executable.as_deref(),
+        fdctl_executable.as_deref(),
+    )
+    .await;
 
     // Detect sync status using RPC calls
     // We need to get the full command line from the ps output to extract RPC port
@@ -1844,7 +1873,8 @@ async fn detect_node_status_and_executable(
 /// Check if a node is ready for validator switching
 ///
 /// This function checks:
-/// - Funded identity keypair (readable)
+/// - Funded identity keypair (readable, unless `funded_keypair_required` is false,
+///   as for standbys in keyless standby mode)
 /// - Unfunded identity keypair (readable)
 /// - Vote keypair (readable)
 /// - Ledger directory (exists and writable)
@@ -1859,6 +1889,7 @@ pub async fn check_node_swap_readiness(
     ssh_key_path: &str,
     ledger_path: Option<&String>,
     is_standby: Option<bool>,
+    funded_keypair_required: bool,
 ) -> (bool, Vec<String>) {
     let mut issues = Vec::new();
     let mut all_ready = true;
@@ -1905,7 +1936,7 @@ pub async fn check_node_swap_readiness(
         Ok(output) => {
             for line in output.lines() {
                 match line.trim() {
-                    "funded_fail" => {
+                    "funded_fail" if funded_keypair_required => {
                         issues.push("Funded identity keypair missing or not readable".to_string());
                         all_ready = false;
                     }
@@ -2005,7 +2036,6 @@ async fn detect_node_status_and_executable_with_progress(
     let mut fdctl_executable = None;
     let mut solana_cli_executable = None;
     let mut _main_validator_executable = None;
-    let mut version = None;
     let sync_status;
     let mut current_identity = None;
     let mut ledger_path = None;
@@ -2171,12 +2201,7 @@ async fn detect_node_status_and_executable_with_progress(
         }
     }
 
-    let validator_type_name = match validator_type {
-        crate::types::ValidatorType::Firedancer => "Firedancer",
-        crate::types::ValidatorType::Agave => "Agave",
-        crate::types::ValidatorType::Jito => "Jito",
-        crate::types::ValidatorType::Unknown => "Unknown",
-    };
+    let validator_type_name = crate::validator_backend::validator_type_name(&validator_type);
 
     if validator_type == crate::types::ValidatorType::Unknown {
         logger.log_warning("No validator executable detected")?;
@@ -2202,25 +2227,19 @@ async fn detect_node_status_and_executable_with_progress(
             });
 
             // Read the config file and extract ledger path
-            let cat_cmd = format!(
-                "cat {} 2>/dev/null | grep -A 5 '\\[ledger\\]' | grep 'path' | head -1",
-                config_path
-            );
-            if let Ok(config_output) = ssh_pool.execute_command(node, &ssh_key, &cat_cmd).await {
-                // Parse something like: path = "/mnt/solana_ledger"
-                for line in config_output.lines() {
-                    if line.contains("path") && line.contains("=") {
-                        let parts: Vec<&str> = line.split('=').collect();
-                        if parts.len() >= 2 {
-                            let path = parts[1].trim().trim_matches('"').trim_matches('\'');
-                            if !path.is_empty() {
-                                ledger_path = Some(path.to_string());
-                                progress_bar.suspend(|| {
-                                    println!("      ✅ Ledger path: {}", path.bright_cyan());
-                                });
-                                break;
-                            }
-                        }
+            let backend = crate::validator_backend::backend_for(&validator_type);
+            if let Some((backend, cat_cmd)) = backend.and_then(|backend| {
+                backend
+                    .ledger_path_config_command(config_path)
+                    .map(|cmd| (backend, cmd))
+            }) {
+                if let Ok(config_output) = ssh_pool.execute_command(node, &ssh_key, &cat_cmd).await
+                {
+                    if let Some(path) = backend.parse_ledger_path_config(&config_output) {
+                        progress_bar.suspend(|| {
+                            println!("      ✅ Ledger path: {}", path.bright_cyan());
+                        });
+                        ledger_path = Some(path);
                     }
                 }
             }
@@ -2234,65 +2253,15 @@ async fn detect_node_status_and_executable_with_progress(
     logger.log("Detecting validator version...")?;
 
     // Detect version based on validator type
-    if validator_type == crate::types::ValidatorType::Firedancer {
-        // For Firedancer, use fdctl executable
-        if let Some(ref fdctl_exec) = fdctl_executable {
-            if let Ok(version_output) = ssh_pool
-                .execute_command(
-                    node,
-                    &ssh_key,
-                    &format!("timeout 5 {} --version 2>/dev/null", fdctl_exec),
-                )
-                .await
-            {
-                // Parse fdctl version output - first part is version, second is git hash
-                if let Some(line) = version_output.lines().next() {
-                    if let Some(version_match) = line.split_whitespace().next() {
-                        version = Some(format!("Firedancer {}", version_match));
-                    }
-                }
-            }
-        }
-    } else if let Some(ref agave_exec) = agave_validator_executable {
-        // For Agave/Jito, use agave-validator executable
-        if let Ok(version_output) = ssh_pool
-            .execute_command(
-                node,
-                &ssh_key,
-                &format!("timeout 5 {} --version 2>/dev/null", agave_exec),
-            )
-            .await
-        {
-            if let Some(line) = version_output.lines().next() {
-                // Handle both agave-validator and solana-cli output formats
-                if line.starts_with("agave-validator ") || line.starts_with("solana-cli ") {
-                    let parts: Vec<&str> = line.split_whitespace().collect();
-                    if parts.len() >= 2 {
-                        let version_num = parts[1];
-                        if line.contains("client:Firedancer") {
-                            version = Some(format!("Firedancer {}", version_num));
-                        } else if line.contains("client:Agave") {
-                            version = Some(format!("Agave {}", version_num));
-                        } else if version_num.starts_with("0.") {
-                            version = Some(format!("Firedancer {}", version_num));
-                        } else if version_num.starts_with("2.") {
-                            version = Some(format!("Agave {}", version_num));
-                        }
-                    }
-                }
-                // Check if it's Jito by looking for "jito" in the version output
-                if validator_type == crate::types::ValidatorType::Agave
-                    && version_output.to_lowercase().contains("jito")
-                {
-                    validator_type = crate::types::ValidatorType::Jito;
-                    // Update version string to indicate Jito
-                    if let Some(ref mut v) = version {
-                        *v = v.replace("Agave", "Jito");
-                    }
-                }
-            }
-        }
-    }
+    let version = detect_version(
+        ssh_pool,
+        node,
+        &ssh_key,
+        &mut validator_type,
+        agave_validator_executable.as_deref(),
+        fdctl_executable.as_deref(),
+    )
+    .await;
 
     if let Some(ref v) = version {
         logger.log(&format!("Version detected: {}", v))?;
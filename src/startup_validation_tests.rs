@@ -49,6 +49,8 @@ mod startup_validation_tests {
             vote_pubkey: format!("vote_pubkey_{}", index),
             identity_pubkey: format!("identity_pubkey_{}", index),
             rpc: format!("http://rpc{}.example.com:8899", index),
+            rpcs: Vec::new(),
+            rpc_quorum: None,
             nodes: vec![
                 NodeConfig {
                     label: format!("node-{}-1", index),
@@ -14,6 +14,7 @@ mod status_ui_alert_tests {
             rpc_failure_threshold_seconds: 1800, // 30 minutes
             telegram: None,
             auto_failover_enabled: true,
+            auto_failover_policy: Default::default(),
         };
 
         // The CORRECT logic for auto-failover:
@@ -174,6 +175,7 @@ mod status_ui_alert_tests {
             rpc_failure_threshold_seconds: 1800, // 30 minutes - VERY LOOSE
             telegram: None,
             auto_failover_enabled: false,
+            auto_failover_policy: Default::default(),
         };
 
         let mut ssh_tracker = FailureTracker::new();
@@ -0,0 +1,326 @@
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+
+use crate::commands::switch::shell_quote;
+use crate::ssh::AsyncSshPool;
+use crate::switch_journal::{JournalNode, StepDurations, SwitchRecord, SwitchTrigger};
+use crate::types::{HookConfig, HookEvent, NodeConfig, ValidatorPair};
+
+/// Marks the exit status of a remote hook in its output
+const EXIT_MARKER: &str = "svs-hook-exit:";
+
+/// What a hook is told about the switch
+#[derive(Debug, Clone, Serialize)]
+pub struct HookContext {
+    pub event: HookEvent,
+    pub trigger: SwitchTrigger,
+    pub validator_identity: String,
+    pub vote_pubkey: String,
+    pub old_active: JournalNode,
+    pub new_active: JournalNode,
+    pub timings: StepDurations,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl HookContext {
+    pub fn new(event: HookEvent, record: &SwitchRecord, error: Option<String>) -> Self {
+        Self {
+            event,
+            trigger: record.trigger,
+            validator_identity: record.validator_identity.clone(),
+            vote_pubkey: record.vote_pubkey.clone(),
+            old_active: record.from_node.clone(),
+            new_active: record.to_node.clone(),
+            timings: record.steps.clone(),
+            error,
+        }
+    }
+
+    /// The context as `SVS_*` environment variables
+    pub fn env(&self) -> Vec<(&'static str, String)> {
+        let ms = |v: Option<u64>| v.map(|v| v.to_string()).unwrap_or_default();
+        let mut env = vec![
+            ("SVS_HOOK_EVENT", self.event.as_str().to_string()),
+            ("SVS_TRIGGER", self.trigger.as_str().to_string()),
+            ("SVS_VALIDATOR_IDENTITY", self.validator_identity.clone()),
+            ("SVS_VOTE_PUBKEY", self.vote_pubkey.clone()),
+            ("SVS_OLD_ACTIVE", self.old_active.label.clone()),
+            ("SVS_OLD_ACTIVE_HOST", self.old_active.host.clone()),
+            ("SVS_NEW_ACTIVE", self.new_active.label.clone()),
+            ("SVS_NEW_ACTIVE_HOST", self.new_active.host.clone()),
+            (
+                "SVS_ACTIVE_TO_UNFUNDED_MS",
+                ms(self.timings.active_to_unfunded_ms),
+            ),
+            ("SVS_TOWER_TRANSFER_MS", ms(self.timings.tower_transfer_ms)),
+            (
+                "SVS_STANDBY_TO_FUNDED_MS",
+                ms(self.timings.standby_to_funded_ms),
+            ),
+        ];
+        if let Some(error) = &self.error {
+            env.push(("SVS_ERROR", error.clone()));
+        }
+        env
+    }
+}
+
+/// A hook that failed or timed out
+#[derive(Debug, Clone)]
+pub struct HookFailure {
+    pub name: String,
+    /// Failed pre_switch hook with `veto` set
+    pub vetoes: bool,
+    pub error: String,
+}
+
+impl HookFailure {
+    pub fn describe(&self) -> String {
+        format!("hook '{}' failed: {}", self.name, self.error)
+    }
+}
+
+/// Hooks configured for `event`, in config order
+pub fn hooks_for(hooks: &[HookConfig], event: HookEvent) -> Vec<&HookConfig> {
+    hooks.iter().filter(|hook| hook.event == event).collect()
+}
+
+/// Run the hooks of `context.event` one after another and return those that failed
+pub async fn run_hooks(
+    hooks: &[HookConfig],
+    context: &HookContext,
+    validator_pair: &ValidatorPair,
+    ssh_pool: &AsyncSshPool,
+    detected_ssh_keys: &HashMap<String, String>,
+) -> Vec<HookFailure> {
+    let mut failures = Vec::new();
+    for hook in hooks_for(hooks, context.event) {
+        let run = async {
+            match &hook.node {
+                Some(node) => {
+                    let node = resolve_node(node, validator_pair, context)?;
+                    run_remote(hook, node, context, ssh_pool, detected_ssh_keys).await
+                }
+                None => run_local(hook, context).await,
+            }
+        };
+        let result = tokio::time::timeout(Duration::from_secs(hook.timeout_seconds), run)
+            .await
+            .unwrap_or_else(|_| Err(anyhow!("timed out after {}s", hook.timeout_seconds)));
+        if let Err(e) = result {
+            failures.push(HookFailure {
+                name: hook.name.clone(),
+                vetoes: context.event == HookEvent::PreSwitch && hook.veto,
+                error: e.to_string(),
+            });
+        }
+    }
+    failures
+}
+
+/// Node of the validator a remote hook runs on
+fn resolve_node<'a>(
+    node: &str,
+    validator_pair: &'a ValidatorPair,
+    context: &HookContext,
+) -> Result<&'a NodeConfig> {
+    let label = match node {
+        "old_active" => &context.old_active.label,
+        "new_active" => &context.new_active.label,
+        label => label,
+    };
+    validator_pair
+        .nodes
+        .iter()
+        .find(|n| n.label == label)
+        .ok_or_else(|| anyhow!("no node labelled '{}'", label))
+}
+
+async fn run_local(hook: &HookConfig, context: &HookContext) -> Result<()> {
+    let mut child = tokio::process::Command::new("sh")
+        .arg("-c")
+        .arg(&hook.command)
+        .envs(context.env())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(|e| anyhow!("failed to run: {}", e))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // A hook that ignores stdin may exit before reading it
+        let _ = stdin
+            .write_all(serde_json::to_string(context)?.as_bytes())
+            .await;
+    }
+    let output = child.wait_with_output().await?;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "exited with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(())
+}
+
+/// Remote shell command running the hook with the context in its environment and on
+/// stdin, followed by its exit status
+fn remote_command(command: &str, context: &HookContext) -> Result<String> {
+    let exports = context
+        .env()
+        .iter()
+        .map(|(name, value)| format!("export {}={};", name, shell_quote(value)))
+        .collect::<Vec<_>>()
+        .join(" ");
+    Ok(format!(
+        "{} printf '%s' {} | bash -c {} 2>&1; echo \"{}$?\"",
+        exports,
+        shell_quote(&serde_json::to_string(context)?),
+        shell_quote(command),
+        EXIT_MARKER
+    ))
+}
+
+/// Output of a remote hook and its exit status, if it got that far
+fn parse_remote_output(output: &str) -> (String, Option<i32>) {
+    match output.rfind(EXIT_MARKER) {
+        Some(pos) => (
+            output[..pos].trim().to_string(),
+            output[pos + EXIT_MARKER.len()..].trim().parse().ok(),
+        ),
+        None => (output.trim().to_string(), None),
+    }
+}
+
+async fn run_remote(
+    hook: &HookConfig,
+    node: &NodeConfig,
+    context: &HookContext,
+    ssh_pool: &AsyncSshPool,
+    detected_ssh_keys: &HashMap<String, String>,
+) -> Result<()> {
+    let ssh_key = detected_ssh_keys
+        .get(&node.host)
+        .ok_or_else(|| anyhow!("no SSH key detected for {}", node.label))?;
+    let output = ssh_pool
+        .execute_command(node, ssh_key, &remote_command(&hook.command, context)?)
+        .await?;
+
+    match parse_remote_output(&output) {
+        (_, Some(0)) => Ok(()),
+        (output, Some(code)) => Err(anyhow!(
+            "exited with {} on {}: {}",
+            code,
+            node.label,
+            output
+        )),
+        (output, None) => Err(anyhow!("no exit status from {}: {}", node.label, output)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context(event: HookEvent) -> HookContext {
+        let node = |label: &str, host: &str| JournalNode {
+            label: label.to_string(),
+            host: host.to_string(),
+            validator_type: "Agave".to_string(),
+            version: None,
+        };
+        HookContext {
+            event,
+            trigger: SwitchTrigger::Manual,
+            validator_identity: "Ident111".to_string(),
+            vote_pubkey: "Vote111".to_string(),
+            old_active: node("primary", "10.0.0.1"),
+            new_active: node("spare", "10.0.0.2"),
+            timings: StepDurations {
+                active_to_unfunded_ms: Some(120),
+                ..Default::default()
+            },
+            error: None,
+        }
+    }
+
+    fn hook(name: &str, event: HookEvent, command: &str) -> HookConfig {
+        serde_json::from_value(serde_json::json!({
+            "name": name,
+            "event": event.as_str(),
+            "command": command,
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_local_hooks_get_context_and_can_veto() {
+        let pair = ValidatorPair {
+            vote_pubkey: "Vote111".to_string(),
+            identity_pubkey: "Ident111".to_string(),
+            rpc: "http://localhost:8899".to_string(),
+            rpcs: Vec::new(),
+            rpc_quorum: None,
+            nodes: Vec::new(),
+        };
+        let hooks = vec![
+            hook(
+                "dns",
+                HookEvent::PreSwitch,
+                "test \"$SVS_NEW_ACTIVE_HOST\" = 10.0.0.2 && grep -q '\"old_active\"' && test \"$SVS_ACTIVE_TO_UNFUNDED_MS\" = 120",
+            ),
+            hook("relay", HookEvent::PreSwitch, "echo relay down >&2; exit 2"),
+            hook("notify", HookEvent::OnFailure, "exit 1"),
+        ];
+        assert!(hooks[0].veto);
+        assert_eq!(hooks[0].timeout_seconds, 30);
+
+        let failures = run_hooks(
+            &hooks,
+            &context(HookEvent::PreSwitch),
+            &pair,
+            &AsyncSshPool::new(),
+            &HashMap::new(),
+        )
+        .await;
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].name, "relay");
+        assert!(failures[0].vetoes);
+        assert!(failures[0].error.contains("relay down"));
+    }
+
+    #[tokio::test]
+    async fn test_remote_command_reports_exit_status() {
+        let context = context(HookEvent::AfterActivation);
+        let run = |command: String| async move {
+            let output = tokio::process::Command::new("bash")
+                .arg("-c")
+                .arg(command)
+                .output()
+                .await
+                .unwrap();
+            parse_remote_output(&String::from_utf8_lossy(&output.stdout))
+        };
+
+        let ok = remote_command("read -r json; echo \"$SVS_OLD_ACTIVE $json\"", &context).unwrap();
+        let (output, code) = run(ok).await;
+        assert_eq!(code, Some(0));
+        assert!(output.starts_with("primary {\"event\":\"after_activation\""));
+
+        let (output, code) =
+            run(remote_command("echo \"it's broken\"; exit 3", &context).unwrap()).await;
+        assert_eq!(code, Some(3));
+        assert_eq!(output, "it's broken");
+        assert_eq!(
+            parse_remote_output("garbage"),
+            ("garbage".to_string(), None)
+        );
+    }
+}
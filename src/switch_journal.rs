@@ -0,0 +1,407 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::types::NodeWithStatus;
+
+const JOURNAL_FILE_NAME: &str = "switch_history.jsonl";
+
+/// What started a switch
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SwitchTrigger {
+    Manual,
+    Tui,
+    AutoFailover,
+}
+
+impl SwitchTrigger {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SwitchTrigger::Manual => "manual",
+            SwitchTrigger::Tui => "tui",
+            SwitchTrigger::AutoFailover => "auto_failover",
+        }
+    }
+}
+
+impl std::str::FromStr for SwitchTrigger {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "manual" => Ok(SwitchTrigger::Manual),
+            "tui" => Ok(SwitchTrigger::Tui),
+            "auto_failover" | "auto-failover" | "auto" => Ok(SwitchTrigger::AutoFailover),
+            _ => Err(anyhow!(
+                "Unknown trigger '{}' (expected manual, tui or auto_failover)",
+                s
+            )),
+        }
+    }
+}
+
+/// Node as it was at the time of the switch
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JournalNode {
+    pub label: String,
+    pub host: String,
+    pub validator_type: String,
+    pub version: Option<String>,
+}
+
+impl JournalNode {
+    pub fn from_node(node_with_status: &NodeWithStatus) -> Self {
+        Self {
+            label: node_with_status.node.label.clone(),
+            host: node_with_status.node.host.clone(),
+            validator_type: format!("{:?}", node_with_status.validator_type),
+            version: node_with_status.version.clone(),
+        }
+    }
+}
+
+/// Per-step durations in milliseconds (None if the step did not run)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StepDurations {
+    pub active_to_unfunded_ms: Option<u64>,
+    pub tower_transfer_ms: Option<u64>,
+    pub standby_to_funded_ms: Option<u64>,
+    pub identity_switch_ms: Option<u64>,
+    pub first_vote_ms: Option<u64>,
+    pub total_ms: Option<u64>,
+}
+
+pub fn duration_ms(duration: Option<Duration>) -> Option<u64> {
+    duration.map(|d| d.as_millis() as u64)
+}
+
+/// One switch attempt as stored in the journal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SwitchRecord {
+    pub timestamp: String,
+    pub trigger: SwitchTrigger,
+    pub dry_run: bool,
+    pub success: bool,
+    pub validator_identity: String,
+    pub vote_pubkey: String,
+    pub operator_host: String,
+    pub from_node: JournalNode,
+    pub to_node: JournalNode,
+    pub steps: StepDurations,
+    #[serde(default)]
+    pub errors: Vec<String>,
+}
+
+impl SwitchRecord {
+    pub fn new(
+        trigger: SwitchTrigger,
+        dry_run: bool,
+        validator_pair: &crate::types::ValidatorPair,
+        from_node: &NodeWithStatus,
+        to_node: &NodeWithStatus,
+    ) -> Self {
+        Self {
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            trigger,
+            dry_run,
+            success: false,
+            validator_identity: validator_pair.identity_pubkey.clone(),
+            vote_pubkey: validator_pair.vote_pubkey.clone(),
+            operator_host: operator_host(),
+            from_node: JournalNode::from_node(from_node),
+            to_node: JournalNode::from_node(to_node),
+            steps: StepDurations::default(),
+            errors: Vec::new(),
+        }
+    }
+}
+
+/// Filters for listing journal records
+#[derive(Debug, Default)]
+pub struct HistoryFilter {
+    pub validator: Option<String>,
+    pub trigger: Option<SwitchTrigger>,
+    pub since: Option<chrono::DateTime<chrono::Utc>>,
+    pub failed_only: bool,
+    pub include_dry_runs: bool,
+}
+
+impl HistoryFilter {
+    pub fn matches(&self, record: &SwitchRecord) -> bool {
+        if let Some(validator) = &self.validator {
+            if !record.validator_identity.starts_with(validator.as_str())
+                && !record.vote_pubkey.starts_with(validator.as_str())
+            {
+                return false;
+            }
+        }
+        if let Some(trigger) = self.trigger {
+            if record.trigger != trigger {
+                return false;
+            }
+        }
+        if let Some(since) = self.since {
+            match chrono::DateTime::parse_from_rfc3339(&record.timestamp) {
+                Ok(ts) if ts >= since => {}
+                _ => return false,
+            }
+        }
+        if self.failed_only && record.success {
+            return false;
+        }
+        if !self.include_dry_runs && record.dry_run {
+            return false;
+        }
+        true
+    }
+}
+
+/// Append-only JSONL journal of switches under ~/.solana-validator-switch/
+pub struct SwitchJournal {
+    path: PathBuf,
+}
+
+impl SwitchJournal {
+    pub fn new() -> Result<Self> {
+        let dir = dirs::home_dir()
+            .ok_or_else(|| anyhow!("Could not find home directory"))?
+            .join(".solana-validator-switch");
+        Ok(Self::with_path(dir.join(JOURNAL_FILE_NAME)))
+    }
+
+    pub fn with_path(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn append(&self, record: &SwitchRecord) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", serde_json::to_string(record)?)?;
+        file.flush()?;
+        Ok(())
+    }
+
+    /// Load all records, skipping lines that fail to parse
+    pub fn load(&self) -> Result<Vec<SwitchRecord>> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let content = fs::read_to_string(&self.path)?;
+        Ok(content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect())
+    }
+}
+
+/// Best-effort write of a record - journaling must never break a switch
+pub fn record_switch(record: &SwitchRecord) {
+    let result = SwitchJournal::new().and_then(|journal| journal.append(record));
+    if let Err(e) = result {
+        eprintln!("⚠️  Failed to write switch journal: {}", e);
+    }
+}
+
+/// Hostname of the machine running svs
+pub fn operator_host() -> String {
+    if let Ok(host) = std::env::var("HOSTNAME") {
+        if !host.trim().is_empty() {
+            return host.trim().to_string();
+        }
+    }
+
+    std::process::Command::new("hostname")
+        .output()
+        .ok()
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .filter(|host| !host.is_empty())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Render records as CSV with a header row
+pub fn records_to_csv(records: &[SwitchRecord]) -> String {
+    let mut csv = String::from(
+        "timestamp,trigger,dry_run,success,validator_identity,vote_pubkey,operator_host,\
+         from_node,from_host,from_type,to_node,to_host,to_type,active_to_unfunded_ms,\
+         tower_transfer_ms,standby_to_funded_ms,identity_switch_ms,first_vote_ms,total_ms,errors\n",
+    );
+
+    let opt = |v: Option<u64>| v.map(|v| v.to_string()).unwrap_or_default();
+
+    for record in records {
+        let fields = vec![
+            record.timestamp.clone(),
+            record.trigger.as_str().to_string(),
+            record.dry_run.to_string(),
+            record.success.to_string(),
+            record.validator_identity.clone(),
+            record.vote_pubkey.clone(),
+            record.operator_host.clone(),
+            record.from_node.label.clone(),
+            record.from_node.host.clone(),
+            record.from_node.validator_type.clone(),
+            record.to_node.label.clone(),
+            record.to_node.host.clone(),
+            record.to_node.validator_type.clone(),
+            opt(record.steps.active_to_unfunded_ms),
+            opt(record.steps.tower_transfer_ms),
+            opt(record.steps.standby_to_funded_ms),
+            opt(record.steps.identity_switch_ms),
+            opt(record.steps.first_vote_ms),
+            opt(record.steps.total_ms),
+            record.errors.join("; "),
+        ];
+
+        let line = fields
+            .iter()
+            .map(|field| csv_escape(field))
+            .collect::<Vec<_>>()
+            .join(",");
+        csv.push_str(&line);
+        csv.push('\n');
+    }
+
+    csv
+}
+
+fn csv_escape(field: &str) -> String {
+    if field.contains(',') || field.contains('"') || field.contains('\n') {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_record(trigger: SwitchTrigger, success: bool, dry_run: bool) -> SwitchRecord {
+        SwitchRecord {
+            timestamp: "2025-02-01T03:00:00+00:00".to_string(),
+            trigger,
+            dry_run,
+            success,
+            validator_identity: "Ident111".to_string(),
+            vote_pubkey: "Vote111".to_string(),
+            operator_host: "ops-laptop".to_string(),
+            from_node: JournalNode {
+                label: "node-a".to_string(),
+                host: "10.0.0.1".to_string(),
+                validator_type: "Agave".to_string(),
+                version: Some("Agave 2.0.0".to_string()),
+            },
+            to_node: JournalNode {
+                label: "node-b".to_string(),
+                host: "10.0.0.2".to_string(),
+                validator_type: "Firedancer".to_string(),
+                version: None,
+            },
+            steps: StepDurations {
+                active_to_unfunded_ms: Some(120),
+                tower_transfer_ms: Some(80),
+                standby_to_funded_ms: Some(300),
+                identity_switch_ms: Some(500),
+                first_vote_ms: None,
+                total_ms: Some(900),
+            },
+            errors: if success {
+                vec![]
+            } else {
+                vec!["Tower copy failed, \"timeout\"".to_string()]
+            },
+        }
+    }
+
+    #[test]
+    fn test_journal_round_trip() {
+        let path =
+            std::env::temp_dir().join(format!("svs_journal_test_{}.jsonl", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let journal = SwitchJournal::with_path(path.clone());
+
+        assert!(journal.load().unwrap().is_empty());
+
+        journal
+            .append(&test_record(SwitchTrigger::Manual, true, false))
+            .unwrap();
+        journal
+            .append(&test_record(SwitchTrigger::AutoFailover, false, false))
+            .unwrap();
+
+        let records = journal.load().unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[1].trigger, SwitchTrigger::AutoFailover);
+        assert_eq!(records[0].steps.tower_transfer_ms, Some(80));
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_history_filter() {
+        let manual = test_record(SwitchTrigger::Manual, true, false);
+        let failed_auto = test_record(SwitchTrigger::AutoFailover, false, false);
+        let dry_run = test_record(SwitchTrigger::Manual, true, true);
+
+        let filter = HistoryFilter::default();
+        assert!(filter.matches(&manual));
+        assert!(!filter.matches(&dry_run));
+
+        let filter = HistoryFilter {
+            failed_only: true,
+            ..Default::default()
+        };
+        assert!(!filter.matches(&manual));
+        assert!(filter.matches(&failed_auto));
+
+        let filter = HistoryFilter {
+            trigger: Some(SwitchTrigger::AutoFailover),
+            validator: Some("Ident".to_string()),
+            ..Default::default()
+        };
+        assert!(filter.matches(&failed_auto));
+        assert!(!filter.matches(&manual));
+
+        let filter = HistoryFilter {
+            since: Some("2025-03-01T00:00:00Z".parse().unwrap()),
+            ..Default::default()
+        };
+        assert!(!filter.matches(&manual));
+    }
+
+    #[test]
+    fn test_csv_export_escapes_fields() {
+        let csv = records_to_csv(&[test_record(SwitchTrigger::AutoFailover, false, false)]);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("timestamp,trigger"));
+        assert!(lines[1].contains(",auto_failover,"));
+        assert!(lines[1].ends_with("\"Tower copy failed, \"\"timeout\"\"\""));
+    }
+
+    #[test]
+    fn test_trigger_parsing() {
+        assert_eq!(
+            "auto-failover".parse::<SwitchTrigger>().unwrap(),
+            SwitchTrigger::AutoFailover
+        );
+        assert_eq!("TUI".parse::<SwitchTrigger>().unwrap(), SwitchTrigger::Tui);
+        assert!("cron".parse::<SwitchTrigger>().is_err());
+    }
+}
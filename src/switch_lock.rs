@@ -0,0 +1,360 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::commands::switch::shell_quote;
+use crate::ssh::AsyncSshPool;
+use crate::types::NodeConfig;
+
+/// Lock files live in /tmp so a node reboot never leaves one behind
+const LOCK_DIR: &str = "/tmp";
+
+/// Who holds the switch lock of a validator, as stored in the lock file on each node
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LockOwner {
+    /// Operator user and machine, e.g. `alice@ops-laptop`
+    pub owner: String,
+    pub host: String,
+    pub pid: u32,
+    /// What the lock was taken for, e.g. `manual switch` or `auto-failover`
+    pub operation: String,
+    /// Unix seconds
+    pub acquired_at: u64,
+    pub expires_at: u64,
+    /// Unique per acquisition, so we only ever release our own lock
+    pub token: String,
+}
+
+impl LockOwner {
+    pub fn new(operation: &str, ttl_seconds: u64) -> Self {
+        let now = chrono::Utc::now();
+        let host = crate::switch_journal::operator_host();
+        let user = std::env::var("USER").unwrap_or_else(|_| "unknown".to_string());
+        let pid = std::process::id();
+        Self {
+            owner: format!("{}@{}", user, host),
+            token: format!(
+                "{}-{}-{}",
+                host,
+                pid,
+                now.timestamp_nanos_opt().unwrap_or(0)
+            ),
+            host,
+            pid,
+            operation: operation.to_string(),
+            acquired_at: now.timestamp() as u64,
+            expires_at: now.timestamp() as u64 + ttl_seconds,
+        }
+    }
+
+    pub fn is_expired(&self, now: u64) -> bool {
+        now >= self.expires_at
+    }
+
+    /// A lock left behind by an svs process on this machine that no longer runs
+    fn is_orphaned(&self) -> bool {
+        self.host == crate::switch_journal::operator_host()
+            && self.pid != std::process::id()
+            && !std::process::Command::new("kill")
+                .args(["-0", &self.pid.to_string()])
+                .status()
+                .is_ok_and(|status| status.success())
+    }
+
+    pub fn describe(&self) -> String {
+        let acquired = chrono::DateTime::from_timestamp(self.acquired_at as i64, 0)
+            .map(|t| t.to_rfc3339())
+            .unwrap_or_else(|| self.acquired_at.to_string());
+        format!(
+            "{} (pid {}, {}) since {}",
+            self.owner, self.pid, self.operation, acquired
+        )
+    }
+}
+
+/// Path of the lock file of a validator on its nodes
+pub fn lock_path(validator_identity: &str) -> String {
+    format!("{}/svs-switch-{}.lock", LOCK_DIR, validator_identity)
+}
+
+/// Create the lock file only if it does not exist (noclobber), otherwise print the holder
+fn acquire_command(path: &str, owner: &LockOwner) -> Result<String> {
+    let path = shell_quote(path);
+    Ok(format!(
+        "(set -C; printf '%s\\n' {} > {}) 2>/dev/null && echo 'acquired' || cat {}",
+        shell_quote(&serde_json::to_string(owner)?),
+        path,
+        path
+    ))
+}
+
+/// Remove the lock file, but only while it still holds `token`
+fn release_command(path: &str, token: &str) -> String {
+    let path = shell_quote(path);
+    format!(
+        "grep -qF {} {} 2>/dev/null && rm -f {}; echo 'released'",
+        shell_quote(token),
+        path,
+        path
+    )
+}
+
+/// Rewrite the lock file with a later expiry, but only while it still holds `owner`'s token
+fn extend_command(path: &str, owner: &LockOwner) -> Result<String> {
+    let path = shell_quote(path);
+    Ok(format!(
+        "grep -qF {} {} 2>/dev/null && printf '%s\\n' {} > {} && echo 'extended' || echo 'lost'",
+        shell_quote(&owner.token),
+        path,
+        shell_quote(&serde_json::to_string(owner)?),
+        path
+    ))
+}
+
+/// Result of trying to create the lock file on one node
+#[derive(Debug, PartialEq)]
+pub enum LockAttempt {
+    Acquired,
+    /// Held by someone else; the lock file could not be parsed if None
+    Held(Option<LockOwner>),
+}
+
+pub fn parse_acquire_output(output: &str) -> LockAttempt {
+    if output.trim() == "acquired" {
+        LockAttempt::Acquired
+    } else {
+        LockAttempt::Held(serde_json::from_str(output.trim()).ok())
+    }
+}
+
+/// Switch lock held on the nodes of one validator
+pub struct SwitchLock {
+    pub owner: LockOwner,
+    path: String,
+    nodes: Vec<NodeConfig>,
+    /// Locks of other operators removed with `--break-lock` (node label, previous holder)
+    pub broken: Vec<(String, String)>,
+}
+
+impl SwitchLock {
+    /// Take the lock on every node. Expired locks and locks left behind by a dead svs
+    /// on this machine are replaced; any other lock is an error unless `break_lock`
+    /// is set. With `skip_unreachable`, nodes that cannot be reached are left out.
+    /// On error, locks already taken are released again.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn acquire(
+        ssh_pool: &AsyncSshPool,
+        nodes: &[&NodeConfig],
+        detected_ssh_keys: &HashMap<String, String>,
+        validator_identity: &str,
+        operation: &str,
+        ttl_seconds: u64,
+        break_lock: bool,
+        skip_unreachable: bool,
+    ) -> Result<Self> {
+        let mut lock = Self {
+            owner: LockOwner::new(operation, ttl_seconds),
+            path: lock_path(validator_identity),
+            nodes: Vec::new(),
+            broken: Vec::new(),
+        };
+
+        for node in nodes {
+            if let Err(e) = lock
+                .acquire_on(
+                    ssh_pool,
+                    node,
+                    detected_ssh_keys,
+                    break_lock,
+                    skip_unreachable,
+                )
+                .await
+            {
+                lock.release(ssh_pool, detected_ssh_keys).await;
+                return Err(e);
+            }
+        }
+        Ok(lock)
+    }
+
+    async fn acquire_on(
+        &mut self,
+        ssh_pool: &AsyncSshPool,
+        node: &NodeConfig,
+        detected_ssh_keys: &HashMap<String, String>,
+        break_lock: bool,
+        skip_unreachable: bool,
+    ) -> Result<()> {
+        let ssh_key = detected_ssh_keys
+            .get(&node.host)
+            .ok_or_else(|| anyhow!("No SSH key detected for {}", node.label))?;
+        let command = acquire_command(&self.path, &self.owner)?;
+
+        // A second attempt only follows removing a stale or broken lock
+        for _ in 0..2 {
+            let output = match ssh_pool.execute_command(node, ssh_key, &command).await {
+                Ok(output) => output,
+                Err(_) if skip_unreachable => return Ok(()),
+                Err(e) => {
+                    return Err(anyhow!(
+                        "Failed to take the switch lock on {}: {}",
+                        node.label,
+                        e
+                    ))
+                }
+            };
+            let holder = match parse_acquire_output(&output) {
+                LockAttempt::Acquired => {
+                    self.nodes.push(node.clone());
+                    return Ok(());
+                }
+                LockAttempt::Held(holder) => holder,
+            };
+
+            let now = chrono::Utc::now().timestamp() as u64;
+            let stale = holder
+                .as_ref()
+                .is_some_and(|h| h.is_expired(now) || h.is_orphaned());
+            if !stale && !break_lock {
+                return Err(anyhow!(
+                    "Switch lock on {} is held by {}. Wait for it to finish, or use --break-lock if that svs is gone ({}:{})",
+                    node.label,
+                    holder
+                        .as_ref()
+                        .map(|h| h.describe())
+                        .unwrap_or_else(|| "an unreadable lock file".to_string()),
+                    node.host,
+                    self.path
+                ));
+            }
+            if !stale {
+                self.broken.push((
+                    node.label.clone(),
+                    holder
+                        .map(|h| h.describe())
+                        .unwrap_or_else(|| "unknown holder".to_string()),
+                ));
+            }
+            ssh_pool
+                .execute_command_with_args(node, ssh_key, "rm", &["-f", &self.path])
+                .await?;
+        }
+        Err(anyhow!(
+            "Switch lock on {} was taken again while replacing it",
+            node.label
+        ))
+    }
+
+    /// Push the expiry to `ttl_seconds` from now on every node where the lock was
+    /// taken. `Ok(false)` if a lock file no longer holds our token.
+    pub async fn extend(
+        &mut self,
+        ssh_pool: &AsyncSshPool,
+        detected_ssh_keys: &HashMap<String, String>,
+        ttl_seconds: u64,
+    ) -> Result<bool> {
+        let mut owner = self.owner.clone();
+        owner.expires_at = chrono::Utc::now().timestamp() as u64 + ttl_seconds;
+        let command = extend_command(&self.path, &owner)?;
+        for node in &self.nodes {
+            let ssh_key = detected_ssh_keys
+                .get(&node.host)
+                .ok_or_else(|| anyhow!("No SSH key detected for {}", node.label))?;
+            if ssh_pool
+                .execute_command(node, ssh_key, &command)
+                .await?
+                .trim()
+                != "extended"
+            {
+                return Ok(false);
+            }
+        }
+        self.owner = owner;
+        Ok(true)
+    }
+
+    /// Best-effort release on every node where the lock was taken
+    pub async fn release(
+        &self,
+        ssh_pool: &AsyncSshPool,
+        detected_ssh_keys: &HashMap<String, String>,
+    ) {
+        let command = release_command(&self.path, &self.owner.token);
+        for node in &self.nodes {
+            if let Some(ssh_key) = detected_ssh_keys.get(&node.host) {
+                let _ = ssh_pool.execute_command(node, ssh_key, &command).await;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lock_owner_round_trip_and_expiry() {
+        let owner = LockOwner::new("manual switch", 300);
+        assert!(!owner.is_expired(owner.acquired_at));
+        assert!(owner.is_expired(owner.acquired_at + 300));
+
+        let output = serde_json::to_string(&owner).unwrap();
+        assert_eq!(
+            parse_acquire_output(&format!("{}\n", output)),
+            LockAttempt::Held(Some(owner.clone()))
+        );
+        assert_eq!(parse_acquire_output("acquired\n"), LockAttempt::Acquired);
+        assert_eq!(parse_acquire_output("garbage"), LockAttempt::Held(None));
+        assert!(owner.describe().contains("manual switch"));
+    }
+
+    #[tokio::test]
+    async fn test_lock_commands_are_exclusive_and_release_only_own_lock() {
+        let path = std::env::temp_dir().join(format!("svs_lock_test_{}.lock", std::process::id()));
+        let path = path.to_string_lossy().to_string();
+        let _ = std::fs::remove_file(&path);
+        let run = |command: String| async move {
+            let output = tokio::process::Command::new("bash")
+                .arg("-c")
+                .arg(command)
+                .output()
+                .await
+                .unwrap();
+            String::from_utf8_lossy(&output.stdout).to_string()
+        };
+
+        let first = LockOwner::new("manual switch", 300);
+        let second = LockOwner::new("auto-failover", 300);
+        let acquire_first = acquire_command(&path, &first).unwrap();
+        assert_eq!(
+            parse_acquire_output(&run(acquire_first).await),
+            LockAttempt::Acquired
+        );
+        assert_eq!(
+            parse_acquire_output(&run(acquire_command(&path, &second).unwrap()).await),
+            LockAttempt::Held(Some(first.clone()))
+        );
+
+        // Extending rewrites our own lock only
+        let mut extended = first.clone();
+        extended.expires_at += 600;
+        assert_eq!(
+            run(extend_command(&path, &extended).unwrap()).await.trim(),
+            "extended"
+        );
+        assert_eq!(
+            parse_acquire_output(&run(acquire_command(&path, &second).unwrap()).await),
+            LockAttempt::Held(Some(extended.clone()))
+        );
+        assert_eq!(
+            run(extend_command(&path, &second).unwrap()).await.trim(),
+            "lost"
+        );
+
+        // Someone else's token leaves the lock in place
+        run(release_command(&path, "not-our-token")).await;
+        assert!(std::path::Path::new(&path).exists());
+        run(release_command(&path, &first.token)).await;
+        assert!(!std::path::Path::new(&path).exists());
+    }
+}
@@ -0,0 +1,272 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Bumped whenever the plan layout changes incompatibly
+pub const PLAN_FORMAT_VERSION: u32 = 1;
+
+/// Everything a switch will do, generated by a dry run so it can be reviewed
+/// and later executed as-is with `svs switch --plan`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SwitchPlan {
+    pub format_version: u32,
+    pub created_at: String,
+    pub created_by: String,
+    pub validator_identity: String,
+    pub vote_pubkey: String,
+    pub active: PlanNode,
+    pub standby: PlanNode,
+    pub tower: PlanTower,
+    pub steps: Vec<PlanStep>,
+}
+
+/// A node as it was when the plan was made, and the identity it will end up with
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlanNode {
+    pub label: String,
+    pub host: String,
+    pub user: String,
+    pub validator_type: String,
+    /// Identity the node reported when the plan was made
+    pub current_identity: Option<String>,
+    /// Keypair the node is switched to
+    pub target_identity_keypair: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlanTower {
+    pub source: String,
+    pub staging: String,
+    pub destination: String,
+}
+
+/// Remote commands run on one node for one step, in order
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlanStep {
+    pub step: u8,
+    pub description: String,
+    pub node: String,
+    pub commands: Vec<String>,
+}
+
+impl SwitchPlan {
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| anyhow!("Failed to read plan {}: {}", path.display(), e))?;
+        let plan: SwitchPlan = serde_json::from_str(&content)
+            .map_err(|e| anyhow!("Invalid plan {}: {}", path.display(), e))?;
+
+        if plan.format_version != PLAN_FORMAT_VERSION {
+            return Err(anyhow!(
+                "Plan {} has format version {} (this svs expects {})",
+                path.display(),
+                plan.format_version,
+                PLAN_FORMAT_VERSION
+            ));
+        }
+
+        Ok(plan)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        std::fs::write(path, serde_json::to_string_pretty(self)? + "\n")
+            .map_err(|e| anyhow!("Failed to write plan {}: {}", path.display(), e))
+    }
+
+    /// Differences between this (reviewed) plan and one built from the current
+    /// cluster state. Creation metadata is ignored.
+    pub fn mismatches(&self, current: &SwitchPlan) -> Vec<String> {
+        let mut mismatches = Vec::new();
+        let mut check = |what: &str, expected: String, actual: String| {
+            if expected != actual {
+                mismatches.push(format!("{}: plan has {}, now {}", what, expected, actual));
+            }
+        };
+
+        check(
+            "validator identity",
+            self.validator_identity.clone(),
+            current.validator_identity.clone(),
+        );
+        check(
+            "vote account",
+            self.vote_pubkey.clone(),
+            current.vote_pubkey.clone(),
+        );
+        for (role, expected, actual) in [
+            ("active", &self.active, &current.active),
+            ("standby", &self.standby, &current.standby),
+        ] {
+            check(
+                &format!("{} node", role),
+                format!("{} ({})", expected.label, expected.host),
+                format!("{} ({})", actual.label, actual.host),
+            );
+            check(
+                &format!("{} validator type", role),
+                expected.validator_type.clone(),
+                actual.validator_type.clone(),
+            );
+            check(
+                &format!("{} identity", role),
+                format!("{:?}", expected.current_identity),
+                format!("{:?}", actual.current_identity),
+            );
+            check(
+                &format!("{} target keypair", role),
+                expected.target_identity_keypair.clone(),
+                actual.target_identity_keypair.clone(),
+            );
+        }
+        check(
+            "tower source",
+            self.tower.source.clone(),
+            current.tower.source.clone(),
+        );
+        check(
+            "tower staging",
+            self.tower.staging.clone(),
+            current.tower.staging.clone(),
+        );
+        check(
+            "tower destination",
+            self.tower.destination.clone(),
+            current.tower.destination.clone(),
+        );
+
+        if self.steps != current.steps {
+            for step in &self.steps {
+                let now = current
+                    .steps
+                    .iter()
+                    .find(|s| s.step == step.step && s.node == step.node);
+                match now {
+                    Some(now) if now.commands == step.commands => {}
+                    Some(now) => check(
+                        &format!("step {} commands on {}", step.step, step.node),
+                        step.commands.join(" ; "),
+                        now.commands.join(" ; "),
+                    ),
+                    None => check(
+                        &format!("step {} on {}", step.step, step.node),
+                        "present".to_string(),
+                        "missing".to_string(),
+                    ),
+                }
+            }
+            // A step left out of the reviewed plan would otherwise run unreviewed
+            for step in &current.steps {
+                if !self
+                    .steps
+                    .iter()
+                    .any(|s| s.step == step.step && s.node == step.node)
+                {
+                    check(
+                        &format!("step {} on {}", step.step, step.node),
+                        "missing".to_string(),
+                        "present".to_string(),
+                    );
+                }
+            }
+        }
+
+        mismatches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_plan() -> SwitchPlan {
+        SwitchPlan {
+            format_version: PLAN_FORMAT_VERSION,
+            created_at: "2025-03-10T12:00:00+00:00".to_string(),
+            created_by: "ops-laptop".to_string(),
+            validator_identity: "Ident111".to_string(),
+            vote_pubkey: "Vote111".to_string(),
+            active: PlanNode {
+                label: "node-a".to_string(),
+                host: "10.0.0.1".to_string(),
+                user: "solana".to_string(),
+                validator_type: "Agave".to_string(),
+                current_identity: Some("Ident111".to_string()),
+                target_identity_keypair: "/keys/unfunded.json".to_string(),
+            },
+            standby: PlanNode {
+                label: "node-b".to_string(),
+                host: "10.0.0.2".to_string(),
+                user: "solana".to_string(),
+                validator_type: "Firedancer".to_string(),
+                current_identity: Some("Unfunded222".to_string()),
+                target_identity_keypair: "/keys/funded.json".to_string(),
+            },
+            tower: PlanTower {
+                source: "/mnt/ledger/tower-1_9-Ident111.bin".to_string(),
+                staging: "/mnt/ledger/.tower-1_9-Ident111.bin.svs-tmp".to_string(),
+                destination: "/mnt/ledger/tower-1_9-Ident111.bin".to_string(),
+            },
+            steps: vec![PlanStep {
+                step: 1,
+                description: "Switch active node to unfunded identity".to_string(),
+                node: "node-a".to_string(),
+                commands: vec![
+                    "/opt/agave-validator -l /mnt/ledger set-identity /keys/unfunded.json"
+                        .to_string(),
+                ],
+            }],
+        }
+    }
+
+    #[test]
+    fn test_plan_round_trip() {
+        let dir = std::env::temp_dir().join(format!("svs-plan-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("plan.json");
+
+        let plan = test_plan();
+        plan.save(&path).unwrap();
+        assert_eq!(SwitchPlan::load(&path).unwrap(), plan);
+
+        let mut old = plan.clone();
+        old.format_version = 0;
+        old.save(&path).unwrap();
+        assert!(SwitchPlan::load(&path).is_err());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_plan_mismatches() {
+        let plan = test_plan();
+
+        // Only the creation metadata differs
+        let mut current = plan.clone();
+        current.created_at = "2025-03-10T13:00:00+00:00".to_string();
+        current.created_by = "other-host".to_string();
+        assert!(plan.mismatches(&current).is_empty());
+
+        // Nodes swapped roles and a command changed
+        let mut current = plan.clone();
+        std::mem::swap(&mut current.active, &mut current.standby);
+        current.steps[0].commands[0] = "/opt/agave-validator -l /other set-identity x".to_string();
+        let mismatches = plan.mismatches(&current);
+        assert!(mismatches.iter().any(|m| m.starts_with("active node")));
+        assert!(mismatches
+            .iter()
+            .any(|m| m.starts_with("step 1 commands on node-a")));
+
+        // Steps removed from the reviewed plan, and a different staging path
+        let mut reviewed = plan.clone();
+        reviewed.steps.clear();
+        reviewed.tower.staging = "/tmp/tower.bin".to_string();
+        let mismatches = reviewed.mismatches(&plan);
+        assert_eq!(
+            mismatches,
+            vec![
+                "tower staging: plan has /tmp/tower.bin, now /mnt/ledger/.tower-1_9-Ident111.bin.svs-tmp",
+                "step 1 on node-a: plan has missing, now present",
+            ]
+        );
+    }
+}
@@ -0,0 +1,160 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use std::time::Duration;
+
+/// When a planned switch should start
+#[derive(Debug, Clone, PartialEq)]
+pub enum SwitchSchedule {
+    /// At a wall-clock time (UTC)
+    At(DateTime<Utc>),
+    /// As soon as the next epoch has started
+    EpochBoundary,
+}
+
+impl SwitchSchedule {
+    /// Build a schedule from the `--at` / `--at-epoch-boundary` arguments
+    pub fn from_args(at: Option<&str>, at_epoch_boundary: bool) -> Result<Option<Self>> {
+        match (at, at_epoch_boundary) {
+            (Some(_), true) => Err(anyhow!(
+                "--at and --at-epoch-boundary cannot be used together"
+            )),
+            (Some(value), false) => Ok(Some(SwitchSchedule::At(parse_switch_time(
+                value,
+                Utc::now(),
+            )?))),
+            (None, true) => Ok(Some(SwitchSchedule::EpochBoundary)),
+            (None, false) => Ok(None),
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            SwitchSchedule::At(time) => time.format("%Y-%m-%d %H:%M:%S UTC").to_string(),
+            SwitchSchedule::EpochBoundary => "next epoch boundary".to_string(),
+        }
+    }
+}
+
+/// Parse a switch time in UTC. Accepts `HH:MM[:SS]` (next occurrence),
+/// `YYYY-MM-DD HH:MM[:SS]` or RFC 3339. Times in the past are rejected.
+pub fn parse_switch_time(value: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>> {
+    let value = value.trim();
+
+    let time = if let Ok(ts) = DateTime::parse_from_rfc3339(value) {
+        ts.with_timezone(&Utc)
+    } else if let Some(ts) = ["%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M", "%Y-%m-%dT%H:%M"]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+    {
+        ts.and_utc()
+    } else if let Some(time_of_day) = ["%H:%M:%S", "%H:%M"]
+        .iter()
+        .find_map(|format| NaiveTime::parse_from_str(value, format).ok())
+    {
+        let today: NaiveDate = now.date_naive();
+        let candidate = today.and_time(time_of_day).and_utc();
+        if candidate > now {
+            candidate
+        } else {
+            candidate + chrono::Duration::days(1)
+        }
+    } else {
+        return Err(anyhow!(
+            "Invalid --at value '{}' (use HH:MM, YYYY-MM-DD HH:MM or RFC 3339, in UTC)",
+            value
+        ));
+    };
+
+    if time <= now {
+        return Err(anyhow!(
+            "Scheduled time {} is in the past",
+            time.format("%Y-%m-%d %H:%M:%S UTC")
+        ));
+    }
+
+    Ok(time)
+}
+
+/// Current slot and the first slot of the next epoch
+pub async fn fetch_next_epoch_start(rpc_url: &str) -> Result<(u64, u64)> {
+    let epoch_info = crate::solana_rpc::rpc_client(rpc_url)
+        .get_epoch_info()
+        .await
+        .map_err(|e| anyhow!("Failed to get epoch info: {}", e))?;
+
+    let next_epoch_start =
+        epoch_info.absolute_slot - epoch_info.slot_index + epoch_info.slots_in_epoch;
+    Ok((epoch_info.absolute_slot, next_epoch_start))
+}
+
+/// Countdown as `[Dd ]HH:MM:SS`
+pub fn format_countdown(remaining: Duration) -> String {
+    let secs = remaining.as_secs();
+    let (days, hours, minutes, seconds) = (
+        secs / 86_400,
+        (secs % 86_400) / 3_600,
+        (secs % 3_600) / 60,
+        secs % 60,
+    );
+
+    if days > 0 {
+        format!("{}d {:02}:{:02}:{:02}", days, hours, minutes, seconds)
+    } else {
+        format!("{:02}:{:02}:{:02}", hours, minutes, seconds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 3, 10, 12, 30, 0).unwrap()
+    }
+
+    #[test]
+    fn test_parse_switch_time_formats() {
+        let expected = Utc.with_ymd_and_hms(2025, 3, 11, 3, 0, 0).unwrap();
+
+        // Time of day already passed today rolls over to tomorrow
+        assert_eq!(parse_switch_time("03:00", now()).unwrap(), expected);
+        assert_eq!(
+            parse_switch_time("2025-03-11 03:00", now()).unwrap(),
+            expected
+        );
+        assert_eq!(
+            parse_switch_time("2025-03-11T04:00:00+01:00", now()).unwrap(),
+            expected
+        );
+        // Later today stays today
+        assert_eq!(
+            parse_switch_time("13:15:30", now()).unwrap(),
+            Utc.with_ymd_and_hms(2025, 3, 10, 13, 15, 30).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_switch_time_rejects_invalid_and_past() {
+        assert!(parse_switch_time("tomorrow", now()).is_err());
+        assert!(parse_switch_time("25:00", now()).is_err());
+        assert!(parse_switch_time("2025-03-10 12:00", now()).is_err());
+    }
+
+    #[test]
+    fn test_schedule_from_args() {
+        assert_eq!(SwitchSchedule::from_args(None, false).unwrap(), None);
+        assert_eq!(
+            SwitchSchedule::from_args(None, true).unwrap(),
+            Some(SwitchSchedule::EpochBoundary)
+        );
+        assert!(SwitchSchedule::from_args(Some("03:00"), true).is_err());
+    }
+
+    #[test]
+    fn test_format_countdown() {
+        assert_eq!(format_countdown(Duration::from_secs(59)), "00:00:59");
+        assert_eq!(format_countdown(Duration::from_secs(3_725)), "01:02:05");
+        assert_eq!(format_countdown(Duration::from_secs(90_061)), "1d 01:01:01");
+    }
+}
@@ -0,0 +1,216 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+use crate::types::ValidatorPair;
+
+const STATE_DIR_NAME: &str = "switch_state";
+
+/// Last step a switch completed. Written to disk after every step so an
+/// interrupted switch can be resumed or rolled back on the next launch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SwitchPhase {
+    /// About to switch the active node to its unfunded identity (it may already have)
+    Started,
+    /// The active node runs its unfunded identity
+    PrimaryDemoted,
+    /// The tower is on the standby; it may be taking the funded identity
+    TowerCopied,
+    /// The standby runs the funded identity
+    StandbyPromoted,
+    /// The new active node was verified, the switch is finished
+    Verified,
+    /// The original active node was restored, the switch is finished
+    RolledBack,
+}
+
+impl SwitchPhase {
+    pub fn is_finished(&self) -> bool {
+        matches!(self, SwitchPhase::Verified | SwitchPhase::RolledBack)
+    }
+
+    pub fn describe(&self) -> &'static str {
+        match self {
+            SwitchPhase::Started => "started, active node may already be on its unfunded identity",
+            SwitchPhase::PrimaryDemoted => "active node switched to unfunded identity",
+            SwitchPhase::TowerCopied => "tower copied, standby may be taking the funded identity",
+            SwitchPhase::StandbyPromoted => "standby switched to funded identity, not verified",
+            SwitchPhase::Verified => "verified",
+            SwitchPhase::RolledBack => "rolled back",
+        }
+    }
+}
+
+/// Progress of one switch, one file per validator identity
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SwitchState {
+    pub validator_identity: String,
+    pub vote_pubkey: String,
+    /// Label of the node that was active when the switch started
+    pub from_node: String,
+    /// Label of the standby being activated
+    pub to_node: String,
+    pub phase: SwitchPhase,
+    pub started_at: String,
+    pub updated_at: String,
+    pub operator_host: String,
+}
+
+impl SwitchState {
+    pub fn new(validator_pair: &ValidatorPair, from_node: &str, to_node: &str) -> Self {
+        let now = chrono::Utc::now().to_rfc3339();
+        Self {
+            validator_identity: validator_pair.identity_pubkey.clone(),
+            vote_pubkey: validator_pair.vote_pubkey.clone(),
+            from_node: from_node.to_string(),
+            to_node: to_node.to_string(),
+            phase: SwitchPhase::Started,
+            started_at: now.clone(),
+            updated_at: now,
+            operator_host: crate::switch_journal::operator_host(),
+        }
+    }
+
+    pub fn advance(&mut self, phase: SwitchPhase) {
+        self.phase = phase;
+        self.updated_at = chrono::Utc::now().to_rfc3339();
+    }
+}
+
+/// Switch states under ~/.solana-validator-switch/switch_state/
+pub struct SwitchStateStore {
+    dir: PathBuf,
+}
+
+impl SwitchStateStore {
+    pub fn new() -> Result<Self> {
+        let dir = dirs::home_dir()
+            .ok_or_else(|| anyhow!("Could not find home directory"))?
+            .join(".solana-validator-switch")
+            .join(STATE_DIR_NAME);
+        Ok(Self::with_dir(dir))
+    }
+
+    pub fn with_dir(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    fn path_for(&self, validator_identity: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", validator_identity))
+    }
+
+    /// Write the state atomically, so a crash mid-write never leaves a torn file
+    pub fn save(&self, state: &SwitchState) -> Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        let path = self.path_for(&state.validator_identity);
+        let temp_path = path.with_extension("json.tmp");
+        fs::write(&temp_path, serde_json::to_string_pretty(state)?)?;
+        fs::rename(&temp_path, &path)?;
+        Ok(())
+    }
+
+    /// Forget the switch state of a validator
+    pub fn remove(&self, validator_identity: &str) -> Result<()> {
+        let path = self.path_for(validator_identity);
+        if path.exists() {
+            fs::remove_file(path)?;
+        }
+        Ok(())
+    }
+
+    /// Switches that neither finished nor were rolled back
+    pub fn load_unfinished(&self) -> Result<Vec<SwitchState>> {
+        if !self.dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut states = Vec::new();
+        for entry in fs::read_dir(&self.dir)? {
+            let path = entry?.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                continue;
+            }
+            let state: SwitchState = match fs::read_to_string(&path)
+                .map_err(anyhow::Error::from)
+                .and_then(|content| serde_json::from_str(&content).map_err(Into::into))
+            {
+                Ok(state) => state,
+                Err(e) => {
+                    eprintln!(
+                        "⚠️  Ignoring unreadable switch state {}: {}",
+                        path.display(),
+                        e
+                    );
+                    continue;
+                }
+            };
+            if !state.phase.is_finished() {
+                states.push(state);
+            }
+        }
+        states.sort_by(|a, b| a.started_at.cmp(&b.started_at));
+        Ok(states)
+    }
+}
+
+/// Best-effort write of a state - persisting it must never break a switch
+pub fn save_state(state: &SwitchState) {
+    let result = SwitchStateStore::new().and_then(|store| store.save(state));
+    if let Err(e) = result {
+        eprintln!("⚠️  Failed to write switch state: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validator_pair(identity: &str) -> ValidatorPair {
+        ValidatorPair {
+            vote_pubkey: "Vote111".to_string(),
+            identity_pubkey: identity.to_string(),
+            rpc: "http://localhost:8899".to_string(),
+            rpcs: Vec::new(),
+            rpc_quorum: None,
+            nodes: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_only_unfinished_switches_are_loaded() {
+        let dir =
+            std::env::temp_dir().join(format!("svs_switch_state_test_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let store = SwitchStateStore::with_dir(dir.clone());
+
+        let mut interrupted = SwitchState::new(&validator_pair("Ident111"), "primary", "spare");
+        store.save(&interrupted).unwrap();
+        interrupted.advance(SwitchPhase::TowerCopied);
+        store.save(&interrupted).unwrap();
+
+        let mut finished = SwitchState::new(&validator_pair("Ident222"), "primary", "spare");
+        finished.advance(SwitchPhase::Verified);
+        store.save(&finished).unwrap();
+
+        let unfinished = store.load_unfinished().unwrap();
+        assert_eq!(unfinished.len(), 1);
+        assert_eq!(unfinished[0].validator_identity, "Ident111");
+        assert_eq!(unfinished[0].phase, SwitchPhase::TowerCopied);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_phase_order_and_serialization() {
+        assert!(SwitchPhase::Started < SwitchPhase::PrimaryDemoted);
+        assert!(SwitchPhase::TowerCopied < SwitchPhase::StandbyPromoted);
+        assert!(!SwitchPhase::StandbyPromoted.is_finished());
+        assert!(SwitchPhase::RolledBack.is_finished());
+        assert_eq!(
+            serde_json::to_string(&SwitchPhase::PrimaryDemoted).unwrap(),
+            "\"primary_demoted\""
+        );
+    }
+}
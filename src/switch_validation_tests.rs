@@ -233,4 +233,52 @@ mod switch_validation_tests {
         assert!(validation_errors[0].contains("Funded identity keypair missing"));
         assert!(validation_errors[0].contains("Vote keypair missing"));
     }
+
+    #[test]
+    fn test_set_identity_command_matches_validator_process() {
+        use crate::commands::switch::set_identity_command;
+
+        // Agave uses the detected executable and ledger path
+        let mut node = create_test_node("node-1-1", "validator1-1.example.com", true, true);
+        let (_, command) = set_identity_command(
+            &mut node,
+            "solana  1234 agave-validator --ledger /mnt/ledger",
+            "/home/solana/unfunded.json",
+            false,
+        )
+        .unwrap();
+        assert_eq!(
+            command.display(),
+            "/home/solana/bin/agave-validator -l /mnt/ledger set-identity /home/solana/unfunded.json"
+        );
+
+        // Paths with spaces are quoted in the displayed command line
+        let (_, command) = set_identity_command(
+            &mut node,
+            "solana  1234 agave-validator --ledger /mnt/ledger",
+            "/home/solana/my keys/funded.json",
+            false,
+        )
+        .unwrap();
+        assert!(command
+            .display()
+            .ends_with("set-identity '/home/solana/my keys/funded.json'"));
+
+        // Activation can insist on a tower
+        let (_, command) = set_identity_command(
+            &mut node,
+            "solana  1234 agave-validator --ledger /mnt/ledger",
+            "/home/solana/funded.json",
+            true,
+        )
+        .unwrap();
+        assert!(command
+            .display()
+            .ends_with("set-identity --require-tower /home/solana/funded.json"));
+
+        // Nothing recognizable running
+        assert!(
+            set_identity_command(&mut node, "bash", "/home/solana/unfunded.json", false).is_err()
+        );
+    }
 }
@@ -0,0 +1,254 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_sdk::vote::state::{VoteState, VoteState1_14_11};
+
+/// Signed tower as written to `tower-1_9-<identity>.bin`
+#[derive(Serialize, Deserialize)]
+struct SavedTower {
+    signature: Signature,
+    data: Vec<u8>,
+}
+
+/// Versioned wrapper used by current Agave, Jito and Firedancer (fdctl) builds
+#[derive(Serialize, Deserialize)]
+enum SavedTowerVersions {
+    V1_17_14(SavedTower),
+    Current(SavedTower),
+}
+
+/// Leading fields of a current tower, whose votes carry their landing latency;
+/// everything after `vote_state` is ignored
+#[derive(Serialize, Deserialize)]
+struct TowerPrefix {
+    node_pubkey: Pubkey,
+    threshold_depth: u64,
+    threshold_size: f64,
+    vote_state: VoteState,
+}
+
+/// Leading fields of a tower written by 1.17.14 and older builds, or without
+/// the version tag, which hold plain lockouts
+#[derive(Serialize, Deserialize)]
+struct TowerPrefix1_14_11 {
+    node_pubkey: Pubkey,
+    threshold_depth: u64,
+    threshold_size: f64,
+    vote_state: VoteState1_14_11,
+}
+
+/// A tower the standby must never be activated with, whatever the step policies
+/// say: activating it could violate the lockouts of votes already cast
+#[derive(Debug)]
+pub struct StaleTower(pub String);
+
+impl std::fmt::Display for StaleTower {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Refusing to activate standby: {}", self.0)
+    }
+}
+
+impl std::error::Error for StaleTower {}
+
+/// What the tower file says about the validator's voting position
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TowerSummary {
+    pub node_pubkey: String,
+    pub last_voted_slot: Option<u64>,
+    pub root_slot: Option<u64>,
+}
+
+impl TowerSummary {
+    /// How many slots the tower is behind the given on-chain last vote
+    pub fn slots_behind(&self, on_chain_last_vote: u64) -> u64 {
+        on_chain_last_vote.saturating_sub(self.last_voted_slot.unwrap_or(0))
+    }
+}
+
+/// Decode a tower file and verify its signature against the node identity it contains
+pub fn decode_tower(bytes: &[u8]) -> Result<TowerSummary> {
+    let (saved, current) = match bincode::deserialize::<SavedTowerVersions>(bytes) {
+        Ok(SavedTowerVersions::Current(saved)) => (saved, true),
+        Ok(SavedTowerVersions::V1_17_14(saved)) => (saved, false),
+        // Towers written before the versioned format have no enum tag
+        Err(_) => (
+            bincode::deserialize::<SavedTower>(bytes)
+                .map_err(|e| anyhow!("Unrecognized tower file format: {}", e))?,
+            false,
+        ),
+    };
+
+    let decode_error = |e| anyhow!("Failed to decode tower data: {}", e);
+    let (node_pubkey, last_voted_slot, root_slot) = if current {
+        let tower: TowerPrefix = bincode::deserialize(&saved.data).map_err(decode_error)?;
+        (
+            tower.node_pubkey,
+            tower.vote_state.last_voted_slot(),
+            tower.vote_state.root_slot,
+        )
+    } else {
+        let tower: TowerPrefix1_14_11 = bincode::deserialize(&saved.data).map_err(decode_error)?;
+        (
+            tower.node_pubkey,
+            tower.vote_state.votes.back().map(|lockout| lockout.slot()),
+            tower.vote_state.root_slot,
+        )
+    };
+
+    if !saved.signature.verify(node_pubkey.as_ref(), &saved.data) {
+        return Err(anyhow!(
+            "Tower signature does not match node identity {}",
+            node_pubkey
+        ));
+    }
+
+    Ok(TowerSummary {
+        node_pubkey: node_pubkey.to_string(),
+        last_voted_slot,
+        root_slot,
+    })
+}
+
+/// Refuse a tower that belongs to another identity or lags the on-chain last vote
+/// by more than `max_lag_slots`
+pub fn check_tower_freshness(
+    tower: &TowerSummary,
+    expected_identity: &str,
+    on_chain_last_vote: u64,
+    max_lag_slots: u64,
+) -> Result<()> {
+    if tower.node_pubkey != expected_identity {
+        return Err(anyhow!(
+            "Tower belongs to {} but the validator identity is {}",
+            tower.node_pubkey,
+            expected_identity
+        ));
+    }
+
+    let behind = tower.slots_behind(on_chain_last_vote);
+    if behind > max_lag_slots {
+        return Err(anyhow!(
+            "Tower is stale: last voted slot {} is {} slots behind the on-chain last vote {} (max {})",
+            tower
+                .last_voted_slot
+                .map(|slot| slot.to_string())
+                .unwrap_or_else(|| "none".to_string()),
+            behind,
+            on_chain_last_vote,
+            max_lag_slots
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::signature::{Keypair, Signer};
+    use solana_sdk::vote::state::{LandedVote, Lockout};
+
+    fn sign_tower(keypair: &Keypair, mut data: Vec<u8>, current: bool) -> Vec<u8> {
+        // Stand-in for last_vote and the fields after it, which the decoder skips
+        data.extend_from_slice(&[7u8; 24]);
+
+        let saved = SavedTower {
+            signature: keypair.sign_message(&data),
+            data,
+        };
+        let versions = if current {
+            SavedTowerVersions::Current(saved)
+        } else {
+            SavedTowerVersions::V1_17_14(saved)
+        };
+        bincode::serialize(&versions).unwrap()
+    }
+
+    /// Tower in the current layout, with latency-carrying votes
+    fn tower_bytes(keypair: &Keypair, slots: &[u64], root: Option<u64>) -> Vec<u8> {
+        let mut vote_state = VoteState::default();
+        vote_state.node_pubkey = keypair.pubkey();
+        vote_state.root_slot = root;
+        vote_state.votes = slots
+            .iter()
+            .map(|slot| LandedVote {
+                latency: 2,
+                lockout: Lockout::new(*slot),
+            })
+            .collect();
+
+        let data = bincode::serialize(&TowerPrefix {
+            node_pubkey: keypair.pubkey(),
+            threshold_depth: 8,
+            threshold_size: 0.67,
+            vote_state,
+        })
+        .unwrap();
+        sign_tower(keypair, data, true)
+    }
+
+    /// Tower in the layout written by 1.17.14 and older builds
+    fn tower_bytes_1_14_11(keypair: &Keypair, slots: &[u64], root: Option<u64>) -> Vec<u8> {
+        let mut vote_state = VoteState1_14_11 {
+            node_pubkey: keypair.pubkey(),
+            root_slot: root,
+            ..VoteState1_14_11::default()
+        };
+        vote_state.votes = slots.iter().map(|slot| Lockout::new(*slot)).collect();
+
+        let data = bincode::serialize(&TowerPrefix1_14_11 {
+            node_pubkey: keypair.pubkey(),
+            threshold_depth: 8,
+            threshold_size: 0.67,
+            vote_state,
+        })
+        .unwrap();
+        sign_tower(keypair, data, false)
+    }
+
+    #[test]
+    fn test_decode_tower_reads_last_vote_and_root() {
+        let keypair = Keypair::new();
+        let tower = decode_tower(&tower_bytes(&keypair, &[100, 101, 105], Some(90))).unwrap();
+
+        assert_eq!(tower.node_pubkey, keypair.pubkey().to_string());
+        assert_eq!(tower.last_voted_slot, Some(105));
+        assert_eq!(tower.root_slot, Some(90));
+
+        let tower = decode_tower(&tower_bytes_1_14_11(&keypair, &[200, 204], Some(180))).unwrap();
+        assert_eq!(tower.node_pubkey, keypair.pubkey().to_string());
+        assert_eq!(tower.last_voted_slot, Some(204));
+        assert_eq!(tower.root_slot, Some(180));
+    }
+
+    #[test]
+    fn test_decode_tower_rejects_tampered_data() {
+        let keypair = Keypair::new();
+        let mut bytes = tower_bytes(&keypair, &[100], None);
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xff;
+
+        assert!(decode_tower(&bytes).is_err());
+        assert!(decode_tower(b"not a tower").is_err());
+    }
+
+    #[test]
+    fn test_check_tower_freshness() {
+        let keypair = Keypair::new();
+        let identity = keypair.pubkey().to_string();
+        let tower = decode_tower(&tower_bytes(&keypair, &[1_000], None)).unwrap();
+
+        // Tower ahead of or level with the chain is fine
+        assert!(check_tower_freshness(&tower, &identity, 990, 0).is_ok());
+        assert!(check_tower_freshness(&tower, &identity, 1_000, 0).is_ok());
+        // Within the margin
+        assert!(check_tower_freshness(&tower, &identity, 1_010, 16).is_ok());
+        // Too far behind
+        let err = check_tower_freshness(&tower, &identity, 1_100, 16).unwrap_err();
+        assert!(err.to_string().contains("100 slots behind"));
+        // Wrong identity
+        let other = Keypair::new().pubkey().to_string();
+        assert!(check_tower_freshness(&tower, &other, 1_000, 16).is_err());
+    }
+}
@@ -1,12 +1,12 @@
 use serde::{Deserialize, Serialize};
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 // Default functions for serde
 fn default_enabled() -> bool {
     true
 }
 
-fn default_delinquency_threshold() -> u64 {
+pub(crate) fn default_delinquency_threshold() -> u64 {
     30
 }
 
@@ -14,16 +14,275 @@ fn default_ssh_failure_threshold() -> u64 {
     1800 // 30 minutes of SSH failures before alert
 }
 
-fn default_rpc_failure_threshold() -> u64 {
+pub(crate) fn default_rpc_failure_threshold() -> u64 {
     1800 // 30 minutes of RPC failures before alert
 }
 
+fn default_vote_confirmation_timeout() -> u64 {
+    60 // Wait up to a minute for the first vote from the new active node
+}
+
+fn default_max_tower_lag_slots() -> u64 {
+    16 // Tower may trail the on-chain last vote by a few slots at most
+}
+
+fn default_leader_window_slots() -> u64 {
+    40 // ~16 seconds without our leader slots, enough for a full switch
+}
+
+fn default_emergency_leader_wait_max_seconds() -> u64 {
+    20
+}
+
+fn default_fencing_timeout() -> u64 {
+    30 // Power-off calls to a BMC can take a while to return
+}
+
+fn default_lock_ttl() -> u64 {
+    600 // Longer than any switch including verification; a crashed svs holds it no longer
+}
+
+fn default_retry_backoff_ms() -> u64 {
+    500 // Doubled after every retry
+}
+
+fn default_hook_timeout() -> u64 {
+    30
+}
+
+fn default_failover_window_minutes() -> u64 {
+    60
+}
+
+fn default_max_standby_slot_lag() -> u64 {
+    50 // ~20 seconds behind the cluster
+}
+
+fn default_fencing_http_method() -> String {
+    "POST".to_string()
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
     pub version: String,
     pub validators: Vec<ValidatorPair>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub alert_config: Option<AlertConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub switch_policy: Option<SwitchPolicy>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SwitchPolicy {
+    #[serde(default = "default_vote_confirmation_timeout")]
+    pub vote_confirmation_timeout_seconds: u64,
+    #[serde(default = "default_max_tower_lag_slots")]
+    pub max_tower_lag_slots: u64,
+    /// Activate with a tower whose freshness could not be checked (undecodable tower,
+    /// unreachable RPC) instead of refusing
+    #[serde(default)]
+    pub allow_unverified_tower: bool,
+    #[serde(default = "default_leader_window_slots")]
+    pub leader_window_slots: u64,
+    #[serde(default)]
+    pub emergency_leader_window: bool,
+    #[serde(default = "default_emergency_leader_wait_max_seconds")]
+    pub emergency_leader_wait_max_seconds: u64,
+    #[serde(default)]
+    pub require_tower: bool,
+    #[serde(default)]
+    pub emergency_activate_without_tower: bool,
+    #[serde(default)]
+    pub fence_double_identity: bool,
+    #[serde(default)]
+    pub emergency_require_unfunded_confirmation: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fencing: Option<FencingConfig>,
+    #[serde(default)]
+    pub proceed_without_fencing: bool,
+    #[serde(default)]
+    pub keyless_standby: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub funded_keypair_source: Option<String>,
+    #[serde(default = "default_lock_ttl")]
+    pub lock_ttl_seconds: u64,
+    /// Before automatic failover activates a standby, require getHealth ok, a slot lag
+    /// of at most `emergency_max_slot_lag`, the unfunded identity and readable key files
+    #[serde(default = "default_enabled")]
+    pub emergency_readiness_gate: bool,
+    #[serde(default = "default_max_standby_slot_lag")]
+    pub emergency_max_slot_lag: u64,
+    #[serde(default)]
+    pub steps: StepPolicies,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hooks: Vec<HookConfig>,
+}
+
+impl Default for SwitchPolicy {
+    fn default() -> Self {
+        Self {
+            vote_confirmation_timeout_seconds: default_vote_confirmation_timeout(),
+            max_tower_lag_slots: default_max_tower_lag_slots(),
+            allow_unverified_tower: false,
+            leader_window_slots: default_leader_window_slots(),
+            emergency_leader_window: false,
+            emergency_leader_wait_max_seconds: default_emergency_leader_wait_max_seconds(),
+            require_tower: false,
+            emergency_activate_without_tower: false,
+            fence_double_identity: false,
+            emergency_require_unfunded_confirmation: false,
+            fencing: None,
+            proceed_without_fencing: false,
+            keyless_standby: false,
+            funded_keypair_source: None,
+            lock_ttl_seconds: default_lock_ttl(),
+            emergency_readiness_gate: true,
+            emergency_max_slot_lag: default_max_standby_slot_lag(),
+            steps: StepPolicies::default(),
+            hooks: Vec::new(),
+        }
+    }
+}
+
+/// Whether a failing switch step stops the switch
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StepRequirement {
+    Required,
+    /// Log the failure and carry on with the next step
+    BestEffort,
+}
+
+/// Timeout, retries and requirement of one switch step. Unset values keep the
+/// built-in behaviour of the manual switch and of automatic failover.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StepPolicy {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_seconds: Option<u64>,
+    /// Extra attempts after a transient SSH error or a timeout
+    #[serde(default)]
+    pub retries: u32,
+    #[serde(default = "default_retry_backoff_ms")]
+    pub retry_backoff_ms: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requirement: Option<StepRequirement>,
+}
+
+impl Default for StepPolicy {
+    fn default() -> Self {
+        Self {
+            timeout_seconds: None,
+            retries: 0,
+            retry_backoff_ms: default_retry_backoff_ms(),
+            requirement: None,
+        }
+    }
+}
+
+impl StepPolicy {
+    pub fn timeout(&self, default: Option<Duration>) -> Option<Duration> {
+        self.timeout_seconds.map(Duration::from_secs).or(default)
+    }
+
+    /// Wait before retry number `retry` (1-based)
+    pub fn backoff(&self, retry: u32) -> Duration {
+        Duration::from_millis(
+            self.retry_backoff_ms
+                .saturating_mul(1 << retry.saturating_sub(1).min(10)),
+        )
+    }
+
+    pub fn is_required(&self, default: bool) -> bool {
+        self.requirement.map_or(default, |requirement| {
+            requirement == StepRequirement::Required
+        })
+    }
+}
+
+/// Per-step settings of the switch pipeline. Promoting the standby is always required.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StepPolicies {
+    #[serde(default)]
+    pub demote_primary: StepPolicy,
+    #[serde(default)]
+    pub transfer_tower: StepPolicy,
+    #[serde(default)]
+    pub promote_standby: StepPolicy,
+    /// Health check of the new active node (manual switches only)
+    #[serde(default)]
+    pub verify: StepPolicy,
+}
+
+/// Point of a switch at which hooks run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HookEvent {
+    /// Before step 1; a failing hook can veto the switch
+    PreSwitch,
+    AfterTowerTransfer,
+    /// After the standby took the funded identity
+    AfterActivation,
+    /// After a switch failed or was vetoed
+    OnFailure,
+}
+
+impl HookEvent {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HookEvent::PreSwitch => "pre_switch",
+            HookEvent::AfterTowerTransfer => "after_tower_transfer",
+            HookEvent::AfterActivation => "after_activation",
+            HookEvent::OnFailure => "on_failure",
+        }
+    }
+}
+
+/// Operator command run during a switch. It gets the switch context as `SVS_*`
+/// environment variables and as JSON on stdin.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HookConfig {
+    pub name: String,
+    pub event: HookEvent,
+    pub command: String,
+    /// Run over SSH on this node (a node label, `old_active` or `new_active`) instead of locally
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<String>,
+    #[serde(default = "default_hook_timeout")]
+    pub timeout_seconds: u64,
+    /// Whether a failing pre_switch hook stops the switch
+    #[serde(default = "default_enabled")]
+    pub veto: bool,
+}
+
+/// How to cut off a primary that may still be alive before a standby takes over
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FencingMethod {
+    /// Run a local command; exit status 0 means the primary is fenced
+    Command,
+    /// Call a power endpoint (Redfish/IPMI gateway); any 2xx response means fenced
+    Http,
+    /// Call a firewall-rule API that isolates the primary; any 2xx response means fenced
+    Firewall,
+}
+
+/// Fencing stage of automatic failover. `{host}`, `{label}`, `{identity}` and `{vote}`
+/// in the command, URL, headers and body are replaced with the primary's values.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FencingConfig {
+    pub method: FencingMethod,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(default = "default_fencing_http_method")]
+    pub http_method: String,
+    #[serde(default)]
+    pub headers: std::collections::BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    #[serde(default = "default_fencing_timeout")]
+    pub timeout_seconds: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -40,6 +299,107 @@ pub struct AlertConfig {
     pub telegram: Option<TelegramConfig>,
     #[serde(default)]
     pub auto_failover_enabled: bool,
+    #[serde(default)]
+    pub auto_failover_policy: AutoFailoverPolicy,
+}
+
+/// Limits on when automatic failover may take over a delinquent validator. Unset
+/// values keep the behaviour without a policy: no rate limit, cooldown or blackout.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutoFailoverPolicy {
+    /// At most this many automatic failovers per validator within `window_minutes`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_failovers: Option<u32>,
+    #[serde(default = "default_failover_window_minutes")]
+    pub window_minutes: u64,
+    /// No automatic failover for this long after the previous one
+    #[serde(default)]
+    pub cooldown_minutes: u64,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub blackout_windows: Vec<BlackoutWindow>,
+    /// Only take over with a standby that is reachable, healthy and at most
+    /// `max_standby_slot_lag` slots behind the cluster
+    #[serde(default)]
+    pub require_standby_healthy: bool,
+    #[serde(default = "default_max_standby_slot_lag")]
+    pub max_standby_slot_lag: u64,
+    /// Only take over when the active node can't be reached over SSH either
+    #[serde(default)]
+    pub require_active_unreachable: bool,
+    /// Log and alert the decision, but never take over
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+impl Default for AutoFailoverPolicy {
+    fn default() -> Self {
+        Self {
+            max_failovers: None,
+            window_minutes: default_failover_window_minutes(),
+            cooldown_minutes: 0,
+            blackout_windows: Vec::new(),
+            require_standby_healthy: false,
+            max_standby_slot_lag: default_max_standby_slot_lag(),
+            require_active_unreachable: false,
+            dry_run: false,
+        }
+    }
+}
+
+/// Daily UTC time range without automatic failover, e.g. planned maintenance. A range
+/// whose end is before its start runs past midnight and belongs to the day it starts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlackoutWindow {
+    /// `HH:MM`
+    pub start: String,
+    /// `HH:MM`, exclusive
+    pub end: String,
+    /// Weekdays it applies to (`mon`, `tue`, ...); every day if empty
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub days: Vec<String>,
+}
+
+impl BlackoutWindow {
+    pub fn times(&self) -> anyhow::Result<(chrono::NaiveTime, chrono::NaiveTime)> {
+        let parse = |time: &str| {
+            chrono::NaiveTime::parse_from_str(time, "%H:%M")
+                .map_err(|_| anyhow::anyhow!("invalid time '{}' (expected HH:MM)", time))
+        };
+        Ok((parse(&self.start)?, parse(&self.end)?))
+    }
+
+    pub fn weekdays(&self) -> anyhow::Result<Vec<chrono::Weekday>> {
+        self.days
+            .iter()
+            .map(|day| {
+                day.parse()
+                    .map_err(|_| anyhow::anyhow!("invalid weekday '{}'", day))
+            })
+            .collect()
+    }
+
+    pub fn covers(&self, at: chrono::DateTime<chrono::Utc>) -> anyhow::Result<bool> {
+        use chrono::Datelike;
+
+        let (start, end) = self.times()?;
+        let weekdays = self.weekdays()?;
+        let applies = |day: chrono::Weekday| weekdays.is_empty() || weekdays.contains(&day);
+        let time = at.time();
+        let weekday = at.weekday();
+        Ok(if start <= end {
+            applies(weekday) && time >= start && time < end
+        } else {
+            (applies(weekday) && time >= start) || (applies(weekday.pred()) && time < end)
+        })
+    }
+
+    pub fn describe(&self) -> String {
+        let mut description = format!("{}-{} UTC", self.start, self.end);
+        if !self.days.is_empty() {
+            description.push_str(&format!(" ({})", self.days.join(", ")));
+        }
+        description
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -55,9 +415,37 @@ pub struct ValidatorPair {
     #[serde(rename = "identityPubkey")]
     pub identity_pubkey: String,
     pub rpc: String,
+    /// More RPC endpoints asked about our votes alongside `rpc`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rpcs: Vec<String>,
+    /// How many endpoints must agree before the validator counts as delinquent
+    /// (default: a majority)
+    #[serde(rename = "rpcQuorum", default, skip_serializing_if = "Option::is_none")]
+    pub rpc_quorum: Option<usize>,
     pub nodes: Vec<NodeConfig>,
 }
 
+impl ValidatorPair {
+    /// `rpc` followed by the additional `rpcs`, without duplicates
+    pub fn rpc_endpoints(&self) -> Vec<String> {
+        let mut endpoints = vec![self.rpc.clone()];
+        for rpc in &self.rpcs {
+            if !endpoints.contains(rpc) {
+                endpoints.push(rpc.clone());
+            }
+        }
+        endpoints
+    }
+
+    /// Endpoints that must agree on delinquency, within 1..=endpoint count
+    pub fn rpc_quorum(&self) -> usize {
+        let endpoints = self.rpc_endpoints().len();
+        self.rpc_quorum
+            .unwrap_or(endpoints / 2 + 1)
+            .clamp(1, endpoints)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NodeConfig {
     pub label: String,
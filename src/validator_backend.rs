@@ -0,0 +1,513 @@
+use anyhow::{anyhow, Result};
+
+use crate::commands::switch::RemoteCommand;
+use crate::types::{NodeWithStatus, ValidatorType};
+
+const DEFAULT_RPC_PORT: u16 = 8899;
+
+/// Everything svs needs to know about one validator client. `ValidatorType`
+/// selects the implementation; supporting a new client means adding one here.
+pub trait ValidatorBackend: Send + Sync {
+    fn validator_type(&self) -> ValidatorType;
+
+    fn name(&self) -> &'static str;
+
+    /// Whether a line of `ps` output is this client's validator process
+    fn matches_process(&self, process_line: &str) -> bool;
+
+    /// Executable used for version and identity commands
+    fn main_executable<'a>(
+        &self,
+        agave_validator_executable: Option<&'a str>,
+        fdctl_executable: Option<&'a str>,
+    ) -> Option<&'a str>;
+
+    /// Short description shown while the identity is being changed
+    fn set_identity_description(&self) -> &'static str;
+
+    /// Command switching the running validator on `node` to `identity_keypair`.
+    /// Missing executable paths are filled in from `process_info`. With
+    /// `require_tower` the client refuses the identity if it has no tower for it.
+    fn set_identity_command(
+        &self,
+        node: &mut NodeWithStatus,
+        process_info: &str,
+        identity_keypair: &str,
+        require_tower: bool,
+    ) -> Result<RemoteCommand>;
+
+    /// Whether `set_identity_command` enforces `require_tower` itself. Otherwise
+    /// the switch checks for the tower file before activating.
+    fn supports_require_tower(&self) -> bool {
+        false
+    }
+
+    fn version_command(&self, executable: &str) -> String {
+        format!("timeout 5 {} --version 2>/dev/null", executable)
+    }
+
+    /// Client actually reported by the version output, and the version string to show
+    fn parse_version(&self, output: &str) -> (ValidatorType, Option<String>);
+
+    /// Local RPC port, from the validator command line where the client allows overriding it
+    fn rpc_port(&self, _command_line: Option<&str>) -> u16 {
+        DEFAULT_RPC_PORT
+    }
+
+    /// Ledger path from the validator command line
+    fn ledger_path_from_process(&self, _process_info: &str) -> Option<String> {
+        None
+    }
+
+    /// Remote command reading the ledger path from the client's config file, for
+    /// clients that take it from there
+    fn ledger_path_config_command(&self, _config_path: &str) -> Option<String> {
+        None
+    }
+
+    fn parse_ledger_path_config(&self, _output: &str) -> Option<String> {
+        None
+    }
+
+    fn tower_path(&self, ledger_path: &str, identity: &str) -> String {
+        format!("{}/tower-1_9-{}.bin", ledger_path, identity)
+    }
+
+    /// Long-running command whose output shows whether the node is caught up
+    fn catchup_command(&self, node: &NodeWithStatus) -> Option<String>;
+
+    /// Short status for one line of `catchup_command` output
+    fn catchup_status(&self, output: &str) -> String;
+}
+
+pub struct AgaveBackend;
+pub struct JitoBackend;
+pub struct FiredancerBackend;
+
+static AGAVE: AgaveBackend = AgaveBackend;
+static JITO: JitoBackend = JitoBackend;
+static FIREDANCER: FiredancerBackend = FiredancerBackend;
+
+/// Backend for a detected validator type, `None` when the type is unknown
+pub fn backend_for(validator_type: &ValidatorType) -> Option<&'static dyn ValidatorBackend> {
+    match validator_type {
+        ValidatorType::Agave => Some(&AGAVE),
+        ValidatorType::Jito => Some(&JITO),
+        ValidatorType::Firedancer => Some(&FIREDANCER),
+        ValidatorType::Unknown => None,
+    }
+}
+
+/// Backend for the validator found in `process_info`. Jito runs the
+/// agave-validator binary, so the detected type decides between the two.
+pub fn backend_for_process(
+    validator_type: &ValidatorType,
+    process_info: &str,
+) -> Option<&'static dyn ValidatorBackend> {
+    let candidates: [&'static dyn ValidatorBackend; 2] = [&FIREDANCER, &AGAVE];
+    let backend = candidates.into_iter().find(|backend| {
+        process_info
+            .lines()
+            .any(|line| backend.matches_process(line))
+    })?;
+
+    if backend.validator_type() == ValidatorType::Agave && *validator_type == ValidatorType::Jito {
+        Some(&JITO)
+    } else {
+        Some(backend)
+    }
+}
+
+pub fn validator_type_name(validator_type: &ValidatorType) -> &'static str {
+    backend_for(validator_type)
+        .map(|backend| backend.name())
+        .unwrap_or("Unknown")
+}
+
+/// Tower file for `identity` in `ledger_path`
+pub fn tower_path(validator_type: &ValidatorType, ledger_path: &str, identity: &str) -> String {
+    backend_for(validator_type)
+        .unwrap_or(&AGAVE)
+        .tower_path(ledger_path, identity)
+}
+
+/// Version string with any client prefix removed, e.g. "Agave 2.1.5" -> "2.1.5"
+pub fn strip_client_prefix(version: &str) -> String {
+    version
+        .replace("Firedancer ", "")
+        .replace("Agave ", "")
+        .replace("Jito ", "")
+}
+
+impl ValidatorBackend for AgaveBackend {
+    fn validator_type(&self) -> ValidatorType {
+        ValidatorType::Agave
+    }
+
+    fn name(&self) -> &'static str {
+        "Agave"
+    }
+
+    fn matches_process(&self, process_line: &str) -> bool {
+        process_line.contains("agave-validator")
+    }
+
+    fn main_executable<'a>(
+        &self,
+        agave_validator_executable: Option<&'a str>,
+        _fdctl_executable: Option<&'a str>,
+    ) -> Option<&'a str> {
+        agave_validator_executable
+    }
+
+    fn set_identity_description(&self) -> &'static str {
+        "Using Agave validator set-identity"
+    }
+
+    fn set_identity_command(
+        &self,
+        node: &mut NodeWithStatus,
+        process_info: &str,
+        identity_keypair: &str,
+        require_tower: bool,
+    ) -> Result<RemoteCommand> {
+        // agave-validator -l <ledger> set-identity [--require-tower] <identity>
+        let agave_path = crate::executable_utils::extract_and_save_agave_path(node, process_info)?;
+        let ledger_path = node
+            .ledger_path
+            .clone()
+            .or_else(|| self.ledger_path_from_process(process_info))
+            .ok_or_else(|| anyhow!("Ledger path not detected for {}", node.node.label))?;
+
+        let mut args = vec!["-l".to_string(), ledger_path, "set-identity".to_string()];
+        if require_tower {
+            args.push("--require-tower".to_string());
+        }
+        args.push(identity_keypair.to_string());
+
+        Ok(RemoteCommand {
+            program: agave_path,
+            args,
+        })
+    }
+
+    fn supports_require_tower(&self) -> bool {
+        true
+    }
+
+    fn parse_version(&self, output: &str) -> (ValidatorType, Option<String>) {
+        let mut version = None;
+        if let Some(line) = output.lines().next() {
+            // Handle both agave-validator and solana-cli output formats
+            if line.starts_with("agave-validator ") || line.starts_with("solana-cli ") {
+                if let Some(version_num) = line.split_whitespace().nth(1) {
+                    if line.contains("client:Firedancer") {
+                        version = Some(format!("Firedancer {}", version_num));
+                    } else if line.contains("client:Agave") {
+                        version = Some(format!("Agave {}", version_num));
+                    } else if version_num.starts_with("0.") {
+                        version = Some(format!("Firedancer {}", version_num));
+                    } else if version_num.starts_with("2.") {
+                        version = Some(format!("Agave {}", version_num));
+                    }
+                }
+            }
+        }
+
+        if output.to_lowercase().contains("jito") {
+            (
+                ValidatorType::Jito,
+                version.map(|v| v.replace("Agave", "Jito")),
+            )
+        } else {
+            (ValidatorType::Agave, version)
+        }
+    }
+
+    fn rpc_port(&self, command_line: Option<&str>) -> u16 {
+        command_line
+            .and_then(|cmd| {
+                let parts: Vec<&str> = cmd.split_whitespace().collect();
+                parts
+                    .windows(2)
+                    .find(|w| w[0] == "--rpc-port")
+                    .and_then(|w| w[1].parse::<u16>().ok())
+            })
+            .unwrap_or(DEFAULT_RPC_PORT)
+    }
+
+    fn ledger_path_from_process(&self, process_info: &str) -> Option<String> {
+        crate::executable_utils::extract_ledger_path(process_info)
+    }
+
+    fn catchup_command(&self, node: &NodeWithStatus) -> Option<String> {
+        let solana_cli = node.solana_cli_executable.clone().or_else(|| {
+            node.agave_validator_executable
+                .as_ref()
+                .map(|validator| validator.replace("agave-validator", "solana"))
+        })?;
+
+        // Need to use bash -c to properly handle the command with its full path
+        Some(format!(
+            "bash -c '{} catchup --our-localhost 2>&1'",
+            solana_cli
+        ))
+    }
+
+    fn catchup_status(&self, output: &str) -> String {
+        if output.contains("0 slot(s)") || output.contains("has caught up") {
+            "Caught up".to_string()
+        } else if let Some(pos) = output.find(" slot(s) behind") {
+            let start = output[..pos].rfind(' ').map(|i| i + 1).unwrap_or(0);
+            let slots_str = &output[start..pos];
+            if let Ok(slots) = slots_str.parse::<u64>() {
+                format!("{} slots behind", slots)
+            } else {
+                output.to_string()
+            }
+        } else if output.contains("bash:") && output.contains("line") {
+            // Parse bash errors more nicely
+            if output.contains("command not found") || output.contains("No such file") {
+                "CLI not found".to_string()
+            } else {
+                "Command error".to_string()
+            }
+        } else if output.contains("Error") || output.contains("error") {
+            if output.contains("RPC") {
+                "RPC Error".to_string()
+            } else if output.contains("connection") {
+                "Connection Error".to_string()
+            } else {
+                "Error".to_string()
+            }
+        } else if output.trim().is_empty() {
+            "Waiting...".to_string()
+        } else {
+            // Show the raw output if we can't parse it, but limit length
+            let trimmed = output.trim();
+            if trimmed.len() > 40 {
+                format!("{}...", trimmed.chars().take(37).collect::<String>())
+            } else {
+                trimmed.to_string()
+            }
+        }
+    }
+}
+
+/// Jito-Solana is an Agave fork and shares its command line
+impl ValidatorBackend for JitoBackend {
+    fn validator_type(&self) -> ValidatorType {
+        ValidatorType::Jito
+    }
+
+    fn name(&self) -> &'static str {
+        "Jito"
+    }
+
+    fn matches_process(&self, process_line: &str) -> bool {
+        AGAVE.matches_process(process_line)
+    }
+
+    fn main_executable<'a>(
+        &self,
+        agave_validator_executable: Option<&'a str>,
+        fdctl_executable: Option<&'a str>,
+    ) -> Option<&'a str> {
+        AGAVE.main_executable(agave_validator_executable, fdctl_executable)
+    }
+
+    fn set_identity_description(&self) -> &'static str {
+        "Using Jito validator set-identity"
+    }
+
+    fn set_identity_command(
+        &self,
+        node: &mut NodeWithStatus,
+        process_info: &str,
+        identity_keypair: &str,
+        require_tower: bool,
+    ) -> Result<RemoteCommand> {
+        AGAVE.set_identity_command(node, process_info, identity_keypair, require_tower)
+    }
+
+    fn supports_require_tower(&self) -> bool {
+        AGAVE.supports_require_tower()
+    }
+
+    fn parse_version(&self, output: &str) -> (ValidatorType, Option<String>) {
+        AGAVE.parse_version(output)
+    }
+
+    fn rpc_port(&self, command_line: Option<&str>) -> u16 {
+        AGAVE.rpc_port(command_line)
+    }
+
+    fn ledger_path_from_process(&self, process_info: &str) -> Option<String> {
+        AGAVE.ledger_path_from_process(process_info)
+    }
+
+    fn catchup_command(&self, node: &NodeWithStatus) -> Option<String> {
+        AGAVE.catchup_command(node)
+    }
+
+    fn catchup_status(&self, output: &str) -> String {
+        AGAVE.catchup_status(output)
+    }
+}
+
+impl ValidatorBackend for FiredancerBackend {
+    fn validator_type(&self) -> ValidatorType {
+        ValidatorType::Firedancer
+    }
+
+    fn name(&self) -> &'static str {
+        "Firedancer"
+    }
+
+    fn matches_process(&self, process_line: &str) -> bool {
+        process_line.contains("fdctl") || process_line.contains("firedancer")
+    }
+
+    fn main_executable<'a>(
+        &self,
+        _agave_validator_executable: Option<&'a str>,
+        fdctl_executable: Option<&'a str>,
+    ) -> Option<&'a str> {
+        fdctl_executable
+    }
+
+    fn set_identity_description(&self) -> &'static str {
+        "Using Firedancer fdctl set-identity"
+    }
+
+    fn set_identity_command(
+        &self,
+        node: &mut NodeWithStatus,
+        process_info: &str,
+        identity_keypair: &str,
+        _require_tower: bool,
+    ) -> Result<RemoteCommand> {
+        // fdctl set-identity --config <config> <identity>
+        // fdctl has no --require-tower; the switch checks for the tower file instead
+        let fdctl_path = crate::executable_utils::extract_and_save_fdctl_path(node, process_info)?;
+        let config_path = crate::executable_utils::extract_firedancer_config_path(process_info)?;
+
+        Ok(RemoteCommand {
+            program: fdctl_path,
+            args: vec![
+                "set-identity".to_string(),
+                "--config".to_string(),
+                config_path,
+                identity_keypair.to_string(),
+            ],
+        })
+    }
+
+    fn parse_version(&self, output: &str) -> (ValidatorType, Option<String>) {
+        // First part is the version, second is the git hash
+        let version = output
+            .lines()
+            .next()
+            .and_then(|line| line.split_whitespace().next())
+            .map(|version| format!("Firedancer {}", version));
+        (ValidatorType::Firedancer, version)
+    }
+
+    fn ledger_path_config_command(&self, config_path: &str) -> Option<String> {
+        Some(format!(
+            "cat {} 2>/dev/null | grep -A 5 '\\[ledger\\]' | grep 'path' | head -1",
+            config_path
+        ))
+    }
+
+    fn parse_ledger_path_config(&self, output: &str) -> Option<String> {
+        // Something like: path = "/mnt/solana_ledger"
+        output
+            .lines()
+            .filter(|line| line.contains("path") && line.contains('='))
+            .filter_map(|line| line.split('=').nth(1))
+            .map(|path| path.trim().trim_matches('"').trim_matches('\''))
+            .find(|path| !path.is_empty())
+            .map(str::to_string)
+    }
+
+    fn catchup_command(&self, node: &NodeWithStatus) -> Option<String> {
+        node.fdctl_executable
+            .as_ref()
+            .map(|fdctl| format!("bash -c '{} status'", fdctl))
+    }
+
+    fn catchup_status(&self, output: &str) -> String {
+        if output.contains("running") {
+            "Caught up".to_string()
+        } else {
+            "Not running".to_string()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backend_for_process() {
+        let agave = "solana 1234 /opt/bin/agave-validator --ledger /mnt/ledger";
+        let fdctl = "solana 5678 /opt/firedancer/bin/fdctl run --config /etc/fd.toml";
+
+        let backend = backend_for_process(&ValidatorType::Agave, agave).unwrap();
+        assert_eq!(backend.validator_type(), ValidatorType::Agave);
+        // Jito runs agave-validator too
+        let backend = backend_for_process(&ValidatorType::Jito, agave).unwrap();
+        assert_eq!(backend.validator_type(), ValidatorType::Jito);
+        let backend = backend_for_process(&ValidatorType::Unknown, fdctl).unwrap();
+        assert_eq!(backend.validator_type(), ValidatorType::Firedancer);
+        // Legacy solana-validator has no supported backend
+        assert!(backend_for_process(
+            &ValidatorType::Unknown,
+            "solana 9012 solana-validator --ledger /mnt/ledger"
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_parse_version() {
+        let (validator_type, version) =
+            AGAVE.parse_version("agave-validator 2.1.5 (src:abcd; feat:123, client:Agave)");
+        assert_eq!(validator_type, ValidatorType::Agave);
+        assert_eq!(version.as_deref(), Some("Agave 2.1.5"));
+
+        let (validator_type, version) = AGAVE
+            .parse_version("agave-validator 2.1.5 (src:abcd; feat:123, client:JitoLabs)\njito");
+        assert_eq!(validator_type, ValidatorType::Jito);
+        assert_eq!(version.as_deref(), Some("Jito 2.1.5"));
+
+        let (validator_type, version) = FIREDANCER.parse_version("0.505.20216 9f1c3e2\n");
+        assert_eq!(validator_type, ValidatorType::Firedancer);
+        assert_eq!(version.as_deref(), Some("Firedancer 0.505.20216"));
+        assert_eq!(strip_client_prefix("Firedancer 0.505.20216"), "0.505.20216");
+    }
+
+    #[test]
+    fn test_rpc_port_and_ledger_discovery() {
+        let cmd = "agave-validator --ledger /mnt/ledger --rpc-port 8898 --full-rpc-api";
+        assert_eq!(AGAVE.rpc_port(Some(cmd)), 8898);
+        assert_eq!(JITO.rpc_port(None), 8899);
+        assert_eq!(FIREDANCER.rpc_port(Some(cmd)), 8899);
+        assert_eq!(
+            AGAVE.ledger_path_from_process(cmd).as_deref(),
+            Some("/mnt/ledger")
+        );
+
+        assert_eq!(
+            FIREDANCER
+                .parse_ledger_path_config("    path = \"/mnt/solana_ledger\"\n")
+                .as_deref(),
+            Some("/mnt/solana_ledger")
+        );
+        assert_eq!(
+            tower_path(&ValidatorType::Firedancer, "/mnt/ledger", "Ident111"),
+            "/mnt/ledger/tower-1_9-Ident111.bin"
+        );
+    }
+}
@@ -13,25 +13,9 @@ pub struct RpcResponse {
 
 /// Get the appropriate RPC port for a validator type
 pub fn get_rpc_port(validator_type: ValidatorType, command_line: Option<&str>) -> u16 {
-    match validator_type {
-        ValidatorType::Agave | ValidatorType::Jito => {
-            // Check command line for custom RPC port
-            let mut port = 8899; // default
-            if let Some(cmd) = command_line {
-                if let Some(pos) = cmd.find("--rpc-port") {
-                    let rest = &cmd[pos..];
-                    if let Some(port_str) = rest.split_whitespace().nth(1) {
-                        if let Ok(p) = port_str.parse::<u16>() {
-                            port = p;
-                        }
-                    }
-                }
-            }
-            port
-        }
-        ValidatorType::Firedancer => 8899, // Firedancer always uses 8899
-        ValidatorType::Unknown => 8899,    // Default to 8899
-    }
+    crate::validator_backend::backend_for(&validator_type)
+        .map(|backend| backend.rpc_port(command_line))
+        .unwrap_or(8899) // Default to 8899
 }
 
 /// Execute a JSON-RPC call over SSH
@@ -112,6 +96,25 @@ pub async fn get_health(
     }
 }
 
+/// Get the slot the validator has processed using getSlot RPC call
+pub async fn get_slot(
+    ssh_pool: &AsyncSshPool,
+    node: &NodeConfig,
+    ssh_key: &str,
+    rpc_port: u16,
+) -> Result<u64> {
+    let response = execute_rpc_call(ssh_pool, node, ssh_key, "getSlot", None, rpc_port).await?;
+
+    if let Some(error) = response.error {
+        return Err(anyhow!("RPC error: {:?}", error));
+    }
+
+    response
+        .result
+        .as_u64()
+        .ok_or_else(|| anyhow!("Failed to extract slot from RPC response"))
+}
+
 /// Check if a validator is caught up using getHealth RPC
 pub async fn is_validator_caught_up(
     ssh_pool: &AsyncSshPool,